@@ -1,25 +1,34 @@
 use anyhow::{anyhow, Result};
 use breez_sdk_core::{
-    error::*, mnemonic_to_seed as sdk_mnemonic_to_seed, parse as sdk_parse_input,
+    error::*, format_fiat as sdk_format_fiat, format_sats as sdk_format_sats,
+    mnemonic_to_seed as sdk_mnemonic_to_seed, parse as sdk_parse_input,
     parse_invoice as sdk_parse_invoice, AesSuccessActionDataDecrypted, BackupFailedData,
-    BackupStatus, BitcoinAddressData, BreezEvent, BreezServices, BuyBitcoinProvider,
-    BuyBitcoinRequest, BuyBitcoinResponse, ChannelState, CheckMessageRequest, CheckMessageResponse,
-    ClosedChannelPaymentDetails, Config, CurrencyInfo, EnvironmentType, EventListener,
-    FeeratePreset, FiatCurrency, GreenlightCredentials, GreenlightNodeConfig, InputType,
-    InvoicePaidDetails, LNInvoice, ListPaymentsRequest, LnPaymentDetails, LnUrlAuthRequestData,
-    LnUrlCallbackStatus, LnUrlErrorData, LnUrlPayRequest, LnUrlPayRequestData, LnUrlPayResult,
-    LnUrlWithdrawRequest, LnUrlWithdrawRequestData, LnUrlWithdrawResult, LnUrlWithdrawSuccessData,
-    LocaleOverrides, LocalizedName, LogEntry, LogStream, LspInformation, MessageSuccessActionData,
-    MetadataItem, Network, NodeConfig, NodeState, OpenChannelFeeRequest, OpenChannelFeeResponse,
-    OpeningFeeParams, OpeningFeeParamsMenu, Payment, PaymentDetails, PaymentFailedData,
-    PaymentStatus, PaymentType, PaymentTypeFilter, PrepareRefundRequest, PrepareRefundResponse,
-    PrepareSweepRequest, PrepareSweepResponse, Rate, ReceiveOnchainRequest, ReceivePaymentRequest,
+    BackupStatus, BackupTableDiff, BackupVerificationReport, BackupVersion, BitcoinAddressData,
+    BitcoinUnit, BreezEvent, BreezServices, BuyBitcoinProvider, BuyBitcoinRequest,
+    BuyBitcoinResponse, BuyInboundLiquidityRequest, BuyInboundLiquidityResponse,
+    ChangeBackupPassphraseRequest, ChannelState, CheckMessageRequest, CheckMessageResponse,
+    ClosedChannelPaymentDetails, Config, CurrencyInfo, DbMaintenanceReport, DbMaintenanceRequest,
+    EnvironmentType, EventListener, ExportPaymentsRequest, FeeratePreset, FiatCurrency, FiatQuote,
+    FiatRates, GreenlightCredentials, GreenlightNodeConfig, InputType, InvoicePaidDetails,
+    LNInvoice, LiquidityOrder, LiquidityOrderState, ListPaymentsRequest, LnPaymentDetails,
+    LnUrlAuthRequestData, LnUrlCallbackStatus, LnUrlErrorData, LnUrlPayRequest,
+    LnUrlPayRequestData, LnUrlPayResult, LnUrlWithdrawRequest, LnUrlWithdrawRequestData,
+    LnUrlWithdrawResult, LnUrlWithdrawSuccessData, LocaleOverrides, LocalizedName, LogEntry,
+    LogStream, LspInformation, MessageSuccessActionData, MetadataItem, Network, NodeBalance,
+    NodeConfig, NodeState, OpenChannelFeeRequest, OpenChannelFeeResponse, OpeningFeeParams,
+    OpeningFeeParamsMenu, Payment, PaymentDetails, PaymentExportFormat, PaymentFailedData,
+    PaymentMetadata, PaymentStatus, PaymentType, PaymentTypeFilter, PaymentsCursor, PersistedEvent,
+    PersistedEventListener, PrepareBuyInboundLiquidityRequest, PrepareBuyInboundLiquidityResponse,
+    PrepareRefundRequest, PrepareRefundResponse, PrepareSweepRequest, PrepareSweepResponse,
+    ProcessNotificationRequest, ProcessNotificationResult, Rate, ReceiveFiatPaymentRequest,
+    ReceiveFiatPaymentResponse, ReceiveOnchainRequest, ReceivePaymentRequest,
     ReceivePaymentResponse, RecommendedFees, RefundRequest, RefundResponse, ReverseSwapFeesRequest,
     ReverseSwapInfo, ReverseSwapPairInfo, ReverseSwapStatus, RouteHint, RouteHintHop,
-    SendOnchainRequest, SendOnchainResponse, SendPaymentRequest, SendPaymentResponse,
-    SendSpontaneousPaymentRequest, SignMessageRequest, SignMessageResponse, StaticBackupRequest,
-    StaticBackupResponse, SuccessActionProcessed, SwapInfo, SwapStatus, SweepRequest,
-    SweepResponse, Symbol, UnspentTransactionOutput, UrlSuccessActionData,
+    SendFiatPaymentRequest, SendFiatPaymentResponse, SendOnchainRequest, SendOnchainResponse,
+    SendPaymentRequest, SendPaymentResponse, SendSpontaneousPaymentRequest, SignMessageRequest,
+    SignMessageResponse, StaticBackupRequest, StaticBackupResponse, SuccessActionProcessed,
+    SwapInfo, SwapStatus, SweepRequest, SweepResponse, Symbol, UnspentTransactionOutput,
+    UrlSuccessActionData,
 };
 use log::{Level, LevelFilter, Metadata, Record};
 use once_cell::sync::{Lazy, OnceCell};
@@ -95,6 +104,18 @@ pub fn connect(
     })
 }
 
+/// See [BreezServices::connect_for_notification]
+pub fn connect_for_notification(
+    config: Config,
+    seed: Vec<u8>,
+) -> SdkResult<Arc<BlockingBreezServices>> {
+    rt().block_on(async move {
+        let breez_services = BreezServices::connect_for_notification(config, seed).await?;
+
+        Ok(Arc::new(BlockingBreezServices { breez_services }))
+    })
+}
+
 /// If used, this must be called before `connect`
 pub fn set_log_stream(log_stream: Box<dyn LogStream>) -> Result<()> {
     LOG_INIT
@@ -113,6 +134,20 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.disconnect())
     }
 
+    pub fn add_event_listener(&self, listener: Box<dyn PersistedEventListener>) -> String {
+        self.breez_services.add_event_listener(listener)
+    }
+
+    pub fn events_since(&self, seq: u64) -> SdkResult<Vec<PersistedEvent>> {
+        self.breez_services.events_since(seq)
+    }
+
+    pub fn remove_event_listener(&self, id: String) -> SdkResult<()> {
+        self.breez_services
+            .remove_event_listener(id)
+            .map_err(|e| e.into())
+    }
+
     pub fn send_payment(&self, req: SendPaymentRequest) -> SdkResult<SendPaymentResponse> {
         rt().block_on(self.breez_services.send_payment(req))
     }
@@ -128,6 +163,20 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.receive_payment(req))
     }
 
+    pub fn send_fiat_payment(
+        &self,
+        req: SendFiatPaymentRequest,
+    ) -> SdkResult<SendFiatPaymentResponse> {
+        rt().block_on(self.breez_services.send_fiat_payment(req))
+    }
+
+    pub fn receive_fiat_payment(
+        &self,
+        req: ReceiveFiatPaymentRequest,
+    ) -> SdkResult<ReceiveFiatPaymentResponse> {
+        rt().block_on(self.breez_services.receive_fiat_payment(req))
+    }
+
     pub fn node_info(&self) -> SdkResult<NodeState> {
         self.breez_services.node_info()
     }
@@ -142,6 +191,17 @@ impl BlockingBreezServices {
             .map_err(|e| e.into())
     }
 
+    pub fn process_notification(
+        &self,
+        req: ProcessNotificationRequest,
+    ) -> SdkResult<ProcessNotificationResult> {
+        rt().block_on(self.breez_services.process_notification(req))
+    }
+
+    pub fn register_webhook(&self, webhook_url: String) -> SdkResult<()> {
+        rt().block_on(self.breez_services.register_webhook(webhook_url))
+    }
+
     pub fn backup_status(&self) -> SdkResult<BackupStatus> {
         self.breez_services.backup_status().map_err(|e| e.into())
     }
@@ -151,10 +211,55 @@ impl BlockingBreezServices {
             .map_err(|e| e.into())
     }
 
+    pub fn verify_backup(&self) -> SdkResult<BackupVerificationReport> {
+        rt().block_on(self.breez_services.verify_backup())
+            .map_err(|e| e.into())
+    }
+
+    pub fn list_backup_versions(&self) -> SdkResult<Vec<BackupVersion>> {
+        self.breez_services.list_backup_versions()
+    }
+
+    pub fn restore_backup(&self, version: u64) -> SdkResult<()> {
+        rt().block_on(self.breez_services.restore_backup(version))
+    }
+
+    pub fn change_backup_passphrase(&self, req: ChangeBackupPassphraseRequest) -> SdkResult<()> {
+        rt().block_on(self.breez_services.change_backup_passphrase(req))
+            .map_err(|e| e.into())
+    }
+
+    pub fn export_backup(&self, path: String, passphrase: String) -> SdkResult<()> {
+        rt().block_on(self.breez_services.export_backup(path, passphrase))
+            .map_err(|e| e.into())
+    }
+
+    pub fn import_backup(&self, path: String, passphrase: String) -> SdkResult<()> {
+        rt().block_on(self.breez_services.import_backup(path, passphrase))
+            .map_err(|e| e.into())
+    }
+
+    pub fn db_maintenance(&self, req: DbMaintenanceRequest) -> SdkResult<DbMaintenanceReport> {
+        rt().block_on(self.breez_services.db_maintenance(req))
+    }
+
     pub fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
         rt().block_on(self.breez_services.list_payments(req))
     }
 
+    pub fn export_payments(&self, req: ExportPaymentsRequest) -> SdkResult<String> {
+        rt().block_on(self.breez_services.export_payments(req))
+    }
+
+    pub fn set_payment_metadata(
+        &self,
+        hash: String,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> SdkResult<()> {
+        rt().block_on(self.breez_services.set_payment_metadata(hash, note, tags))
+    }
+
     pub fn payment_by_hash(&self, hash: String) -> SdkResult<Option<Payment>> {
         rt().block_on(self.breez_services.payment_by_hash(hash))
             .map_err(|e| e.into())
@@ -185,6 +290,11 @@ impl BlockingBreezServices {
             .map_err(|e| e.into())
     }
 
+    pub fn fiat_rates(&self) -> SdkResult<FiatRates> {
+        rt().block_on(self.breez_services.fiat_rates())
+            .map_err(|e| e.into())
+    }
+
     pub fn list_fiat_currencies(&self) -> SdkResult<Vec<FiatCurrency>> {
         rt().block_on(self.breez_services.list_fiat_currencies())
             .map_err(|e| e.into())
@@ -198,6 +308,10 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.connect_lsp(lsp_id))
     }
 
+    pub fn connect_lsp_by_uri(&self, uri: String) -> SdkResult<LspInformation> {
+        rt().block_on(self.breez_services.connect_lsp_by_uri(uri))
+    }
+
     pub fn fetch_lsp_info(&self, lsp_id: String) -> SdkResult<Option<LspInformation>> {
         rt().block_on(self.breez_services.fetch_lsp_info(lsp_id))
             .map_err(|e| e.into())
@@ -227,6 +341,20 @@ impl BlockingBreezServices {
         .map_err(|e: anyhow::Error| e.into())
     }
 
+    pub fn prepare_buy_inbound_liquidity(
+        &self,
+        req: PrepareBuyInboundLiquidityRequest,
+    ) -> SdkResult<PrepareBuyInboundLiquidityResponse> {
+        rt().block_on(self.breez_services.prepare_buy_inbound_liquidity(req))
+    }
+
+    pub fn buy_inbound_liquidity(
+        &self,
+        req: BuyInboundLiquidityRequest,
+    ) -> SdkResult<BuyInboundLiquidityResponse> {
+        rt().block_on(self.breez_services.buy_inbound_liquidity(req))
+    }
+
     /// Onchain receive swap API
     pub fn receive_onchain(&self, req: ReceiveOnchainRequest) -> SdkResult<SwapInfo> {
         rt().block_on(self.breez_services.receive_onchain(req))
@@ -315,6 +443,14 @@ pub fn mnemonic_to_seed(phrase: String) -> SdkResult<Vec<u8>> {
     sdk_mnemonic_to_seed(phrase).map_err(|e| e.into())
 }
 
+pub fn format_fiat(amount: f64, currency_id: String, locale: String) -> SdkResult<String> {
+    sdk_format_fiat(amount, &currency_id, &locale).map_err(|e| e.into())
+}
+
+pub fn format_sats(amount_msat: u64, unit: BitcoinUnit, locale: String) -> String {
+    sdk_format_sats(amount_msat, unit, &locale)
+}
+
 fn rt() -> &'static tokio::runtime::Runtime {
     &RT
 }
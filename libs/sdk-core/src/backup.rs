@@ -1,20 +1,28 @@
 use crate::{
     breez_services::BackupFailedData,
-    persist::db::{HookEvent, SqliteStorage},
-    BreezEvent, Config,
+    crypt::{derive_passphrase_key, derive_scrypt_key},
+    persist::{
+        db::{HookEvent, SqliteStorage},
+        storage::Storage,
+        sync::SyncDbSummary,
+    },
+    BackupTableDiff, BackupVerificationReport, BreezEvent, Config,
 };
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
+use bitcoin::hashes::{sha256, Hash};
 use ecies::utils::{aes_decrypt, aes_encrypt};
 use miniz_oxide::{deflate::compress_to_vec, inflate::decompress_to_vec_with_limit};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::{
     fs::{self, File},
     io::{Read, Write},
     path::Path,
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
-use tempfile::tempdir_in;
+use tempfile::{tempdir_in, TempDir};
 use tokio::{
     runtime::Builder,
     sync::{
@@ -60,6 +68,40 @@ pub struct BackupState {
     pub data: Vec<u8>,
 }
 
+/// How often the remote state is checked for changes pushed by other apps
+const REMOTE_POLL_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Number of PBKDF2 rounds used to derive the backup file keys from user passphrases
+const PASSPHRASE_KDF_ROUNDS: u32 = 600_000;
+
+/// Identifies a portable backup file, see [BackupWatcher::export_to_file]
+const BACKUP_FILE_MAGIC: &[u8; 8] = b"BREEZBAK";
+const BACKUP_FILE_VERSION: u8 = 1;
+const BACKUP_FILE_SALT_LEN: usize = 16;
+const BACKUP_FILE_HEADER_LEN: usize = BACKUP_FILE_MAGIC.len() + 1 + 4 + BACKUP_FILE_SALT_LEN;
+/// Upper bound of the KDF rounds accepted from a backup file header, so a crafted file can't
+/// make the import derive keys endlessly
+const BACKUP_FILE_MAX_KDF_ROUNDS: u32 = 10_000_000;
+
+/// The content of a portable backup file, before it is compressed and encrypted.
+#[derive(Serialize, Deserialize)]
+struct BackupFileContent {
+    created_at: u64,
+    /// The sync DB, base64 encoded
+    sync_db: String,
+    static_backup: Option<Vec<String>>,
+}
+
+/// The backup passphrase keys known in the current session, see [BackupWatcher::set_passphrase]
+#[derive(Default)]
+struct PassphraseKeys {
+    /// The key of the current passphrase, once it was set in this session
+    current: Option<Vec<u8>>,
+    /// The keys of the replaced passphrases, to still decrypt the versions (and the states
+    /// pushed by other apps) that are encrypted with them
+    previous: Vec<Vec<u8>>,
+}
+
 /// BackupTransport is the interface for syncing the sdk state between multiple apps.
 #[tonic::async_trait]
 pub trait BackupTransport: Send + Sync {
@@ -71,9 +113,10 @@ pub(crate) struct BackupWatcher {
     pub(crate) config: Config,
     backup_request_sender: Mutex<Option<mpsc::Sender<BackupRequest>>>,
     inner: Arc<dyn BackupTransport>,
-    persister: Arc<SqliteStorage>,
+    persister: Arc<dyn Storage>,
     encryption_key: Vec<u8>,
     legacy_encryption_key: Vec<u8>,
+    passphrase_keys: Arc<std::sync::Mutex<PassphraseKeys>>,
     events_notifier: broadcast::Sender<BreezEvent>,
 }
 
@@ -82,7 +125,7 @@ impl BackupWatcher {
     pub(crate) fn new(
         config: Config,
         inner: Arc<dyn BackupTransport>,
-        persister: Arc<SqliteStorage>,
+        persister: Arc<dyn Storage>,
         encryption_key: Vec<u8>,
         legacy_encryption_key: Vec<u8>,
     ) -> Self {
@@ -95,6 +138,7 @@ impl BackupWatcher {
             persister,
             encryption_key,
             legacy_encryption_key,
+            passphrase_keys: Default::default(),
             events_notifier,
         }
     }
@@ -104,15 +148,20 @@ impl BackupWatcher {
         *backup_request_sender = Some(sender);
     }
 
-    pub(crate) async fn start(&self, mut quit_receiver: watch::Receiver<()>) -> Result<()> {
-        let worker = BackupWorker::new(
+    fn worker(&self) -> BackupWorker {
+        BackupWorker::new(
             self.config.working_dir.clone(),
             self.inner.clone(),
             self.persister.clone(),
             self.encryption_key.clone(),
             self.legacy_encryption_key.clone(),
+            self.passphrase_keys.clone(),
             self.events_notifier.clone(),
-        );
+        )
+    }
+
+    pub(crate) async fn start(&self, mut quit_receiver: watch::Receiver<()>) -> Result<()> {
+        let worker = self.worker();
 
         let mut hooks_subscription = self.persister.subscribe_hooks();
         let (backup_request_sender, mut backup_request_receiver) =
@@ -122,6 +171,10 @@ impl BackupWatcher {
         let rt = Builder::new_current_thread().enable_all().build()?;
         std::thread::spawn(move || {
             rt.block_on(async move {
+                let mut remote_poll = tokio::time::interval_at(
+                    tokio::time::Instant::now() + REMOTE_POLL_INTERVAL,
+                    REMOTE_POLL_INTERVAL,
+                );
                 loop {
                     tokio::select! {
 
@@ -173,6 +226,12 @@ impl BackupWatcher {
                             }
                         }
                       },
+                      // We periodically merge the changes other apps pushed to the remote state
+                      _ = remote_poll.tick() => {
+                        if let Err(e) = worker.merge_remote_changes().await {
+                         error!("Failed to merge remote changes {e}");
+                        }
+                      },
                       // We also want to exit if we receive a quit signal
                       _ = quit_receiver.changed() => {
                         return
@@ -189,6 +248,111 @@ impl BackupWatcher {
         self.events_notifier.subscribe()
     }
 
+    /// Replaces the local sync state with the state that was pushed as the given version.
+    ///
+    /// The versions that can be restored are the ones kept in the local versions history.
+    pub(crate) async fn restore_version(&self, version: u64) -> Result<()> {
+        let sync_version = self
+            .persister
+            .get_sync_version(version)?
+            .ok_or_else(|| anyhow!("Backup version {version} not found"))?;
+        self.worker().restore(sync_version.data)?;
+        _ = self
+            .events_notifier
+            .send(BreezEvent::BackupRestored { version });
+        Ok(())
+    }
+
+    /// Merges the remote state into the local state, in case it is newer.
+    pub(crate) async fn merge_remote_changes(&self) -> Result<()> {
+        self.worker().merge_remote_changes().await
+    }
+
+    /// Pulls the remote backup and compares it with the local sync state.
+    pub(crate) async fn verify(&self) -> Result<BackupVerificationReport> {
+        self.worker().verify().await
+    }
+
+    /// Sets, changes or removes (when `None`) the user passphrase used for the backup encryption.
+    ///
+    /// The key derived from the passphrase (with scrypt) is combined with the seed based
+    /// encryption key, so the backups pushed from now on can't be decrypted with the seed alone.
+    ///
+    /// Neither the passphrase nor its key are stored, so the current passphrase has to be set
+    /// again after every start, the backups are not pushed until then. Only the keys of the
+    /// replaced passphrases are stored, encrypted with the current key, so the versions pushed
+    /// before a change can still be decrypted. Removing the passphrase also drops them.
+    pub(crate) async fn set_passphrase(&self, passphrase: Option<String>) -> Result<()> {
+        let key = match passphrase {
+            Some(passphrase) => {
+                let salt = self.encryption_key.clone();
+                Some(
+                    tokio::task::spawn_blocking(move || derive_scrypt_key(&passphrase, &salt))
+                        .await??,
+                )
+            }
+            None => None,
+        };
+
+        let keyring = self.persister.get_backup_passphrase_keyring()?;
+        let mut keys = self
+            .passphrase_keys
+            .lock()
+            .map_err(|_| anyhow!("Failed to lock the backup passphrase keys"))?;
+        match key {
+            Some(key) => {
+                match keyring.as_ref().and_then(|k| aes_decrypt(&key, k)) {
+                    // The current passphrase was set again, e.g. after a restart
+                    Some(previous) => {
+                        keys.previous = previous.chunks(32).map(|k| k.to_vec()).collect()
+                    }
+                    // A new passphrase, the current one is kept to decrypt the older versions
+                    None if keyring.is_some() => {
+                        let current = keys.current.take().ok_or(anyhow!(
+                            "The current backup passphrase has to be set before changing it"
+                        ))?;
+                        keys.previous.retain(|k| *k != key);
+                        keys.previous.push(current);
+                    }
+                    None => {}
+                }
+                let sealed = aes_encrypt(&key, &keys.previous.concat())
+                    .ok_or(anyhow!("Failed to encrypt the backup passphrase keyring"))?;
+                self.persister.set_backup_passphrase_keyring(Some(sealed))?;
+                keys.current = Some(key);
+            }
+            None => {
+                ensure!(
+                    keyring.is_none() || keys.current.is_some(),
+                    "The current backup passphrase has to be set before removing it"
+                );
+                self.persister.set_backup_passphrase_keyring(None)?;
+                *keys = PassphraseKeys::default();
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the sync state and the static channel backup to a single file, encrypted with
+    /// a key derived from the given passphrase.
+    ///
+    /// The file starts with a plain header (magic, format version, KDF rounds and salt) followed
+    /// by the AES-GCM encrypted and compressed content, so tampering with any part of it makes
+    /// the decryption fail.
+    ///
+    /// The key derivation is CPU bound, so it runs on a blocking thread.
+    pub(crate) async fn export_to_file(&self, path: String, passphrase: String) -> Result<()> {
+        let worker = self.worker();
+        tokio::task::spawn_blocking(move || worker.export_to_file(&path, &passphrase)).await?
+    }
+
+    /// Merges the sync state and the static channel backup of a file created by
+    /// [BackupWatcher::export_to_file] into the local state.
+    pub(crate) async fn import_from_file(&self, path: String, passphrase: String) -> Result<()> {
+        let worker = self.worker();
+        tokio::task::spawn_blocking(move || worker.import_from_file(&path, &passphrase)).await?
+    }
+
     pub(crate) async fn request_backup(&self, req: BackupRequest) -> Result<()> {
         let request_handler = self.backup_request_sender.lock().await;
         let h = request_handler.clone();
@@ -204,9 +368,10 @@ impl BackupWatcher {
 struct BackupWorker {
     working_dir_path: String,
     inner: Arc<dyn BackupTransport>,
-    persister: Arc<SqliteStorage>,
+    persister: Arc<dyn Storage>,
     encryption_key: Vec<u8>,
     legacy_encryption_key: Vec<u8>,
+    passphrase_keys: Arc<std::sync::Mutex<PassphraseKeys>>,
     events_notifier: broadcast::Sender<BreezEvent>,
 }
 
@@ -214,9 +379,10 @@ impl BackupWorker {
     pub(crate) fn new(
         working_dir_path: String,
         inner: Arc<dyn BackupTransport>,
-        persister: Arc<SqliteStorage>,
+        persister: Arc<dyn Storage>,
         encryption_key: Vec<u8>,
         legacy_encryption_key: Vec<u8>,
+        passphrase_keys: Arc<std::sync::Mutex<PassphraseKeys>>,
         events_notifier: broadcast::Sender<BreezEvent>,
     ) -> Self {
         Self {
@@ -225,10 +391,41 @@ impl BackupWorker {
             persister,
             encryption_key,
             legacy_encryption_key,
+            passphrase_keys,
             events_notifier,
         }
     }
 
+    /// The key used to encrypt the backups: the seed based key, combined with the passphrase
+    /// key when a passphrase is set.
+    ///
+    /// Fails when a passphrase was configured but not set again since the start, rather than
+    /// pushing backups that are only protected by the seed.
+    fn current_encryption_key(&self) -> Result<Vec<u8>> {
+        let passphrase_key = self
+            .passphrase_keys
+            .lock()
+            .map_err(|_| anyhow!("Failed to lock the backup passphrase keys"))?
+            .current
+            .clone();
+        match passphrase_key {
+            Some(passphrase_key) => Ok(self.combined_key(passphrase_key)),
+            None => {
+                ensure!(
+                    self.persister.get_backup_passphrase_keyring()?.is_none(),
+                    "The backup passphrase has to be set before pushing backups"
+                );
+                Ok(self.encryption_key.clone())
+            }
+        }
+    }
+
+    fn combined_key(&self, passphrase_key: Vec<u8>) -> Vec<u8> {
+        sha256::Hash::hash(&[self.encryption_key.clone(), passphrase_key].concat())
+            .into_inner()
+            .to_vec()
+    }
+
     async fn notify(&self, e: BreezEvent) -> Result<()> {
         // we don't care for errors here as this happens if
         // there ar eno subscribers, just ignoring them.
@@ -242,9 +439,16 @@ impl BackupWorker {
         if !force && last_sync_request_id == 0 {
             return Ok(());
         }
-        let sync_dir = self.sync_dir()?;
+        // The other backup operations use the sync directory at the same time, so the sync
+        // works in a directory of its own
+        let sync_dir = tempdir_in(self.sync_dir()?)?;
+        let sync_dir_path = sync_dir
+            .path()
+            .to_str()
+            .ok_or(anyhow!("Invalid sync directory path"))?
+            .to_string();
         let notify_res = match self
-            .sync_internal(sync_dir.clone(), last_sync_request_id)
+            .sync_internal(sync_dir_path, last_sync_request_id)
             .await
         {
             Ok(_) => {
@@ -261,7 +465,7 @@ impl BackupWorker {
                 .await
             }
         };
-        fs::remove_dir_all(Path::new(sync_dir.as_str()))?;
+        sync_dir.close()?;
 
         match notify_res {
             Ok(r) => Ok(r),
@@ -362,8 +566,10 @@ impl BackupWorker {
 
                 // Bidirectionaly sync the local and remote changes
                 self.persister
-                    .import_remote_changes(&remote_storage, true)?;
-                remote_storage.import_remote_changes(self.persister.as_ref(), false)?;
+                    .import_remote_changes(Path::new(&remote_storage.sync_db_path()), true)?;
+                let (_local_tmp_dir, local_storage) = self.temp_storage(&local_data)?;
+                remote_storage
+                    .import_remote_changes(Path::new(&local_storage.sync_db_path()), false)?;
                 *last_sync_request_id = self.persister.get_last_sync_request()?.unwrap_or_default();
 
                 let mut hex = vec![];
@@ -384,18 +590,240 @@ impl BackupWorker {
         }
     }
 
+    /// Merges the remote state into the local state, in case another app pushed it since
+    /// the last local sync.
+    ///
+    /// Merged rows add sync requests, so the local state (now including the remote changes)
+    /// is pushed on top of the merged version right after.
+    async fn merge_remote_changes(&self) -> Result<()> {
+        let last_version = self.persister.get_last_sync_version()?;
+        let remote_state = match self.inner.pull().await? {
+            Some(state) if last_version.is_none_or(|v| v < state.generation) => state,
+            _ => return Ok(()),
+        };
+        debug!(
+            "Remote state changed, last_version = {:?}, remote version = {}",
+            last_version, remote_state.generation
+        );
+
+        let decrypted = self.decrypt(&remote_state.data)?;
+        let decompressed = decompress_to_vec_with_limit(&decrypted, 4000000)
+            .map_err(|e| anyhow!("Failed to decompress backup: {e}"))?;
+        let (_tmp_dir, remote_storage) = self.temp_storage(&decompressed)?;
+        self.persister
+            .import_remote_changes(Path::new(&remote_storage.sync_db_path()), true)?;
+        self.persister
+            .set_last_sync_version(remote_state.generation, &remote_state.data)?;
+        info!("Merged remote state version {}", remote_state.generation);
+        self.notify(BreezEvent::RemoteStateChanged).await
+    }
+
+    /// Restores the local sync state from an encrypted backup, dropping any local changes
+    /// that are not part of it.
+    fn restore(&self, encrypted_data: Vec<u8>) -> Result<()> {
+        let data = self.decrypt(&encrypted_data)?;
+        let decompressed = decompress_to_vec_with_limit(&data, 4000000)
+            .map_err(|e| anyhow!("Failed to decompress backup: {e}"))?;
+
+        let (_tmp_dir, restored_storage) = self.temp_storage(&decompressed)?;
+        self.persister
+            .restore_remote_changes(Path::new(&restored_storage.sync_db_path()))
+    }
+
+    async fn verify(&self) -> Result<BackupVerificationReport> {
+        let local_version = self.persister.get_last_sync_version()?;
+        let local_storage_file = tempfile::NamedTempFile::new_in(self.sync_dir()?)?;
+        self.persister.backup(local_storage_file.path())?;
+        let local_summary = SqliteStorage::sync_db_summary(
+            local_storage_file
+                .path()
+                .to_str()
+                .ok_or(anyhow!("Invalid local storage path"))?
+                .to_string(),
+        )?;
+
+        let remote_state = match self.inner.pull().await? {
+            Some(state) => state,
+            None => {
+                return Ok(BackupVerificationReport {
+                    remote_generation: None,
+                    local_version,
+                    integrity_ok: false,
+                    in_sync: false,
+                    diff: vec![],
+                })
+            }
+        };
+        // A remote backup that can't be opened is reported, not returned as an error
+        let remote_summary = match self.remote_summary(&remote_state.data) {
+            Ok(summary) => Some(summary),
+            Err(e) => {
+                warn!("Failed to open the remote backup: {e}");
+                None
+            }
+        };
+        let integrity_ok = remote_summary.as_ref().is_some_and(|s| s.integrity_ok);
+
+        let diff: Vec<BackupTableDiff> = local_summary
+            .tables
+            .iter()
+            .map(|local| {
+                let remote = remote_summary
+                    .as_ref()
+                    .and_then(|s| s.tables.iter().find(|t| t.table == local.table));
+                BackupTableDiff {
+                    table: local.table.clone(),
+                    local_rows: local.rows,
+                    remote_rows: remote.map(|t| t.rows).unwrap_or_default(),
+                    in_sync: remote.is_some_and(|t| t.hash == local.hash),
+                }
+            })
+            .collect();
+
+        Ok(BackupVerificationReport {
+            remote_generation: Some(remote_state.generation),
+            local_version,
+            integrity_ok,
+            in_sync: integrity_ok && diff.iter().all(|d| d.in_sync),
+            diff,
+        })
+    }
+
+    /// Decrypts and opens an encrypted backup to summarize its content.
+    fn remote_summary(&self, encrypted_data: &[u8]) -> Result<SyncDbSummary> {
+        let decrypted = self.decrypt(encrypted_data)?;
+        let decompressed = decompress_to_vec_with_limit(&decrypted, 4000000)
+            .map_err(|e| anyhow!("Failed to decompress backup: {e}"))?;
+        let (_tmp_dir, remote_storage) = self.temp_storage(&decompressed)?;
+        SqliteStorage::sync_db_summary(remote_storage.sync_db_path())
+    }
+
+    fn export_to_file(&self, path: &str, passphrase: &str) -> Result<()> {
+        let sync_dir = self.sync_dir()?;
+        let local_storage_file = tempfile::NamedTempFile::new_in(sync_dir)?;
+        self.persister.backup(local_storage_file.path())?;
+        let mut sync_db = vec![];
+        File::open(local_storage_file.path())?.read_to_end(&mut sync_db)?;
+
+        let content = BackupFileContent {
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            sync_db: base64::encode(sync_db),
+            static_backup: self.persister.get_static_backup()?,
+        };
+        let compressed_content = compress_to_vec(&serde_json::to_vec(&content)?, 10);
+
+        let salt: [u8; BACKUP_FILE_SALT_LEN] = rand::thread_rng().gen();
+        let key = derive_passphrase_key(passphrase, &salt, PASSPHRASE_KDF_ROUNDS);
+        let encrypted_content = aes_encrypt(key.as_slice(), compressed_content.as_slice())
+            .ok_or(anyhow!("Failed to encrypt backup file"))?;
+
+        let mut f = File::create(path)?;
+        f.write_all(BACKUP_FILE_MAGIC)?;
+        f.write_all(&[BACKUP_FILE_VERSION])?;
+        f.write_all(&PASSPHRASE_KDF_ROUNDS.to_be_bytes())?;
+        f.write_all(&salt)?;
+        f.write_all(&encrypted_content)?;
+        f.flush()?;
+        info!("Backup exported to {path}");
+        Ok(())
+    }
+
+    fn import_from_file(&self, path: &str, passphrase: &str) -> Result<()> {
+        let mut data = vec![];
+        File::open(path)?.read_to_end(&mut data)?;
+        ensure!(
+            data.len() > BACKUP_FILE_HEADER_LEN && data.starts_with(BACKUP_FILE_MAGIC),
+            "Not a backup file"
+        );
+
+        let (header, encrypted_content) = data.split_at(BACKUP_FILE_HEADER_LEN);
+        let version = header[BACKUP_FILE_MAGIC.len()];
+        ensure!(
+            version == BACKUP_FILE_VERSION,
+            "Unsupported backup file version {version}"
+        );
+        let rounds_start = BACKUP_FILE_MAGIC.len() + 1;
+        let rounds = u32::from_be_bytes(header[rounds_start..rounds_start + 4].try_into()?);
+        ensure!(
+            rounds > 0 && rounds <= BACKUP_FILE_MAX_KDF_ROUNDS,
+            "Invalid backup file KDF rounds {rounds}"
+        );
+        let salt = &header[rounds_start + 4..];
+
+        let key = derive_passphrase_key(passphrase, salt, rounds);
+        let compressed_content = aes_decrypt(key.as_slice(), encrypted_content)
+            .ok_or(anyhow!("Failed to decrypt backup file, wrong passphrase?"))?;
+        let content: BackupFileContent = serde_json::from_slice(
+            &decompress_to_vec_with_limit(&compressed_content, 4000000)
+                .map_err(|e| anyhow!("Failed to decompress backup file: {e}"))?,
+        )?;
+
+        let (_tmp_dir, imported_storage) = self.temp_storage(&base64::decode(content.sync_db)?)?;
+        self.persister
+            .import_remote_changes(Path::new(&imported_storage.sync_db_path()), true)?;
+        // The node keeps the static backup up to date, so we only use the imported one
+        // when there is none locally (e.g. when migrating to a new device).
+        if let Some(static_backup) = content.static_backup {
+            if self.persister.get_static_backup()?.is_none() {
+                self.persister.set_static_backup(static_backup)?;
+            }
+        }
+        info!("Backup imported from {path}");
+        Ok(())
+    }
+
+    /// Writes the given sync DB content to a temporary storage, which is deleted together with
+    /// the returned [TempDir].
+    fn temp_storage(&self, sync_db: &[u8]) -> Result<(TempDir, SqliteStorage)> {
+        let tmp_dir = tempdir_in(self.sync_dir()?)?;
+        let mut storage_file = File::create(tmp_dir.path().join("sync_storage.sql"))?;
+        storage_file.write_all(sync_db)?;
+        storage_file.flush()?;
+        let storage = SqliteStorage::new(
+            tmp_dir
+                .path()
+                .as_os_str()
+                .to_str()
+                .ok_or(anyhow!("Invalid temporary storage path"))?
+                .to_string(),
+        );
+        Ok((tmp_dir, storage))
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let passphrase_keys: Vec<Vec<u8>> = {
+            let keys = self
+                .passphrase_keys
+                .lock()
+                .map_err(|_| anyhow!("Failed to lock the backup passphrase keys"))?;
+            keys.current
+                .iter()
+                .chain(keys.previous.iter().rev())
+                .map(|k| self.combined_key(k.clone()))
+                .collect()
+        };
+        let mut decrypted = passphrase_keys
+            .iter()
+            .find_map(|key| aes_decrypt(key.as_slice(), data));
+        // Backups pushed before a passphrase was set are only encrypted with the seed based key
+        if decrypted.is_none() {
+            if !passphrase_keys.is_empty() {
+                warn!("Failed to decrypt backup with passphrase keys, trying seed key");
+            }
+            decrypted = aes_decrypt(self.encryption_key.as_slice(), data);
+        }
+        if decrypted.is_none() {
+            warn!("Failed to decrypt backup with new key, trying legacy key");
+            decrypted = aes_decrypt(self.legacy_encryption_key.as_slice(), data);
+        }
+        decrypted.ok_or(anyhow!("Failed to decrypt backup"))
+    }
+
     async fn pull(&self) -> Result<Option<BackupState>> {
         let state = self.inner.pull().await?;
         match state {
             Some(state) => {
-                let mut decrypted =
-                    aes_decrypt(self.encryption_key.as_slice(), state.data.as_slice());
-                if decrypted.is_none() {
-                    warn!("Failed to decrypt backup with new key, trying legacy key");
-                    decrypted =
-                        aes_decrypt(self.legacy_encryption_key.as_slice(), state.data.as_slice());
-                }
-                let decrypted_data = decrypted.ok_or(anyhow!("Failed to decrypt backup"))?;
+                let decrypted_data = self.decrypt(state.data.as_slice())?;
                 match decompress_to_vec_with_limit(&decrypted_data, 4000000) {
                     Ok(decompressed) => Ok(Some(BackupState {
                         generation: state.generation,
@@ -417,9 +845,11 @@ impl BackupWorker {
             "Pushing compressed data with size = {}",
             compressed_data.len()
         );
-        let encrypted_data =
-            aes_encrypt(self.encryption_key.as_slice(), compressed_data.as_slice())
-                .ok_or(anyhow!("Failed to encrypt backup"))?;
+        let encrypted_data = aes_encrypt(
+            self.current_encryption_key()?.as_slice(),
+            compressed_data.as_slice(),
+        )
+        .ok_or(anyhow!("Failed to encrypt backup"))?;
         let version = self.inner.push(version, encrypted_data.clone()).await?;
         Ok((version, encrypted_data))
     }
@@ -446,11 +876,12 @@ mod tests {
     use crate::test_utils::get_test_ofp_48h;
     use crate::{
         backup::BackupRequest,
-        persist::db::SqliteStorage,
+        persist::storage::Storage,
         test_utils::{create_test_config, create_test_persister, MockBackupTransport},
         BreezEvent, SwapInfo,
     };
-    use std::{sync::Arc, vec};
+    use ecies::utils::{aes_decrypt, aes_encrypt};
+    use std::{path::Path, sync::Arc, vec};
     use tokio::sync::{broadcast::Receiver, watch};
     use tokio::{
         spawn,
@@ -736,14 +1167,11 @@ mod tests {
 
             persister.set_last_sync_version(10, &vec![]).unwrap();
             // Remove the data frmo the sql database and change the sync version to cause conflict.
+            let empty_storage = create_test_persister(create_test_config());
+            empty_storage.init().unwrap();
             persister
-                    .get_connection()
-                    .unwrap()
-                    .execute(
-                        "delete from sync.swaps; delete from sync.reverse_swaps; delete from sync.payment_external_info;",
-                        [],
-                    )
-                    .unwrap();
+                .restore_remote_changes(Path::new(&empty_storage.sync_db_path()))
+                .unwrap();
             watcher
                 .request_backup(BackupRequest::new(true))
                 .await
@@ -757,7 +1185,279 @@ mod tests {
         quit_sender.closed().await;
     }
 
-    fn populate_sync_table(persister: Arc<SqliteStorage>) {
+    // Test that restoring an older version drops the changes that were pushed after it
+    #[tokio::test]
+    async fn test_restore_version() {
+        let (quit_sender, watcher, _) = create_test_backup_watcher().await;
+        let persister = watcher.persister.clone();
+
+        let subscription = watcher.subscribe_events();
+        populate_sync_table(persister.clone());
+        wait_for_backup_success(subscription).await;
+        let first_version = persister.get_last_sync_version().unwrap().unwrap();
+
+        let subscription = watcher.subscribe_events();
+        let mut second_swap = persister.list_swaps().unwrap()[0].clone();
+        second_swap.bitcoin_address = String::from("2");
+        second_swap.payment_hash = vec![6];
+        second_swap.preimage = vec![7];
+        second_swap.private_key = vec![8];
+        second_swap.public_key = vec![9];
+        second_swap.swapper_public_key = vec![10];
+        second_swap.script = vec![10];
+        persister.insert_swap(second_swap).unwrap();
+        wait_for_backup_success(subscription).await;
+        assert_eq!(persister.list_swaps().unwrap().len(), 2);
+
+        let subscription = watcher.subscribe_events();
+        watcher.restore_version(first_version).await.unwrap();
+        wait_for_event(
+            subscription,
+            BreezEvent::BackupRestored {
+                version: first_version,
+            },
+        )
+        .await;
+        assert_eq!(persister.list_swaps().unwrap().len(), 1);
+        assert!(watcher.restore_version(first_version + 10).await.is_err());
+
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+    }
+
+    // Test that a remote state pushed by another app is merged into the local state
+    #[tokio::test]
+    async fn test_merge_remote_changes() {
+        let (quit_sender, watcher, transport) = create_test_backup_watcher().await;
+        let subscription = watcher.subscribe_events();
+        populate_sync_table(watcher.persister.clone());
+        wait_for_backup_success(subscription).await;
+        let remote_state = transport.state.lock().unwrap().clone().unwrap();
+
+        let (other_quit_sender, other_watcher, other_transport) =
+            create_test_backup_watcher().await;
+        *other_transport.remote_version.lock().unwrap() = Some(remote_state.generation);
+        *other_transport.state.lock().unwrap() = Some(remote_state.clone());
+
+        // The merge triggers a backup, so other events may be emitted before
+        let subscription = other_watcher.subscribe_events();
+        other_watcher.worker().merge_remote_changes().await.unwrap();
+        wait_for_event(subscription, BreezEvent::RemoteStateChanged).await;
+        assert_eq!(
+            other_watcher.persister.list_swaps().unwrap(),
+            watcher.persister.list_swaps().unwrap()
+        );
+        assert!(
+            other_watcher.persister.get_last_sync_version().unwrap()
+                >= Some(remote_state.generation)
+        );
+
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+        _ = other_quit_sender.send(());
+        other_quit_sender.closed().await;
+    }
+
+    // Test that an exported backup file can only be imported with the right passphrase
+    #[tokio::test]
+    async fn test_export_import_file() {
+        let (quit_sender, watcher, _) = create_test_backup_watcher().await;
+        populate_sync_table(watcher.persister.clone());
+        watcher
+            .persister
+            .set_static_backup(vec!["scb".into()])
+            .unwrap();
+        let file_path = format!("{}/backup.breez", watcher.config.working_dir);
+        watcher
+            .export_to_file(file_path.clone(), "passphrase".into())
+            .await
+            .unwrap();
+
+        let (other_quit_sender, other_watcher, _) = create_test_backup_watcher().await;
+        assert!(other_watcher
+            .import_from_file(file_path.clone(), "wrong passphrase".into())
+            .await
+            .is_err());
+        other_watcher
+            .import_from_file(file_path.clone(), "passphrase".into())
+            .await
+            .unwrap();
+        assert_eq!(
+            other_watcher.persister.list_swaps().unwrap(),
+            watcher.persister.list_swaps().unwrap()
+        );
+        assert_eq!(
+            other_watcher.persister.get_static_backup().unwrap(),
+            Some(vec!["scb".into()])
+        );
+
+        // A file claiming an unbounded number of KDF rounds is rejected before deriving the key
+        let mut data = std::fs::read(&file_path).unwrap();
+        let rounds_start = super::BACKUP_FILE_MAGIC.len() + 1;
+        data[rounds_start..rounds_start + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+        let crafted_path = format!("{}/crafted.breez", watcher.config.working_dir);
+        std::fs::write(&crafted_path, data).unwrap();
+        let err = other_watcher
+            .import_from_file(crafted_path, "passphrase".into())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("KDF rounds"));
+
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+        _ = other_quit_sender.send(());
+        other_quit_sender.closed().await;
+    }
+
+    // Test that the verification detects local changes that were not pushed yet
+    #[tokio::test]
+    async fn test_verify() {
+        let (quit_sender, watcher, transport) = create_test_backup_watcher().await;
+        let report = watcher.verify().await.unwrap();
+        assert_eq!(report.remote_generation, None);
+
+        let subscription = watcher.subscribe_events();
+        populate_sync_table(watcher.persister.clone());
+        wait_for_backup_success(subscription).await;
+        let report = watcher.verify().await.unwrap();
+        assert_eq!(
+            report.remote_generation,
+            watcher.persister.get_last_sync_version().unwrap()
+        );
+        assert!(report.integrity_ok);
+        assert!(report.in_sync);
+
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+        watcher
+            .persister
+            .insert_open_channel_payment_info("123", 100000)
+            .unwrap();
+        let report = watcher.verify().await.unwrap();
+        assert!(!report.in_sync);
+        let diff = report
+            .diff
+            .iter()
+            .find(|d| d.table == "open_channel_payment_info")
+            .unwrap();
+        assert_eq!((diff.local_rows, diff.remote_rows), (1, 0));
+
+        // A remote backup that can't be decrypted is reported as such
+        transport.state.lock().unwrap().as_mut().unwrap().data = vec![1, 2, 3];
+        let report = watcher.verify().await.unwrap();
+        assert!(report.remote_generation.is_some());
+        assert!(!report.integrity_ok);
+        assert!(!report.in_sync);
+    }
+
+    // Test that backups pushed after setting a passphrase can't be decrypted with the seed key alone
+    #[tokio::test]
+    async fn test_passphrase() {
+        let (quit_sender, watcher, transport) = create_test_backup_watcher().await;
+        watcher
+            .set_passphrase(Some("passphrase".into()))
+            .await
+            .unwrap();
+        // Only the (empty) keyring, encrypted with the passphrase key, is stored
+        let keyring = watcher
+            .persister
+            .get_backup_passphrase_keyring()
+            .unwrap()
+            .unwrap();
+        assert!(aes_decrypt(&[0; 32], &keyring).is_none());
+
+        let subscription = watcher.subscribe_events();
+        populate_sync_table(watcher.persister.clone());
+        wait_for_backup_success(subscription).await;
+
+        let pushed = transport.state.lock().unwrap().clone().unwrap().data;
+        assert!(aes_decrypt(&[0; 32], &pushed).is_none());
+        assert!(watcher.worker().decrypt(&pushed).is_ok());
+
+        // After a restart nothing is pushed until the passphrase is set again
+        let restarted = BackupWatcher::new(
+            watcher.config.clone(),
+            transport.clone(),
+            watcher.persister.clone(),
+            vec![0; 32],
+            vec![0; 32],
+        );
+        assert!(restarted.worker().current_encryption_key().is_err());
+        assert!(restarted.worker().decrypt(&pushed).is_err());
+        restarted
+            .set_passphrase(Some("passphrase".into()))
+            .await
+            .unwrap();
+        assert!(restarted.worker().decrypt(&pushed).is_ok());
+
+        watcher.set_passphrase(None).await.unwrap();
+        assert!(watcher
+            .persister
+            .get_backup_passphrase_keyring()
+            .unwrap()
+            .is_none());
+
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+    }
+
+    // Test that the versions encrypted with a replaced passphrase can still be decrypted
+    #[tokio::test]
+    async fn test_change_passphrase() {
+        let (quit_sender, watcher, transport) = create_test_backup_watcher().await;
+        watcher.set_passphrase(Some("A".into())).await.unwrap();
+        let subscription = watcher.subscribe_events();
+        populate_sync_table(watcher.persister.clone());
+        wait_for_backup_success(subscription).await;
+        let pushed_with_a = transport.state.lock().unwrap().clone().unwrap().data;
+
+        watcher.set_passphrase(Some("B".into())).await.unwrap();
+        let pushed_with_b = aes_encrypt(
+            &watcher.worker().current_encryption_key().unwrap(),
+            &[1, 2, 3],
+        )
+        .unwrap();
+        assert!(watcher.worker().decrypt(&pushed_with_a).is_ok());
+        assert!(watcher.worker().decrypt(&pushed_with_b).is_ok());
+
+        // A device still on A decrypts the versions pushed with A
+        let (other_quit_sender, other_watcher, _) = create_test_backup_watcher().await;
+        other_watcher
+            .set_passphrase(Some("A".into()))
+            .await
+            .unwrap();
+        assert!(other_watcher.worker().decrypt(&pushed_with_a).is_ok());
+
+        // After a restart, setting B again restores the key of A from the keyring
+        let restarted = BackupWatcher::new(
+            watcher.config.clone(),
+            transport.clone(),
+            watcher.persister.clone(),
+            vec![0; 32],
+            vec![0; 32],
+        );
+        restarted.set_passphrase(Some("B".into())).await.unwrap();
+        assert!(restarted.worker().decrypt(&pushed_with_a).is_ok());
+        assert!(restarted.worker().decrypt(&pushed_with_b).is_ok());
+
+        // Changing the passphrase requires the current one first
+        let restarted = BackupWatcher::new(
+            watcher.config.clone(),
+            transport.clone(),
+            watcher.persister.clone(),
+            vec![0; 32],
+            vec![0; 32],
+        );
+        assert!(restarted.set_passphrase(Some("C".into())).await.is_err());
+        assert!(restarted.set_passphrase(None).await.is_err());
+
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+        _ = other_quit_sender.send(());
+        other_quit_sender.closed().await;
+    }
+
+    fn populate_sync_table(persister: Arc<dyn Storage>) {
         let tested_swap_info = SwapInfo {
             bitcoin_address: String::from("1"),
             created_at: 0,
@@ -791,4 +1491,17 @@ mod tests {
             }
         }
     }
+
+    async fn wait_for_event(mut subscription: Receiver<BreezEvent>, expected: BreezEvent) {
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while let Ok(event) = subscription.recv().await {
+                if event == expected {
+                    return;
+                }
+            }
+            panic!("Event {expected:?} was not emitted");
+        })
+        .await
+        .unwrap();
+    }
 }
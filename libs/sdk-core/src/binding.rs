@@ -19,25 +19,33 @@ use log::{Level, LevelFilter, Metadata, Record};
 use once_cell::sync::{Lazy, OnceCell};
 use tokio::sync::Mutex;
 
-use crate::breez_services::{self, BreezEvent, BreezServices, EventListener};
+use crate::breez_services::{
+    self, BreezEvent, BreezServices, EventListener, PersistedEvent, PersistedEventListener,
+};
 use crate::chain::RecommendedFees;
 use crate::error::SdkError;
-use crate::fiat::{FiatCurrency, Rate};
+use crate::fiat::{FiatCurrency, FiatRates, Rate};
+use crate::format::BitcoinUnit;
 use crate::input_parser::{self, InputType, LnUrlAuthRequestData};
 use crate::invoice::{self, LNInvoice};
 use crate::lnurl::pay::model::LnUrlPayResult;
 use crate::lsp::LspInformation;
 use crate::models::{Config, LogEntry, NodeState, Payment, SwapInfo};
 use crate::{
-    BackupStatus, BuyBitcoinRequest, BuyBitcoinResponse, CheckMessageRequest, CheckMessageResponse,
-    EnvironmentType, ListPaymentsRequest, LnUrlCallbackStatus, LnUrlPayRequest,
-    LnUrlWithdrawRequest, LnUrlWithdrawResult, NodeConfig, OpenChannelFeeRequest,
-    OpenChannelFeeResponse, PrepareRefundRequest, PrepareRefundResponse, PrepareSweepRequest,
-    PrepareSweepResponse, ReceiveOnchainRequest, ReceivePaymentRequest, ReceivePaymentResponse,
-    RefundRequest, RefundResponse, ReverseSwapFeesRequest, ReverseSwapInfo, ReverseSwapPairInfo,
-    SendOnchainRequest, SendOnchainResponse, SendPaymentRequest, SendPaymentResponse,
-    SendSpontaneousPaymentRequest, SignMessageRequest, SignMessageResponse, StaticBackupRequest,
-    StaticBackupResponse, SweepRequest, SweepResponse,
+    BackupStatus, BackupVerificationReport, BackupVersion, BuyBitcoinRequest, BuyBitcoinResponse,
+    BuyInboundLiquidityRequest, BuyInboundLiquidityResponse, ChangeBackupPassphraseRequest,
+    CheckMessageRequest, CheckMessageResponse, DbMaintenanceReport, DbMaintenanceRequest,
+    EnvironmentType, ExportPaymentsRequest, ListPaymentsRequest, LnUrlCallbackStatus,
+    LnUrlPayRequest, LnUrlWithdrawRequest, LnUrlWithdrawResult, NodeConfig, OpenChannelFeeRequest,
+    OpenChannelFeeResponse, PrepareBuyInboundLiquidityRequest, PrepareBuyInboundLiquidityResponse,
+    PrepareRefundRequest, PrepareRefundResponse, PrepareSweepRequest, PrepareSweepResponse,
+    ProcessNotificationRequest, ProcessNotificationResult, ReceiveFiatPaymentRequest,
+    ReceiveFiatPaymentResponse, ReceiveOnchainRequest, ReceivePaymentRequest,
+    ReceivePaymentResponse, RefundRequest, RefundResponse, ReverseSwapFeesRequest, ReverseSwapInfo,
+    ReverseSwapPairInfo, SendFiatPaymentRequest, SendFiatPaymentResponse, SendOnchainRequest,
+    SendOnchainResponse, SendPaymentRequest, SendPaymentResponse, SendSpontaneousPaymentRequest,
+    SignMessageRequest, SignMessageResponse, StaticBackupRequest, StaticBackupResponse,
+    SweepRequest, SweepResponse,
 };
 
 /*
@@ -114,6 +122,23 @@ pub fn check_message(req: CheckMessageRequest) -> Result<CheckMessageResponse> {
     block_on(async { get_breez_services().await?.check_message(req).await })
 }
 
+/// See [BreezServices::process_notification]
+pub fn process_notification(req: ProcessNotificationRequest) -> Result<ProcessNotificationResult> {
+    block_on(async { get_breez_services().await?.process_notification(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::register_webhook]
+pub fn register_webhook(webhook_url: String) -> Result<()> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .register_webhook(webhook_url)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Breez Services Helper API's */
 
 /// See [breez_services::mnemonic_to_seed]
@@ -145,6 +170,28 @@ pub fn breez_events_stream(s: StreamSink<BreezEvent>) -> Result<()> {
     Ok(())
 }
 
+/// Adds a stream of SDK events, next to the one set with [breez_events_stream]. Unlike it, this
+/// must be called after `connect`.
+///
+/// Returns the id to pass to [remove_event_stream].
+pub fn add_event_stream(s: StreamSink<PersistedEvent>) -> Result<String> {
+    block_on(async {
+        let breez_services = get_breez_services().await?;
+        Ok(breez_services.add_event_listener(Box::new(StreamEventListener { stream: s })))
+    })
+}
+
+/// See [BreezServices::remove_event_listener]
+pub fn remove_event_stream(id: String) -> Result<()> {
+    block_on(async { get_breez_services().await?.remove_event_listener(id) })
+}
+
+/// See [BreezServices::events_since]
+pub fn events_since(seq: u64) -> Result<Vec<PersistedEvent>> {
+    block_on(async { get_breez_services().await?.events_since(seq) })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// If used, this must be called before `connect`. It can only be called once.
 pub fn breez_log_stream(s: StreamSink<LogEntry>) -> Result<()> {
     LOG_INIT
@@ -167,6 +214,12 @@ pub fn connect_lsp(lsp_id: String) -> Result<()> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::connect_lsp_by_uri]
+pub fn connect_lsp_by_uri(uri: String) -> Result<LspInformation> {
+    block_on(async { get_breez_services().await?.connect_lsp_by_uri(uri).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::lsp_id]
 pub fn lsp_id() -> Result<Option<String>> {
     block_on(async { get_breez_services().await?.lsp_id().await }).map_err(anyhow::Error::new)
@@ -190,6 +243,27 @@ pub fn close_lsp_channels() -> Result<()> {
     })
 }
 
+/// See [BreezServices::prepare_buy_inbound_liquidity]
+pub fn prepare_buy_inbound_liquidity(
+    req: PrepareBuyInboundLiquidityRequest,
+) -> Result<PrepareBuyInboundLiquidityResponse> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .prepare_buy_inbound_liquidity(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::buy_inbound_liquidity]
+pub fn buy_inbound_liquidity(
+    req: BuyInboundLiquidityRequest,
+) -> Result<BuyInboundLiquidityResponse> {
+    block_on(async { get_breez_services().await?.buy_inbound_liquidity(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Backup API's */
 
 /// See [BreezServices::backup]
@@ -202,6 +276,59 @@ pub fn backup_status() -> Result<BackupStatus> {
     block_on(async { get_breez_services().await?.backup_status() })
 }
 
+/// See [BreezServices::verify_backup]
+pub fn verify_backup() -> Result<BackupVerificationReport> {
+    block_on(async { get_breez_services().await?.verify_backup().await })
+}
+
+/// See [BreezServices::list_backup_versions]
+pub fn list_backup_versions() -> Result<Vec<BackupVersion>> {
+    block_on(async { get_breez_services().await?.list_backup_versions() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::restore_backup]
+pub fn restore_backup(version: u64) -> Result<()> {
+    block_on(async { get_breez_services().await?.restore_backup(version).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::change_backup_passphrase]
+pub fn change_backup_passphrase(req: ChangeBackupPassphraseRequest) -> Result<()> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .change_backup_passphrase(req)
+            .await
+    })
+}
+
+/// See [BreezServices::export_backup]
+pub fn export_backup(path: String, passphrase: String) -> Result<()> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .export_backup(path, passphrase)
+            .await
+    })
+}
+
+/// See [BreezServices::import_backup]
+pub fn import_backup(path: String, passphrase: String) -> Result<()> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .import_backup(path, passphrase)
+            .await
+    })
+}
+
+/// See [BreezServices::db_maintenance]
+pub fn db_maintenance(req: DbMaintenanceRequest) -> Result<DbMaintenanceReport> {
+    block_on(async { get_breez_services().await?.db_maintenance(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Parse API's */
 
 pub fn parse_invoice(invoice: String) -> Result<LNInvoice> {
@@ -225,6 +352,23 @@ pub fn payment_by_hash(hash: String) -> Result<Option<Payment>> {
     block_on(async { get_breez_services().await?.payment_by_hash(hash).await })
 }
 
+/// See [BreezServices::export_payments]
+pub fn export_payments(req: ExportPaymentsRequest) -> Result<String> {
+    block_on(async { get_breez_services().await?.export_payments(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::set_payment_metadata]
+pub fn set_payment_metadata(hash: String, note: Option<String>, tags: Vec<String>) -> Result<()> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .set_payment_metadata(hash, note, tags)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Lightning Payment API's */
 
 /// See [BreezServices::send_payment]
@@ -250,6 +394,18 @@ pub fn receive_payment(req: ReceivePaymentRequest) -> Result<ReceivePaymentRespo
         .map_err(anyhow::Error::new)
 }
 
+/// See [BreezServices::send_fiat_payment]
+pub fn send_fiat_payment(req: SendFiatPaymentRequest) -> Result<SendFiatPaymentResponse> {
+    block_on(async { get_breez_services().await?.send_fiat_payment(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::receive_fiat_payment]
+pub fn receive_fiat_payment(req: ReceiveFiatPaymentRequest) -> Result<ReceiveFiatPaymentResponse> {
+    block_on(async { get_breez_services().await?.receive_fiat_payment(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  LNURL API's */
 
 /// See [BreezServices::lnurl_pay]
@@ -274,6 +430,21 @@ pub fn fetch_fiat_rates() -> Result<Vec<Rate>> {
     block_on(async { get_breez_services().await?.fetch_fiat_rates().await })
 }
 
+/// See [BreezServices::fiat_rates]
+pub fn fiat_rates() -> Result<FiatRates> {
+    block_on(async { get_breez_services().await?.fiat_rates().await })
+}
+
+/// See [crate::format_fiat]
+pub fn format_fiat(amount: f64, currency_id: String, locale: String) -> Result<String> {
+    crate::format::format_fiat(amount, &currency_id, &locale)
+}
+
+/// See [crate::format_sats]
+pub fn format_sats(amount_msat: u64, unit: BitcoinUnit, locale: String) -> String {
+    crate::format::format_sats(amount_msat, unit, &locale)
+}
+
 /// See [BreezServices::list_fiat_currencies]
 pub fn list_fiat_currencies() -> Result<Vec<FiatCurrency>> {
     block_on(async { get_breez_services().await?.list_fiat_currencies().await })
@@ -388,6 +559,16 @@ impl EventListener for BindingEventListener {
     }
 }
 
+struct StreamEventListener {
+    stream: StreamSink<PersistedEvent>,
+}
+
+impl PersistedEventListener for StreamEventListener {
+    fn on_event(&self, e: PersistedEvent) {
+        self.stream.add(e);
+    }
+}
+
 struct BindingLogger {
     log_stream: StreamSink<LogEntry>,
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::str::FromStr;
@@ -10,8 +11,12 @@ use bitcoin::hashes::hex::ToHex;
 use bitcoin::hashes::{sha256, Hash};
 use bitcoin::util::bip32::ChildNumber;
 use chrono::Local;
+use futures::Stream;
 use log::{LevelFilter, Metadata, Record};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use tokio::sync::broadcast::{self, error::RecvError};
 use tokio::sync::{mpsc, watch, Mutex};
 use tokio::time::sleep;
 use tonic::codegen::InterceptedService;
@@ -23,9 +28,14 @@ use tonic::{Request, Status};
 use crate::backup::{BackupRequest, BackupTransport, BackupWatcher};
 use crate::boltzswap::BoltzApi;
 use crate::chain::{ChainService, MempoolSpace, Outspend, RecommendedFees};
+use crate::crypt::storage_key;
 use crate::error::{SdkError, SdkResult};
-use crate::fiat::{FiatCurrency, Rate};
+use crate::export;
+use crate::fiat::{
+    fiat_to_msat, FiatCurrency, FiatRates, FiatService, HttpFiatAPI, PaymentFiatRate, Rate,
+};
 use crate::greenlight::{GLBackupTransport, Greenlight};
+use crate::grpc::chain_notifier_client::ChainNotifierClient;
 use crate::grpc::channel_opener_client::ChannelOpenerClient;
 use crate::grpc::fund_manager_client::FundManagerClient;
 use crate::grpc::information_client::InformationClient;
@@ -40,28 +50,54 @@ use crate::lnurl::pay::model::{
 };
 use crate::lnurl::pay::validate_lnurl_pay;
 use crate::lnurl::withdraw::validate_lnurl_withdraw;
-use crate::lsp::LspInformation;
+use crate::lsp::{LspInformation, LspManager};
+use crate::lsps0;
+use crate::lsps1;
+use crate::lsps2;
+use crate::lsps5;
 use crate::models::{
-    parse_short_channel_id, ChannelState, ClosedChannelPaymentDetails, Config, EnvironmentType,
-    FiatAPI, LnUrlCallbackStatus, LspAPI, NodeAPI, NodeState, Payment, PaymentDetails, PaymentType,
-    ReverseSwapPairInfo, ReverseSwapServiceAPI, SwapInfo, SwapperAPI,
+    parse_short_channel_id, ChainNotifierAPI, ChannelState, ClosedChannelPaymentDetails, Config,
+    EnvironmentType, FiatAPI, LnUrlCallbackStatus, LspAPI, NodeAPI, NodeState, Payment,
+    PaymentDetails, PaymentType, ReverseSwapPairInfo, ReverseSwapServiceAPI, SwapInfo, SwapperAPI,
     INVOICE_PAYMENT_FEE_EXPIRY_SECONDS,
 };
 use crate::moonpay::MoonPayApi;
+use crate::notifications::Notification;
 use crate::persist::db::SqliteStorage;
+use crate::persist::storage::Storage;
 use crate::reverseswap::BTCSendSwap;
 use crate::swap::BTCReceiveSwap;
 use crate::BuyBitcoinProvider::Moonpay;
 use crate::*;
 
+/// Timeout of the requests to the LSP over LSPS0
+const LSPS_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Timeout of the check of the protocols the LSP supports. It's short, as LSPs that don't support
+/// LSPS0 never reply.
+const LSPS_PROTOCOLS_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long an LSP is known not to support LSPS2 before its protocols are listed again
+const LSPS2_UNSUPPORTED_TTL: Duration = Duration::from_secs(600);
+/// Name of the app the webhook is registered for with the LSP over LSPS5
+const LSPS5_APP_NAME: &str = "breez-sdk";
+
 /// Trait that can be used to react to various [BreezEvent]s emitted by the SDK.
 pub trait EventListener: Send + Sync {
     fn on_event(&self, e: BreezEvent);
 }
 
+/// Trait of the listeners added with [BreezServices::add_event_listener], which get the emitted
+/// events with their sequence number in the event log.
+pub trait PersistedEventListener: Send + Sync {
+    fn on_event(&self, e: PersistedEvent);
+}
+
 /// Event emitted by the SDK. To listen for and react to these events, use an [EventListener] when
-/// initializing the [BreezServices].
-#[derive(Clone, Debug, PartialEq)]
+/// initializing the [BreezServices], add one with [BreezServices::add_event_listener] or use
+/// [BreezServices::subscribe_events].
+///
+/// Emitted events are also kept in an event log, see [BreezServices::events_since], except for
+/// the frequent [BreezEvent::NewBlock] and [BreezEvent::Synced] ones.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum BreezEvent {
     /// Indicates that a new block has just been found
@@ -80,14 +116,72 @@ pub enum BreezEvent {
     BackupSucceeded,
     /// Indicates that the backup process has just failed
     BackupFailed { details: BackupFailedData },
+    /// Indicates that changes pushed to the remote backup by another app were merged into the local state
+    RemoteStateChanged,
+    /// Indicates that a channel has been opened and is ready to be used
+    ChannelOpened { funding_txid: String },
+    /// Indicates that the closing of a channel has been initiated
+    ChannelPendingClose { funding_txid: String },
+    /// Indicates that a channel has been closed. The closing txid may be unknown if it could not
+    /// be retrieved.
+    ChannelClosed {
+        funding_txid: String,
+        closing_txid: Option<String>,
+    },
+    /// Indicates that the balance of the node has changed
+    BalanceChanged {
+        previous: NodeBalance,
+        current: NodeBalance,
+    },
+    /// Indicates that an order bought with [BreezServices::buy_inbound_liquidity] has changed
+    LiquidityOrderUpdated { details: LiquidityOrder },
+    /// Indicates that the local sync state was restored from a backup version, see
+    /// [BreezServices::restore_backup]
+    BackupRestored { version: u64 },
 }
 
-#[derive(Clone, Debug, PartialEq)]
+impl BreezEvent {
+    /// Whether the event is kept in the event log. The events emitted on every block or sync
+    /// would otherwise quickly push the others out of it.
+    fn is_logged(&self) -> bool {
+        !matches!(self, BreezEvent::NewBlock { .. } | BreezEvent::Synced)
+    }
+}
+
+/// An emitted [BreezEvent], as kept in the event log
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PersistedEvent {
+    /// Increasing sequence number of the event, to pass to [BreezServices::events_since] to get
+    /// the events emitted after it. It is 0 for the events that were not logged, because they
+    /// are not kept in the event log, it is disabled or it couldn't be written.
+    pub seq: u64,
+    /// Epoch time, in seconds, at which the event was emitted
+    pub created_at: u64,
+    pub event: BreezEvent,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BackupFailedData {
     pub error: String,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// The balances of the node, included as payload in [BreezEvent::BalanceChanged]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeBalance {
+    pub channels_balance_msat: u64,
+    pub onchain_balance_msat: u64,
+}
+
+impl From<&NodeState> for NodeBalance {
+    fn from(node_state: &NodeState) -> Self {
+        NodeBalance {
+            channels_balance_msat: node_state.channels_balance_msat,
+            onchain_balance_msat: node_state.onchain_balance_msat,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PaymentFailedData {
     pub error: String,
     pub node_id: String,
@@ -95,7 +189,7 @@ pub struct PaymentFailedData {
 }
 
 /// Details of an invoice that has been paid, included as payload in an emitted [BreezEvent]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InvoicePaidDetails {
     pub payment_hash: String,
     pub bolt11: String,
@@ -141,17 +235,23 @@ pub struct CheckMessageResponse {
 
 /// BreezServices is a facade and the single entry point for the SDK.
 pub struct BreezServices {
+    config: Config,
     started: Mutex<bool>,
     node_api: Arc<dyn NodeAPI>,
     lsp_api: Arc<dyn LspAPI>,
-    fiat_api: Arc<dyn FiatAPI>,
+    fiat_service: Arc<FiatService>,
+    lsp_manager: Arc<LspManager>,
     moonpay_api: Arc<dyn MoonPayApi>,
+    chain_notifier_api: Arc<dyn ChainNotifierAPI>,
     chain_service: Arc<dyn ChainService>,
-    persister: Arc<SqliteStorage>,
+    persister: Arc<dyn Storage>,
     payment_receiver: Arc<PaymentReceiver>,
     btc_receive_swapper: Arc<BTCReceiveSwap>,
     btc_send_swapper: Arc<BTCSendSwap>,
     event_listener: Option<Box<dyn EventListener>>,
+    event_listeners: std::sync::Mutex<HashMap<String, Arc<dyn PersistedEventListener>>>,
+    events_notifier: broadcast::Sender<PersistedEvent>,
+    lsps0_transport: Arc<lsps0::Transport>,
     backup_watcher: Arc<BackupWatcher>,
     shutdown_sender: watch::Sender<()>,
     shutdown_receiver: watch::Receiver<()>,
@@ -174,15 +274,23 @@ impl BreezServices {
         seed: Vec<u8>,
         event_listener: Box<dyn EventListener>,
     ) -> SdkResult<Arc<BreezServices>> {
-        let start = Instant::now();
-        let services = BreezServicesBuilder::new(config)
+        BreezServicesBuilder::new(config)
             .seed(seed)
-            .build(Some(event_listener))
-            .await?;
-        services.start().await?;
-        let connect_duration = start.elapsed();
-        info!("SDK connected in: {:?}", connect_duration);
-        Ok(services)
+            .connect(event_listener)
+            .await
+    }
+
+    /// Creates the SDK services without starting the signer or any background task, so that a
+    /// notification can be processed with [BreezServices::process_notification] while the app is
+    /// woken up in the background.
+    pub async fn connect_for_notification(
+        config: Config,
+        seed: Vec<u8>,
+    ) -> SdkResult<Arc<BreezServices>> {
+        BreezServicesBuilder::new(config)
+            .seed(seed)
+            .build(None)
+            .await
     }
 
     /// Internal utility method that starts the BreezServices background tasks for this instance.
@@ -396,11 +504,161 @@ impl BreezServices {
     /// This also works when the node doesn't have any channels and need inbound liquidity.
     /// In such case when the invoice is paid a new zero-conf channel will be open by the LSP,
     /// providing inbound liquidity and the payment will be routed via this new channel.
+    ///
+    /// If the LSP supports LSPS2, the channel is bought as a JIT channel over LSPS2. In that case,
+    /// any given [ReceivePaymentRequest::opening_fee_params] must come from the LSPS2 fee menu.
+    ///
+    /// If the selected LSP is unreachable and [Config::auto_select_lsp] is set, the payment is
+    /// received through another LSP, see [Config::persist_lsp_failover].
     pub async fn receive_payment(
         &self,
         req: ReceivePaymentRequest,
     ) -> SdkResult<ReceivePaymentResponse> {
-        self.payment_receiver.receive_payment(req).await
+        let lsp_info = self.current_lsp().await?;
+        self.payment_receiver
+            .receive_payment_via(req, lsp_info)
+            .await
+    }
+
+    /// Creates a bolt11 payment request for an amount given in a fiat currency.
+    ///
+    /// The amount is converted with the current rate of that currency, which is returned in the
+    /// [FiatQuote] and holds until the invoice expires. The fiat amount and rate are appended to
+    /// the invoice description and the rate is recorded with the payment, see
+    /// [Payment::fiat_rates].
+    pub async fn receive_fiat_payment(
+        &self,
+        req: ReceiveFiatPaymentRequest,
+    ) -> SdkResult<ReceiveFiatPaymentResponse> {
+        let (rate, amount_msat) = self
+            .fiat_quote(&req.fiat_currency, req.fiat_amount)
+            .await
+            .map_err(|err| SdkError::ReceivePaymentFailed {
+                err: err.to_string(),
+            })?;
+        let description = format!(
+            "{} ({} {} at {} {}/BTC)",
+            req.description, req.fiat_amount, req.fiat_currency, rate, req.fiat_currency
+        );
+        let res = self
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat,
+                description,
+                preimage: None,
+                opening_fee_params: req.opening_fee_params,
+                use_description_hash: req.use_description_hash,
+                expiry: req.expiry,
+                cltv: req.cltv,
+            })
+            .await?;
+        // The invoice can be paid even if its rate isn't recorded
+        if let Err(e) = self.persister.insert_payment_fiat_rates(
+            &res.ln_invoice.payment_hash,
+            &[PaymentFiatRate::from(&Rate {
+                coin: req.fiat_currency.clone(),
+                value: rate,
+            })],
+            true,
+        ) {
+            warn!(
+                "Failed to record the fiat rate of payment {}: {e}",
+                res.ln_invoice.payment_hash
+            );
+        }
+
+        Ok(ReceiveFiatPaymentResponse {
+            quote: FiatQuote {
+                fiat_currency: req.fiat_currency,
+                fiat_amount: req.fiat_amount,
+                rate,
+                amount_msat,
+                expires_at: res.ln_invoice.timestamp + res.ln_invoice.expiry,
+            },
+            ln_invoice: res.ln_invoice,
+            opening_fee_params: res.opening_fee_params,
+            opening_fee_msat: res.opening_fee_msat,
+        })
+    }
+
+    /// Pays a bolt11 invoice without amount, for an amount given in a fiat currency.
+    ///
+    /// The amount is converted with the current rate of that currency, which is returned in the
+    /// [FiatQuote] and recorded with the payment, see [Payment::fiat_rates].
+    pub async fn send_fiat_payment(
+        &self,
+        req: SendFiatPaymentRequest,
+    ) -> SdkResult<SendFiatPaymentResponse> {
+        let parsed_invoice = parse_invoice(req.bolt11.as_str())?;
+        ensure_sdk!(
+            parsed_invoice.amount_msat.is_none(),
+            SdkError::SendPaymentFailed {
+                err: "The invoice has an amount, pay it with send_payment instead".into()
+            }
+        );
+        let (rate, amount_msat) = self
+            .fiat_quote(&req.fiat_currency, req.fiat_amount)
+            .await
+            .map_err(|err| SdkError::SendPaymentFailed {
+                err: err.to_string(),
+            })?;
+        let res = self
+            .send_payment(SendPaymentRequest {
+                bolt11: req.bolt11,
+                amount_msat: Some(amount_msat),
+            })
+            .await?;
+        // The quoted rate replaces the one recorded when the payment completed
+        if let Err(e) = self.persister.insert_payment_fiat_rates(
+            &parsed_invoice.payment_hash,
+            &[PaymentFiatRate::from(&Rate {
+                coin: req.fiat_currency.clone(),
+                value: rate,
+            })],
+            true,
+        ) {
+            warn!(
+                "Failed to record the fiat rate of payment {}: {e}",
+                parsed_invoice.payment_hash
+            );
+        }
+
+        Ok(SendFiatPaymentResponse {
+            payment: res.payment,
+            quote: FiatQuote {
+                fiat_currency: req.fiat_currency,
+                fiat_amount: req.fiat_amount,
+                rate,
+                amount_msat,
+                expires_at: parsed_invoice.timestamp + parsed_invoice.expiry,
+            },
+        })
+    }
+
+    /// Fetches the current fiat rates and converts the fiat amount with the rate of the given
+    /// currency. Returns the rate used and the converted amount.
+    ///
+    /// Fails if the rates couldn't be refreshed or are outdated, see [FiatRates::is_fresh].
+    async fn fiat_quote(&self, currency: &str, fiat_amount: f64) -> Result<(f64, u64)> {
+        ensure!(
+            fiat_amount.is_finite() && fiat_amount > 0.0,
+            "Invalid fiat amount {fiat_amount}"
+        );
+        let fiat_rates = self.fiat_service.fiat_rates().await?;
+        ensure!(
+            fiat_rates.is_fresh(),
+            "The fiat rates are outdated, fetched at {}",
+            fiat_rates.fetched_at
+        );
+        let rate = fiat_rates
+            .rates
+            .iter()
+            .find(|r| r.coin == currency)
+            .map(|r| r.value)
+            .filter(|rate| *rate > 0.0)
+            .ok_or(anyhow!("No exchange rate found for {currency}"))?;
+        let amount_msat = fiat_to_msat(fiat_amount, rate);
+        ensure!(amount_msat > 0, "Fiat amount {fiat_amount} is too small");
+        Ok((rate, amount_msat))
     }
 
     /// Retrieve the node state from the persistent storage.
@@ -431,6 +689,244 @@ impl BreezServices {
         Ok(CheckMessageResponse { is_valid })
     }
 
+    /// Registers a webhook to be notified of events while the app is offline, such as incoming
+    /// payments and transactions to swap addresses. See `doc/SDK_NOTIFICATIONS.md`.
+    ///
+    /// The webhook url is registered with the LSP, over LSPS5 if the LSP supports it, otherwise
+    /// signed with the node key and registered with the LSP API. It is also registered with the
+    /// chain notification service, to which the address of every swap waiting for funds, now and
+    /// in the future, is subscribed.
+    ///
+    /// As the url may change, e.g. with a new push notification token, this should be called
+    /// again on every change. The url is registered again with the LSP on every
+    /// [BreezServices::connect] and whenever another LSP is selected.
+    pub async fn register_webhook(&self, webhook_url: String) -> SdkResult<()> {
+        let lsp_info = self.current_lsp().await?;
+        self.register_lsp_webhook(&lsp_info, &webhook_url).await?;
+
+        let registration_id = self
+            .chain_notifier_api
+            .add_registration(
+                self.persister.get_chain_notifier_registration_id()?,
+                webhook_url.clone(),
+            )
+            .await?;
+        self.persister
+            .set_chain_notifier_registration_id(registration_id)?;
+        self.persister.set_webhook_url(webhook_url)?;
+
+        for swap in self.btc_receive_swapper.list_monitored()? {
+            if let Err(e) = self.subscribe_swap_address(&swap.bitcoin_address).await {
+                warn!(
+                    "Failed to subscribe to the transactions of swap address {}: {e}",
+                    swap.bitcoin_address
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Processes a notification received on the webhook registered with
+    /// [BreezServices::register_webhook], typically delivered by a silent push that wakes up the
+    /// app.
+    ///
+    /// Only what the notification needs is run: the signer, which is already running if the
+    /// services were started with [BreezServices::connect], and either the sync until the
+    /// incoming payment is received or the execution of the pending swaps. If the processing
+    /// doesn't finish before the deadline, [ProcessNotificationResult::DeadlineReached] is
+    /// returned.
+    pub async fn process_notification(
+        &self,
+        req: ProcessNotificationRequest,
+    ) -> SdkResult<ProcessNotificationResult> {
+        let notification = Notification::parse(&req.payload)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(anyhow::Error::new)?
+            .as_secs();
+        let time_left = Duration::from_secs(req.deadline.saturating_sub(now));
+
+        let (signer_shutdown_sender, signer_shutdown_receiver) = mpsc::channel(1);
+        if !*self.started.lock().await {
+            let node_api = self.node_api.clone();
+            tokio::spawn(async move { node_api.start_signer(signer_shutdown_receiver).await });
+        }
+        let res = tokio::time::timeout(time_left, self.handle_notification(notification)).await;
+        _ = signer_shutdown_sender.send(()).await;
+
+        match res {
+            Ok(res) => res,
+            Err(_) => {
+                warn!("The notification was not processed before the deadline");
+                Ok(ProcessNotificationResult::DeadlineReached)
+            }
+        }
+    }
+
+    async fn handle_notification(
+        &self,
+        notification: Notification,
+    ) -> SdkResult<ProcessNotificationResult> {
+        self.start_node().await?;
+        match notification {
+            Notification::PaymentReceived { payment_hash } => {
+                let payment = self.wait_for_incoming_payment(&payment_hash).await?;
+                self.record_fiat_rates(&payment_hash).await;
+                Ok(ProcessNotificationResult::PaymentReceived { payment })
+            }
+            Notification::AddressTxsConfirmed { address } => {
+                let tip = self.chain_service.current_tip().await?;
+                self.btc_receive_swapper.execute_pending_swaps(tip).await?;
+                let swap_info = self
+                    .persister
+                    .get_swap_info_by_address(address.clone())?
+                    .ok_or(SdkError::Generic {
+                        err: format!("No swap found for address {address}"),
+                    })?;
+                Ok(ProcessNotificationResult::SwapUpdated { swap_info })
+            }
+        }
+    }
+
+    /// Waits until the incoming payment is completed, which happens once the signer is up.
+    ///
+    /// The invoice stream is opened before the sync, so the payment can't be settled unnoticed
+    /// in between, and is then followed until the payment is seen.
+    async fn wait_for_incoming_payment(&self, payment_hash: &str) -> SdkResult<Payment> {
+        let payment_hash = payment_hash.to_string();
+        loop {
+            let invoice_stream = self.node_api.stream_incoming_payments().await;
+            self.do_sync(true).await?;
+            if let Some(payment) = self
+                .persister
+                .get_completed_payment_by_hash(&payment_hash)?
+            {
+                return Ok(payment);
+            }
+
+            let mut invoice_stream = invoice_stream?;
+            while let Ok(Some(incoming)) = invoice_stream.message().await {
+                if let Some(gl_client::pb::incoming_payment::Details::Offchain(p)) =
+                    incoming.details
+                {
+                    if hex::encode(&p.payment_hash) != payment_hash {
+                        continue;
+                    }
+                    if let Ok(payment) = Payment::try_from(p) {
+                        self.persister.insert_or_update_payments(&[payment])?;
+                    }
+                    if let Err(e) = self.do_sync(true).await {
+                        error!("failed to sync after paid invoice: {:?}", e);
+                    }
+                    if let Some(payment) = self
+                        .persister
+                        .get_completed_payment_by_hash(&payment_hash)?
+                    {
+                        return Ok(payment);
+                    }
+                }
+            }
+            debug!("invoice stream ended while waiting for payment {payment_hash}");
+            sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    /// Registers the webhook with the given LSP, which must be connected
+    async fn register_lsp_webhook(
+        &self,
+        lsp_info: &LspInformation,
+        webhook_url: &str,
+    ) -> SdkResult<()> {
+        match self.lsps5_client(lsp_info).await? {
+            Some(client) => {
+                let res = client
+                    .set_webhook(lsps5::client::SetWebhookRequest {
+                        app_name: LSPS5_APP_NAME.to_string(),
+                        webhook: webhook_url.to_string(),
+                    })
+                    .await
+                    .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?;
+                debug!(
+                    "Webhook registered over LSPS5 (no change: {}), {} of {} webhooks",
+                    res.no_change, res.num_webhooks, res.max_webhooks
+                );
+            }
+            None => {
+                ensure_sdk!(
+                    !is_custom_lsp(&self.persister, &lsp_info.id)?,
+                    SdkError::Generic {
+                        err: format!("LSP {} doesn't support LSPS5 webhooks", lsp_info.id)
+                    }
+                );
+                let signature = self.node_api.sign_message(webhook_url).await?;
+                self.lsp_api
+                    .subscribe_notifications(
+                        lsp_info.id.clone(),
+                        lsp_info.lsp_pubkey.clone(),
+                        webhook_url.to_string(),
+                        signature,
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers the webhook set with [BreezServices::register_webhook], if any, with the given
+    /// LSP. Failures are only logged, as the webhook is registered again on the next connect.
+    async fn refresh_lsp_webhook(&self, lsp_info: &LspInformation) {
+        match self.persister.get_webhook_url() {
+            Ok(Some(webhook_url)) => {
+                if let Err(e) = self.register_lsp_webhook(lsp_info, &webhook_url).await {
+                    warn!("Failed to register the webhook with the LSP: {e}");
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to read the webhook url: {e}"),
+        }
+    }
+
+    /// Returns an LSPS5 client of the given LSP, if the LSP supports LSPS5
+    async fn lsps5_client(&self, lsp_info: &LspInformation) -> SdkResult<Option<lsps5::Client>> {
+        let peer_id = hex::decode(&lsp_info.pubkey).map_err(|e| SdkError::Generic {
+            err: format!("Invalid LSP pubkey: {e}"),
+        })?;
+        let client = lsps0::Client::new(
+            self.lsps0_transport.clone(),
+            peer_id.clone(),
+            LSPS_PROTOCOLS_TIMEOUT,
+        );
+        let supported = match client.list_protocols().await {
+            Ok(res) => res.protocols.contains(&5),
+            // The LSP doesn't support LSPS0
+            Err(lsps0::Error::Timeout) => false,
+            Err(e) => {
+                warn!("Failed to list the protocols of the LSP: {e}");
+                false
+            }
+        };
+
+        Ok(supported.then(|| {
+            lsps5::Client::new(lsps0::Client::new(
+                self.lsps0_transport.clone(),
+                peer_id,
+                LSPS_REQUEST_TIMEOUT,
+            ))
+        }))
+    }
+
+    /// Subscribes the registered webhook, if any, to the transactions of a swap address
+    async fn subscribe_swap_address(&self, address: &str) -> Result<()> {
+        let webhook_url = self.persister.get_webhook_url()?;
+        let registration_id = self.persister.get_chain_notifier_registration_id()?;
+        if let (Some(webhook_url), Some(registration_id)) = (webhook_url, registration_id) {
+            self.chain_notifier_api
+                .subscribe_address_transactions(registration_id, webhook_url, address.to_string())
+                .await?;
+        }
+        Ok(())
+    }
+
     /// Retrieve the node up to date BackupStatus
     pub fn backup_status(&self) -> Result<BackupStatus> {
         let backup_time = self.persister.get_last_backup_time()?;
@@ -441,6 +937,12 @@ impl BreezServices {
         })
     }
 
+    /// Pull the remote backup and check it can be decrypted, opened and passes the integrity
+    /// check, then compare its content with the local synced state.
+    pub async fn verify_backup(&self) -> Result<BackupVerificationReport> {
+        self.backup_watcher.verify().await
+    }
+
     /// Force running backup
     pub async fn backup(&self) -> Result<()> {
         let (on_complete, mut on_complete_receiver) = mpsc::channel::<Result<()>>(1);
@@ -453,11 +955,198 @@ impl BreezServices {
         }
     }
 
+    /// List the backup versions that are kept locally and can be restored, newest first
+    pub fn list_backup_versions(&self) -> SdkResult<Vec<BackupVersion>> {
+        Ok(self
+            .persister
+            .sync_versions_history()?
+            .into_iter()
+            .map(|v| BackupVersion {
+                version: v.last_version,
+                created_at: v.created_at,
+            })
+            .collect())
+    }
+
+    /// Restore the synced state (swaps, reverse swaps, LNURL metadata, swap fees) to the given
+    /// backup version, as returned by [BreezServices::list_backup_versions].
+    ///
+    /// The local state is replaced by the content of that version and a backup is then forced,
+    /// so the restored state also becomes the latest remote version.
+    pub async fn restore_backup(&self, version: u64) -> SdkResult<()> {
+        self.backup_watcher.restore_version(version).await?;
+        self.backup().await?;
+        Ok(())
+    }
+
+    /// Set, change or remove the user passphrase that is used, in addition to the seed, to
+    /// encrypt the backups.
+    ///
+    /// The local state is then re-encrypted with the new key and pushed as a new backup version.
+    /// When restoring on another device, the same passphrase has to be set there before the
+    /// backup can be read.
+    ///
+    /// The passphrase is not stored, so it has to be set again after every start, the backups
+    /// are not pushed until then. Changing or removing it requires the current one to be set
+    /// first. The versions encrypted with a replaced passphrase can still be read.
+    pub async fn change_backup_passphrase(&self, req: ChangeBackupPassphraseRequest) -> Result<()> {
+        self.backup_watcher.set_passphrase(req.passphrase).await?;
+        self.backup().await
+    }
+
+    /// Export the synced state (swaps, reverse swaps, LNURL metadata, swap fees) and the static
+    /// channel backup to a single file at `path`, encrypted with the given passphrase.
+    ///
+    /// The file doesn't depend on the configured backup transport and can be imported on another
+    /// device with [BreezServices::import_backup].
+    pub async fn export_backup(&self, path: String, passphrase: String) -> Result<()> {
+        self.backup_watcher.export_to_file(path, passphrase).await
+    }
+
+    /// Import a file created by [BreezServices::export_backup], merging its content into the
+    /// local state. The merged state is then pushed to the remote backup as usual.
+    pub async fn import_backup(&self, path: String, passphrase: String) -> Result<()> {
+        self.backup_watcher.import_from_file(path, passphrase).await
+    }
+
+    /// Check the integrity of the local databases and run the periodic housekeeping.
+    ///
+    /// A corrupted sync DB is restored from the remote backup and a corrupted main DB is rebuilt
+    /// from the node, keeping the settings and cached items that could still be read. Then failed
+    /// payments and backup versions are pruned according to the request, cached items left over
+    /// by older versions are deleted and the databases are vacuumed.
+    pub async fn db_maintenance(
+        &self,
+        req: DbMaintenanceRequest,
+    ) -> SdkResult<DbMaintenanceReport> {
+        let integrity = self.persister.check_integrity();
+        let mut report = DbMaintenanceReport {
+            main_db_ok: integrity.main_ok,
+            sync_db_ok: integrity.sync_ok,
+            recovered_payments: 0,
+            recovered_swaps: 0,
+            recovered_reverse_swaps: 0,
+            pruned_failed_payments: 0,
+            pruned_backup_versions: 0,
+            pruned_cached_items: 0,
+        };
+
+        if !integrity.sync_ok {
+            warn!("Sync DB is corrupted, restoring it from the remote backup");
+            self.persister.reset_sync_db()?;
+            self.backup_watcher.merge_remote_changes().await?;
+            report.recovered_swaps = self.persister.list_swaps()?.len() as u32;
+            report.recovered_reverse_swaps = self.persister.list_reverse_swaps()?.len() as u32;
+        }
+
+        if !integrity.main_ok {
+            warn!("Main DB is corrupted, rebuilding it from the node");
+            self.persister.reset_main_db()?;
+            self.do_sync(true).await?;
+            report.recovered_payments = self
+                .persister
+                .list_payments(ListPaymentsRequest {
+                    filters: None,
+                    from_timestamp: None,
+                    to_timestamp: None,
+                    include_failures: Some(true),
+                    offset: None,
+                    limit: None,
+                    cursor: None,
+                    search: None,
+                    min_amount_msat: None,
+                    max_amount_msat: None,
+                    tags: None,
+                })?
+                .len() as u32;
+        }
+
+        if let Some(days) = req.failed_payments_retention_days {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(anyhow::Error::from)?
+                .as_secs() as i64;
+            let before = now - days as i64 * 86400;
+            report.pruned_failed_payments = self.persister.delete_failed_payments_before(before)?;
+        }
+        if let Some(keep) = req.backup_versions_to_keep {
+            report.pruned_backup_versions = self.persister.prune_sync_versions(keep)?;
+        }
+        report.pruned_cached_items = self.persister.delete_unused_cached_items()?;
+        self.persister.vacuum()?;
+
+        Ok(report)
+    }
+
     /// List payments matching the given filters, as retrieved from persistent storage
     pub async fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
         self.persister.list_payments(req)
     }
 
+    /// Export the payment history of the given time range as CSV or JSON.
+    ///
+    /// Failed payments are left out. Every row includes the LNURL info, the swap, reverse swap or
+    /// channel the payment belongs to and, if a fiat currency is given, the payment amount in that
+    /// currency. The amounts use the rate recorded when the payment was made, see
+    /// [Payment::fiat_rates], or the current rate for payments without recorded rates.
+    pub async fn export_payments(&self, req: ExportPaymentsRequest) -> SdkResult<String> {
+        let payments = self.persister.list_payments(ListPaymentsRequest {
+            filters: None,
+            from_timestamp: req.from_timestamp,
+            to_timestamp: req.to_timestamp,
+            include_failures: None,
+            offset: None,
+            limit: None,
+            cursor: None,
+            search: None,
+            min_amount_msat: None,
+            max_amount_msat: None,
+            tags: None,
+        })?;
+        let current_rate = match &req.fiat_currency {
+            Some(currency)
+                if payments
+                    .iter()
+                    .any(|p| !p.fiat_rates.iter().any(|r| &r.coin == currency)) =>
+            {
+                Some(
+                    self.fiat_service
+                        .fetch_fiat_rates()
+                        .await?
+                        .into_iter()
+                        .find(|r| &r.coin == currency)
+                        .ok_or(SdkError::Generic {
+                            err: format!("No exchange rate found for {currency}"),
+                        })?,
+                )
+            }
+            _ => None,
+        };
+
+        Ok(export::export_payments(
+            payments,
+            &self.persister.list_swaps()?,
+            &self.persister.list_reverse_swaps()?,
+            req.fiat_currency.as_deref(),
+            current_rate.as_ref(),
+            req.format,
+        )?)
+    }
+
+    /// Set the note and tags of the payment with the given hash, replacing the previous ones.
+    ///
+    /// The metadata is part of the synced state, so it is backed up and propagated to the
+    /// other apps using the same node. It is returned in [Payment::metadata] and
+    /// [ListPaymentsRequest::tags] can be used to filter payments by tag.
+    pub async fn set_payment_metadata(
+        &self,
+        hash: String,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> SdkResult<()> {
+        Ok(self.persister.set_payment_metadata(&hash, note, tags)?)
+    }
+
     /// Fetch a specific payment by its hash.
     pub async fn payment_by_hash(&self, hash: String) -> Result<Option<Payment>> {
         self.persister
@@ -482,38 +1171,126 @@ impl BreezServices {
         Ok(response)
     }
 
-    /// Fetch live rates of fiat currencies
+    /// Fetch rates of fiat currencies, see [BreezServices::fiat_rates]
     pub async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
-        self.fiat_api.fetch_fiat_rates().await
+        self.fiat_service.fetch_fiat_rates().await
+    }
+
+    /// Fetch rates of fiat currencies along with the time they were fetched.
+    ///
+    /// The rates are cached for a few minutes. If they can't be refreshed, e.g. when offline, the
+    /// last known rates are returned and marked as stale.
+    pub async fn fiat_rates(&self) -> Result<FiatRates> {
+        self.fiat_service.fiat_rates().await
     }
 
     /// List all supported fiat currencies for which there is a known exchange rate.
     pub async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
-        self.fiat_api.list_fiat_currencies().await
+        self.fiat_service.list_fiat_currencies().await
     }
 
-    /// List available LSPs that can be selected by the user
+    /// List available LSPs that can be selected by the user, including the ones connected
+    /// with [BreezServices::connect_lsp_by_uri]
     pub async fn list_lsps(&self) -> SdkResult<Vec<LspInformation>> {
-        self.lsp_api
-            .list_lsps(self.node_info()?.id)
-            .await
-            .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })
+        self.lsp_manager.list_lsps().await
     }
 
     /// Select the LSP to be used and provide inbound liquidity
     pub async fn connect_lsp(&self, lsp_id: String) -> SdkResult<()> {
-        match self.list_lsps().await?.iter().any(|lsp| lsp.id == lsp_id) {
-            true => {
+        match self
+            .list_lsps()
+            .await?
+            .into_iter()
+            .find(|lsp| lsp.id == lsp_id)
+        {
+            Some(lsp_info) => {
                 self.persister.set_lsp_id(lsp_id)?;
                 self.sync().await?;
+                self.refresh_lsp_webhook(&lsp_info).await;
                 Ok(())
             }
-            false => Err(SdkError::LspConnectFailed {
+            None => Err(SdkError::LspConnectFailed {
                 err: format!("Unknown LSP: {lsp_id}"),
             }),
         }
     }
 
+    /// Select a standards-compliant LSP by its node URI (`pubkey@host:port`)
+    ///
+    /// The LSP must support LSPS2 (JIT channels), which is discovered over LSPS0. The returned
+    /// [LspInformation] is persisted, so the LSP is listed by [BreezServices::list_lsps] and can
+    /// be selected again with [BreezServices::connect_lsp] using the pubkey as the LSP ID.
+    ///
+    /// The fee params in [LspInformation::opening_fee_params_list] are a snapshot taken while
+    /// connecting, [BreezServices::receive_payment] fetches fresh ones from the LSP. The channel
+    /// policy is the one the LSP announces for its public channels, while the channel capacity
+    /// and target confirmations, which LSPS2 doesn't advertise, are left at 0.
+    pub async fn connect_lsp_by_uri(&self, uri: String) -> SdkResult<LspInformation> {
+        let (pubkey, host) = parse_node_uri(&uri)?;
+        self.start_node().await?;
+        self.node_api
+            .connect_peer(pubkey.clone(), host.clone())
+            .await
+            .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?;
+
+        let peer_id = hex::decode(&pubkey).map_err(|e| SdkError::Generic {
+            err: format!("Invalid LSP pubkey: {e}"),
+        })?;
+        let client = lsps0::Client::new(
+            self.lsps0_transport.clone(),
+            peer_id.clone(),
+            LSPS_REQUEST_TIMEOUT,
+        );
+        let protocols = client
+            .list_protocols()
+            .await
+            .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?
+            .protocols;
+        ensure_sdk!(
+            protocols.contains(&2),
+            SdkError::LspOpenChannelNotSupported {
+                err: format!("LSP {pubkey} doesn't support LSPS2, supported: {protocols:?}"),
+            }
+        );
+        // The API key is only sent to the Breez LSPs
+        let opening_fee_params_list = lsps2_info(&lsps2::Client::new(client), &None)
+            .await?
+            .opening_fee_params_menu;
+
+        // LSPS2 doesn't advertise the channel policy, so use the one announced in the gossip
+        let policy = self
+            .node_api
+            .fetch_channel_policy(pubkey.clone())
+            .await?
+            .ok_or_else(|| SdkError::LspConnectFailed {
+                err: format!("No channel policy of LSP {pubkey} found in the gossip"),
+            })?;
+        let lsp_info = LspInformation {
+            id: pubkey.clone(),
+            name: host.clone(),
+            widget_url: String::new(),
+            pubkey: pubkey.clone(),
+            host,
+            channel_capacity: 0,
+            target_conf: 0,
+            base_fee_msat: policy.base_fee_msat as i64,
+            fee_rate: policy.fee_rate_ppm as f64 / 1_000_000.0,
+            time_lock_delta: policy.cltv_expiry_delta,
+            min_htlc_msat: policy.htlc_minimum_msat as i64,
+            lsp_pubkey: peer_id,
+            opening_fee_params_list,
+        };
+        let mut custom_lsps = self.persister.get_custom_lsps()?;
+        custom_lsps.retain(|lsp| lsp.id != pubkey);
+        custom_lsps.push(lsp_info.clone());
+        self.persister.set_custom_lsps(&custom_lsps)?;
+
+        self.persister.set_lsp_id(pubkey)?;
+        self.sync().await?;
+        self.refresh_lsp_webhook(&lsp_info).await;
+        Ok(lsp_info)
+    }
+
     /// Get the current LSP's ID
     pub async fn lsp_id(&self) -> SdkResult<Option<String>> {
         self.persister.get_lsp_id()
@@ -545,15 +1322,27 @@ impl BreezServices {
             });
         }
 
-        // Otherwise we need to calculate the fee for opening a new channel.
+        // Otherwise we need to calculate the fee for opening a new channel, with the fee params
+        // of the LSPS2 menu if the LSP sells JIT channels over LSPS2.
         let lsp_info = self.lsp_info().await?;
-        let used_fee_params = lsp_info
-            .cheapest_open_channel_fee(req.expiry.unwrap_or(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS))?;
+        let expiry = req.expiry.unwrap_or(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS);
+        self.start_node().await?;
+        let used_fee_params = match self.payment_receiver.lsps2_client(&lsp_info.pubkey).await? {
+            Some(client) => {
+                let token = self.payment_receiver.lsps2_token(&lsp_info.id)?;
+                lsps2_info(&client, &token)
+                    .await?
+                    .opening_fee_params_menu
+                    .cheapest_open_channel_fee(expiry)?
+                    .clone()
+            }
+            None => lsp_info.cheapest_open_channel_fee(expiry)?.clone(),
+        };
         let fee_msat = used_fee_params.get_channel_fees_msat_for(req.amount_msat);
 
         Ok(OpenChannelFeeResponse {
             fee_msat,
-            used_fee_params: Some(used_fee_params.clone()),
+            used_fee_params: Some(used_fee_params),
         })
     }
 
@@ -599,6 +1388,15 @@ impl BreezServices {
             .btc_receive_swapper
             .create_swap_address(channel_opening_fees)
             .await?;
+        if let Err(e) = self
+            .subscribe_swap_address(&swap_info.bitcoin_address)
+            .await
+        {
+            warn!(
+                "Failed to subscribe to the transactions of swap address {}: {e}",
+                swap_info.bitcoin_address
+            );
+        }
         Ok(swap_info)
     }
 
@@ -730,12 +1528,27 @@ impl BreezServices {
         );
 
         // update node state and channels state
+        let node_state_before_update = self.persister.get_node_state()?;
         self.persister.set_node_state(&new_data.node_state)?;
 
         let channels_before_update = self.persister.list_channels()?;
         self.persister.update_channels(&new_data.channels)?;
         let channels_after_update = self.persister.list_channels()?;
 
+        // Record the LSP of the new channels
+        let new_channels: Vec<crate::models::Channel> = channels_after_update
+            .iter()
+            .filter(|c| {
+                channels_before_update
+                    .iter()
+                    .all(|b| b.funding_txid != c.funding_txid)
+            })
+            .cloned()
+            .collect();
+        if let Err(e) = self.lsp_manager.record_channel_lsps(&new_channels).await {
+            warn!("Failed to record the LSP of the new channels: {e}");
+        }
+
         // Fetch the static backup if needed and persist it
         if channels_before_update.len() != channels_after_update.len() {
             info!("fetching static backup file from node");
@@ -762,43 +1575,285 @@ impl BreezServices {
         let duration = start.elapsed();
         info!("Sync duration: {:?}", duration);
 
-        self.notify_event_listeners(BreezEvent::Synced).await?;
-        Ok(())
-    }
-
-    /// Connects to the selected LSP, if any
-    async fn connect_lsp_peer(&self) -> Result<()> {
-        if let Ok(lsp_info) = self.lsp_info().await {
-            let node_id = lsp_info.pubkey;
-            let address = lsp_info.host;
-            let lsp_connected = self
-                .node_info()
-                .map(|info| info.connected_peers.iter().any(|e| e == node_id.as_str()))?;
-            if !lsp_connected {
-                debug!("connecting to lsp {}@{}", node_id.clone(), address.clone());
-                self.node_api
-                    .connect_peer(node_id.clone(), address.clone())
-                    .await
-                    .map_err(anyhow::Error::msg)?;
+        // The closing txids are only set while converting the closed channels to payments
+        let mut events = channel_events(
+            &channels_before_update,
+            &self.persister.list_channels()?,
+            node_state_before_update.is_none(),
+        );
+        if let Some(node_state_before_update) = node_state_before_update {
+            let previous = NodeBalance::from(&node_state_before_update);
+            let current = NodeBalance::from(&new_data.node_state);
+            if previous != current {
+                events.push(BreezEvent::BalanceChanged { previous, current });
             }
-            debug!("connected to lsp {}@{}", node_id.clone(), address.clone());
         }
+        for event in events {
+            self.notify_event_listeners(event).await?;
+        }
+
+        self.notify_event_listeners(BreezEvent::Synced).await?;
         Ok(())
     }
 
-    async fn on_payment_completed(
+    /// Orders a channel with inbound liquidity from the current LSP, using LSPS1.
+    ///
+    /// The order is created with the LSP but not paid, so that its fee can be shown before
+    /// buying it with [BreezServices::buy_inbound_liquidity]. Unpaid orders expire on the LSP.
+    pub async fn prepare_buy_inbound_liquidity(
         &self,
-        node_id: String,
-        invoice: Option<LNInvoice>,
-        payment_res: Result<PaymentResponse>,
-    ) -> SdkResult<Payment> {
-        self.do_sync(payment_res.is_ok()).await?;
+        req: PrepareBuyInboundLiquidityRequest,
+    ) -> SdkResult<PrepareBuyInboundLiquidityResponse> {
+        self.start_node().await?;
+        let client = lsps1::Client::new(self.lsps0_client().await?);
+        let options = client
+            .get_info()
+            .await
+            .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?
+            .options;
+        ensure_sdk!(
+            (options.min_initial_lsp_balance_sat..=options.max_initial_lsp_balance_sat)
+                .contains(&req.amount_sat),
+            SdkError::Generic {
+                err: format!(
+                    "Amount must be between {} and {} sat",
+                    options.min_initial_lsp_balance_sat, options.max_initial_lsp_balance_sat
+                )
+            }
+        );
+        ensure_sdk!(
+            req.lease_blocks <= options.max_channel_expiry_blocks,
+            SdkError::Generic {
+                err: format!(
+                    "Lease must be at most {} blocks",
+                    options.max_channel_expiry_blocks
+                )
+            }
+        );
 
-        match payment_res {
-            Ok(payment) => match self.persister.get_payment_by_hash(&payment.payment_hash)? {
-                Some(p) => {
-                    self.notify_event_listeners(BreezEvent::PaymentSucceed { details: p.clone() })
-                        .await?;
+        let order = client
+            .create_order(lsps1::client::CreateOrderRequest {
+                lsp_balance_sat: req.amount_sat,
+                client_balance_sat: 0,
+                required_channel_confirmations: options.min_required_channel_confirmations,
+                funding_confirms_within_blocks: options.min_funding_confirms_within_blocks,
+                channel_expiry_blocks: req.lease_blocks,
+                token: None,
+                refund_onchain_address: None,
+                announce_channel: false,
+            })
+            .await
+            .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?;
+        info!("Created liquidity order {}", order.order_id);
+        validate_liquidity_order(&order, req.amount_sat, req.lease_blocks)?;
+
+        let order = LiquidityOrder::from(order);
+        self.persister.insert_or_update_liquidity_order(&order)?;
+        Ok(PrepareBuyInboundLiquidityResponse { order })
+    }
+
+    /// Buys a channel with inbound liquidity, paying an order prepared with
+    /// [BreezServices::prepare_buy_inbound_liquidity].
+    ///
+    /// The order is checked again with the LSP before paying its invoice, after which the LSP
+    /// opens the channel. The order is then tracked in the background, also after a restart,
+    /// until the channel is open or the order failed, emitting every change as a
+    /// [BreezEvent::LiquidityOrderUpdated].
+    pub async fn buy_inbound_liquidity(
+        self: &Arc<BreezServices>,
+        req: BuyInboundLiquidityRequest,
+    ) -> SdkResult<BuyInboundLiquidityResponse> {
+        let prepared =
+            self.persister
+                .get_liquidity_order(&req.order_id)?
+                .ok_or(SdkError::Generic {
+                    err: format!("Liquidity order {} was not prepared", req.order_id),
+                })?;
+        ensure_sdk!(
+            prepared.state == LiquidityOrderState::Created,
+            SdkError::Generic {
+                err: format!("Liquidity order {} was already paid", req.order_id)
+            }
+        );
+
+        self.start_node().await?;
+        let client = lsps1::Client::new(self.lsps0_client().await?);
+        let order = client
+            .get_order(lsps1::client::GetOrderRequest {
+                order_id: req.order_id,
+            })
+            .await
+            .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?;
+        validate_liquidity_order(&order, prepared.lsp_balance_sat, prepared.lease_blocks)?;
+        ensure_sdk!(
+            order.payment.fee_total_sat == prepared.fee_sat,
+            SdkError::Generic {
+                err: format!(
+                    "The fee of the liquidity order changed from {} to {} sat",
+                    prepared.fee_sat, order.payment.fee_total_sat
+                )
+            }
+        );
+        ensure_sdk!(
+            LiquidityOrder::from(order.clone()).state == LiquidityOrderState::Created,
+            SdkError::Generic {
+                err: format!("Liquidity order {} can't be paid anymore", order.order_id)
+            }
+        );
+
+        self.send_payment(SendPaymentRequest {
+            bolt11: order.payment.bolt11_invoice.clone(),
+            amount_msat: None,
+        })
+        .await?;
+        let order_id = order.order_id.clone();
+        let order = match client
+            .get_order(lsps1::client::GetOrderRequest { order_id })
+            .await
+        {
+            Ok(order) => order.into(),
+            Err(e) => {
+                warn!("Failed to get the paid liquidity order: {e}");
+                LiquidityOrder {
+                    state: LiquidityOrderState::Paid,
+                    ..order.into()
+                }
+            }
+        };
+        self.persister.insert_or_update_liquidity_order(&order)?;
+        self.track_liquidity_order(client, order.clone());
+
+        Ok(BuyInboundLiquidityResponse { order })
+    }
+
+    /// Resumes the tracking of the paid liquidity orders whose channel is not open yet
+    async fn resume_liquidity_orders(self: &Arc<BreezServices>) -> SdkResult<()> {
+        let paid_orders: Vec<LiquidityOrder> = self
+            .persister
+            .list_liquidity_orders()?
+            .into_iter()
+            .filter(|o| o.state == LiquidityOrderState::Paid)
+            .collect();
+        for order in paid_orders {
+            info!(
+                "Resuming the tracking of liquidity order {}",
+                order.order_id
+            );
+            let client = lsps1::Client::new(self.lsps0_client().await?);
+            self.track_liquidity_order(client, order);
+        }
+        Ok(())
+    }
+
+    /// Polls the order until the channel is open or the order failed
+    fn track_liquidity_order(
+        self: &Arc<BreezServices>,
+        client: lsps1::Client,
+        mut order: LiquidityOrder,
+    ) {
+        let cloned = self.clone();
+        tokio::spawn(async move {
+            let mut shutdown_receiver = cloned.shutdown_receiver.clone();
+            let mut interval = tokio::time::interval(Duration::from_secs(30));
+            while !matches!(
+                order.state,
+                LiquidityOrderState::Completed | LiquidityOrderState::Failed
+            ) {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        let req = lsps1::client::GetOrderRequest {
+                            order_id: order.order_id.clone(),
+                        };
+                        match client.get_order(req).await {
+                            Ok(updated) => {
+                                let updated = LiquidityOrder::from(updated);
+                                if updated != order {
+                                    order = updated;
+                                    let res = cloned.persister.insert_or_update_liquidity_order(&order);
+                                    if let Err(e) = res {
+                                        warn!("Failed to persist liquidity order: {e}");
+                                    }
+                                    let event = BreezEvent::LiquidityOrderUpdated {
+                                        details: order.clone(),
+                                    };
+                                    _ = cloned.notify_event_listeners(event).await;
+                                }
+                            }
+                            Err(e) => warn!("Failed to get liquidity order {}: {e}", order.order_id),
+                        }
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Liquidity order tracking has completed");
+                        return;
+                    }
+                }
+            }
+            if order.state == LiquidityOrderState::Completed {
+                _ = cloned.sync().await;
+            }
+        });
+    }
+
+    /// Creates a client of the LSPS messages exchanged with the current LSP, connecting to the
+    /// LSP if needed
+    async fn lsps0_client(&self) -> SdkResult<lsps0::Client> {
+        self.connect_lsp_peer().await?;
+        let lsp_info = self.lsp_info().await?;
+        let peer_id = hex::decode(&lsp_info.pubkey).map_err(|e| SdkError::Generic {
+            err: format!("Invalid LSP pubkey: {e}"),
+        })?;
+        Ok(lsps0::Client::new(
+            self.lsps0_transport.clone(),
+            peer_id,
+            LSPS_REQUEST_TIMEOUT,
+        ))
+    }
+
+    /// Connects to the selected LSP, if any, and returns the LSP connected to. With
+    /// [Config::auto_select_lsp], another LSP is used if the selected one is unreachable, and
+    /// the webhook is registered with it.
+    async fn connect_lsp_peer(&self) -> Result<Option<LspInformation>> {
+        let selected_lsp_id = self.persister.get_lsp_id()?;
+        let lsp_info = self
+            .lsp_manager
+            .connect(
+                self.config.auto_select_lsp,
+                self.config.persist_lsp_failover,
+            )
+            .await?;
+        if let Some(lsp_info) = &lsp_info {
+            if selected_lsp_id.as_ref() != Some(&lsp_info.id) {
+                self.refresh_lsp_webhook(lsp_info).await;
+            }
+        }
+        Ok(lsp_info)
+    }
+
+    /// Connects to the LSP to use for the current call and returns it: the selected LSP, or the
+    /// one used instead when it is unreachable
+    async fn current_lsp(&self) -> SdkResult<LspInformation> {
+        match self.connect_lsp_peer().await? {
+            Some(lsp_info) => Ok(lsp_info),
+            None => Ok(self.lsp_info().await?),
+        }
+    }
+
+    async fn on_payment_completed(
+        &self,
+        node_id: String,
+        invoice: Option<LNInvoice>,
+        payment_res: Result<PaymentResponse>,
+    ) -> SdkResult<Payment> {
+        self.do_sync(payment_res.is_ok()).await?;
+        if let Ok(payment) = &payment_res {
+            self.record_fiat_rates(&payment.payment_hash).await;
+        }
+
+        match payment_res {
+            Ok(payment) => match self.persister.get_payment_by_hash(&payment.payment_hash)? {
+                Some(p) => {
+                    self.notify_event_listeners(BreezEvent::PaymentSucceed { details: p.clone() })
+                        .await?;
                     Ok(p)
                 }
                 None => Err(SdkError::SendPaymentFailed {
@@ -819,12 +1874,59 @@ impl BreezServices {
         }
     }
 
+    /// Records the current rates of the [Config::recorded_fiat_currencies] with the given payment,
+    /// so its fiat value at the time it was made is known later. Failing to fetch the rates
+    /// doesn't fail the payment.
+    async fn record_fiat_rates(&self, payment_hash: &str) {
+        if self.config.recorded_fiat_currencies.is_empty() {
+            return;
+        }
+        let fiat_rates = match self.fiat_service.fiat_rates().await {
+            Ok(fiat_rates) => fiat_rates,
+            Err(e) => {
+                warn!("Failed to record the fiat rates of payment {payment_hash}: {e}");
+                return;
+            }
+        };
+        // Outdated rates would misprice the payment, which is better left without rates
+        if !fiat_rates.is_fresh() {
+            warn!(
+                "Not recording the fiat rates of payment {payment_hash}, fetched at {}",
+                fiat_rates.fetched_at
+            );
+            return;
+        }
+        let rates: Vec<PaymentFiatRate> = fiat_rates
+            .rates
+            .iter()
+            .filter(|r| self.config.recorded_fiat_currencies.contains(&r.coin))
+            .map(PaymentFiatRate::from)
+            .collect();
+        if let Err(e) = self
+            .persister
+            .insert_payment_fiat_rates(payment_hash, &rates, false)
+        {
+            warn!("Failed to record the fiat rates of payment {payment_hash}: {e}");
+        }
+    }
+
     async fn on_event(&self, e: BreezEvent) -> Result<()> {
         debug!("breez services got event {:?}", e);
         self.notify_event_listeners(e.clone()).await
     }
 
     async fn notify_event_listeners(&self, e: BreezEvent) -> Result<()> {
+        let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let mut seq = 0;
+        if self.config.event_log_retention > 0 && e.is_logged() {
+            match self
+                .persister
+                .insert_event(&e, created_at, self.config.event_log_retention)
+            {
+                Ok(inserted_seq) => seq = inserted_seq,
+                Err(err) => warn!("Failed to log event {:?}: {:?}", e, err),
+            }
+        }
         if let Err(err) = self.btc_receive_swapper.on_event(e.clone()).await {
             debug!(
                 "btc_receive_swapper failed to process event {:?}: {:?}",
@@ -841,9 +1943,83 @@ impl BreezServices {
         if self.event_listener.is_some() {
             self.event_listener.as_ref().unwrap().on_event(e.clone())
         }
+        // The listeners are called without holding the lock, so they can add or remove listeners
+        let event_listeners: Vec<Arc<dyn PersistedEventListener>> = self
+            .event_listeners
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect();
+        let persisted_event = PersistedEvent {
+            seq,
+            created_at,
+            event: e,
+        };
+        for listener in event_listeners {
+            listener.on_event(persisted_event.clone());
+        }
+        // Sending only fails when there are no subscribers
+        let _ = self.events_notifier.send(persisted_event);
+        Ok(())
+    }
+
+    /// Adds a listener to SDK events, next to the one given when connecting. The events come with
+    /// their sequence number, so that after a restart the missed ones can be listed with
+    /// [BreezServices::events_since].
+    ///
+    /// Returns the id to pass to [BreezServices::remove_event_listener].
+    pub fn add_event_listener(&self, listener: Box<dyn PersistedEventListener>) -> String {
+        let id = rand::thread_rng().gen::<[u8; 16]>().to_hex();
+        self.event_listeners
+            .lock()
+            .unwrap()
+            .insert(id.clone(), Arc::from(listener));
+        id
+    }
+
+    /// Lists the logged events emitted after the event with sequence number `seq`, oldest first.
+    ///
+    /// Apps can keep the sequence number of the last event they processed and call this after
+    /// restarting or being resumed, to catch up with the events they missed. Use 0 to list all
+    /// the logged events. Only the last [Config::event_log_retention] events are kept, and
+    /// [BreezEvent::NewBlock] and [BreezEvent::Synced] events are not logged.
+    pub fn events_since(&self, seq: u64) -> SdkResult<Vec<PersistedEvent>> {
+        Ok(self.persister.list_events_since(seq)?)
+    }
+
+    /// Removes a listener previously added with [BreezServices::add_event_listener]
+    pub fn remove_event_listener(&self, id: String) -> Result<()> {
+        self.event_listeners
+            .lock()
+            .unwrap()
+            .remove(&id)
+            .ok_or_else(|| anyhow!("Event listener {id} not found"))?;
         Ok(())
     }
 
+    /// Returns a stream of the SDK events emitted from now on, with their sequence number in the
+    /// event log.
+    ///
+    /// Each call creates an independent subscription. Events are dropped, with a warning, for
+    /// subscribers that fall too far behind. They can be listed with [BreezServices::events_since].
+    pub fn subscribe_events(&self) -> impl Stream<Item = PersistedEvent> {
+        futures::stream::unfold(
+            self.events_notifier.subscribe(),
+            |mut receiver| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(e) => return Some((e, receiver)),
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!("Events subscriber lagged, skipped {skipped} events")
+                        }
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            },
+        )
+    }
+
     /// Convenience method to look up LSP info based on current LSP ID
     pub async fn lsp_info(&self) -> Result<LspInformation> {
         get_lsp(self.persister.clone(), self.lsp_api.clone()).await
@@ -874,7 +2050,10 @@ impl BreezServices {
     /// This data enables the user to recover the node in an external core ligntning node.
     /// See here for instructions on how to recover using this data: https://docs.corelightning.org/docs/backup-and-recovery#backing-up-using-static-channel-backup
     pub fn static_backup(req: StaticBackupRequest) -> SdkResult<StaticBackupResponse> {
-        let storage = SqliteStorage::new(req.working_dir);
+        let storage = match storage_key(req.storage_key, req.seed.as_deref())? {
+            Some(key) => SqliteStorage::new_encrypted(req.working_dir, key),
+            None => SqliteStorage::new(req.working_dir),
+        };
         Ok(StaticBackupResponse {
             backup: storage.get_static_backup()?,
         })
@@ -925,6 +2104,9 @@ impl BreezServices {
         // start backup watcher
         self.start_backup_watcher().await?;
 
+        // start the transport of the LSPS messages exchanged with the LSP
+        self.lsps0_transport.start(self.shutdown_receiver.clone());
+
         //track backup events
         self.track_backup_events().await;
 
@@ -937,6 +2119,23 @@ impl BreezServices {
         // track logs
         self.track_logs().await;
 
+        // register the webhook, if any, with the LSP
+        let webhook_breez_services = self.clone();
+        tokio::spawn(async move {
+            match webhook_breez_services.current_lsp().await {
+                Ok(lsp_info) => webhook_breez_services.refresh_lsp_webhook(&lsp_info).await,
+                Err(e) => warn!("Failed to connect to the LSP to register the webhook: {e}"),
+            }
+        });
+
+        // resume the tracking of the paid liquidity orders
+        let liquidity_breez_services = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = liquidity_breez_services.resume_liquidity_orders().await {
+                warn!("Failed to resume the liquidity orders: {e}");
+            }
+        });
+
         // Stop signer on shutdown
         let mut shutdown_receiver = self.shutdown_receiver.clone();
         tokio::spawn(async move {
@@ -1032,6 +2231,7 @@ impl BreezServices {
                                                   if let Err(e) = cloned.do_sync(true).await {
                                                         error!("failed to sync after paid invoice: {:?}", e);
                                                   }
+                                                  cloned.record_fiat_rates(&hex::encode(&p.payment_hash)).await;
                                                   _ = cloned.on_event(BreezEvent::InvoicePaid {
                                                       details: InvoicePaidDetails {
                                                           payment_hash: hex::encode(p.payment_hash),
@@ -1306,6 +2506,8 @@ impl BreezServices {
                     closing_txid,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         })
     }
 }
@@ -1336,21 +2538,102 @@ impl log::Log for GlobalSdkLogger {
     fn flush(&self) {}
 }
 
-/// A helper struct to configure and build BreezServices
-struct BreezServicesBuilder {
+/// Lists the events of the channels that changed state between the two lists.
+///
+/// Channels seen for the first time only trigger an event if they are already open, as closed
+/// channels of a restored node have no transition to report. On the first sync, when there is no
+/// previous node state, the channels seen for the first time trigger no event at all.
+fn channel_events(
+    before: &[crate::models::Channel],
+    after: &[crate::models::Channel],
+    first_sync: bool,
+) -> Vec<BreezEvent> {
+    let states_before: HashMap<&String, &ChannelState> =
+        before.iter().map(|c| (&c.funding_txid, &c.state)).collect();
+
+    after
+        .iter()
+        .filter_map(|c| {
+            let state_before = states_before.get(&c.funding_txid).copied();
+            if state_before == Some(&c.state) || (first_sync && state_before.is_none()) {
+                return None;
+            }
+            let funding_txid = c.funding_txid.clone();
+            match (&c.state, state_before) {
+                (ChannelState::Opened, _) => Some(BreezEvent::ChannelOpened { funding_txid }),
+                (ChannelState::PendingClose, Some(_)) => {
+                    Some(BreezEvent::ChannelPendingClose { funding_txid })
+                }
+                (ChannelState::Closed, Some(_)) => Some(BreezEvent::ChannelClosed {
+                    funding_txid,
+                    closing_txid: c.closing_txid.clone(),
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Checks that the order created by the LSP is the one that was requested and that its invoice
+/// is for the order total, before paying it.
+fn validate_liquidity_order(
+    order: &lsps1::client::Order,
+    lsp_balance_sat: u64,
+    lease_blocks: u32,
+) -> SdkResult<()> {
+    ensure_sdk!(
+        order.lsp_balance_sat == lsp_balance_sat,
+        SdkError::Generic {
+            err: format!(
+                "The LSP balance of the liquidity order is {} sat instead of {lsp_balance_sat} sat",
+                order.lsp_balance_sat
+            )
+        }
+    );
+    ensure_sdk!(
+        order.channel_expiry_blocks == lease_blocks,
+        SdkError::Generic {
+            err: format!(
+                "The lease of the liquidity order is {} blocks instead of {lease_blocks} blocks",
+                order.channel_expiry_blocks
+            )
+        }
+    );
+    let invoice_amount_msat = parse_invoice(&order.payment.bolt11_invoice)?.amount_msat;
+    ensure_sdk!(
+        invoice_amount_msat == Some(order.payment.order_total_sat * 1000),
+        SdkError::Generic {
+            err: format!(
+                "The invoice amount doesn't match the order total of {} sat",
+                order.payment.order_total_sat
+            )
+        }
+    );
+    Ok(())
+}
+
+/// A helper struct to configure and build BreezServices.
+///
+/// Besides what [BreezServices::connect] does, it allows to replace the default storage
+/// ([BreezServicesBuilder::persister]), backup transport and to add fiat rate providers.
+pub struct BreezServicesBuilder {
     config: Config,
     node_api: Option<Arc<dyn NodeAPI>>,
     backup_transport: Option<Arc<dyn BackupTransport>>,
     seed: Option<Vec<u8>>,
     lsp_api: Option<Arc<dyn LspAPI>>,
     fiat_api: Option<Arc<dyn FiatAPI>>,
-    persister: Option<Arc<SqliteStorage>>,
+    /// Additional fiat rate providers, whose rates are aggregated with the ones of `fiat_api`
+    fiat_providers: Vec<Arc<dyn FiatAPI>>,
+    persister: Option<Arc<dyn Storage>>,
     swapper_api: Option<Arc<dyn SwapperAPI>>,
     /// Reverse swap functionality on the Breez Server
     reverse_swapper_api: Option<Arc<dyn ReverseSwapperRoutingAPI>>,
     /// Reverse swap functionality on the 3rd party reverse swap service
     reverse_swap_service_api: Option<Arc<dyn ReverseSwapServiceAPI>>,
     moonpay_api: Option<Arc<dyn MoonPayApi>>,
+    chain_notifier_api: Option<Arc<dyn ChainNotifierAPI>>,
+    chain_service: Option<Arc<dyn ChainService>>,
 }
 
 #[allow(dead_code)]
@@ -1362,46 +2645,72 @@ impl BreezServicesBuilder {
             seed: None,
             lsp_api: None,
             fiat_api: None,
+            fiat_providers: vec![],
             persister: None,
             swapper_api: None,
             reverse_swapper_api: None,
             reverse_swap_service_api: None,
             moonpay_api: None,
+            chain_notifier_api: None,
+            chain_service: None,
             backup_transport: None,
         }
     }
 
-    pub fn node_api(&mut self, node_api: Arc<dyn NodeAPI>) -> &mut Self {
+    pub(crate) fn node_api(&mut self, node_api: Arc<dyn NodeAPI>) -> &mut Self {
         self.node_api = Some(node_api);
         self
     }
 
-    pub fn lsp_api(&mut self, lsp_api: Arc<dyn LspAPI>) -> &mut Self {
+    pub(crate) fn lsp_api(&mut self, lsp_api: Arc<dyn LspAPI>) -> &mut Self {
         self.lsp_api = Some(lsp_api.clone());
         self
     }
 
-    pub fn fiat_api(&mut self, fiat_api: Arc<dyn FiatAPI>) -> &mut Self {
+    pub(crate) fn fiat_api(&mut self, fiat_api: Arc<dyn FiatAPI>) -> &mut Self {
         self.fiat_api = Some(fiat_api.clone());
         self
     }
 
-    pub fn moonpay_api(&mut self, moonpay_api: Arc<dyn MoonPayApi>) -> &mut Self {
+    /// Adds a fiat rate provider, whose rates are aggregated with the ones of the Breez server.
+    ///
+    /// Through the bindings, HTTP rate feeds can be added with [Config::fiat_rates_urls].
+    pub fn fiat_provider(&mut self, fiat_provider: Arc<dyn FiatAPI>) -> &mut Self {
+        self.fiat_providers.push(fiat_provider);
+        self
+    }
+
+    pub(crate) fn moonpay_api(&mut self, moonpay_api: Arc<dyn MoonPayApi>) -> &mut Self {
         self.moonpay_api = Some(moonpay_api.clone());
         self
     }
 
-    pub fn persister(&mut self, persister: Arc<SqliteStorage>) -> &mut Self {
+    pub(crate) fn chain_notifier_api(
+        &mut self,
+        chain_notifier_api: Arc<dyn ChainNotifierAPI>,
+    ) -> &mut Self {
+        self.chain_notifier_api = Some(chain_notifier_api.clone());
+        self
+    }
+
+    pub(crate) fn chain_service(&mut self, chain_service: Arc<dyn ChainService>) -> &mut Self {
+        self.chain_service = Some(chain_service.clone());
+        self
+    }
+
+    /// Sets the storage to use instead of the SQLite one, which is created in the working dir
+    /// and encrypted with [Config::storage_key] or a key derived from the seed
+    pub fn persister(&mut self, persister: Arc<dyn Storage>) -> &mut Self {
         self.persister = Some(persister);
         self
     }
 
-    pub fn swapper_api(&mut self, swapper_api: Arc<dyn SwapperAPI>) -> &mut Self {
+    pub(crate) fn swapper_api(&mut self, swapper_api: Arc<dyn SwapperAPI>) -> &mut Self {
         self.swapper_api = Some(swapper_api.clone());
         self
     }
 
-    pub fn reverse_swapper_api(
+    pub(crate) fn reverse_swapper_api(
         &mut self,
         reverse_swapper_api: Arc<dyn ReverseSwapperRoutingAPI>,
     ) -> &mut Self {
@@ -1409,7 +2718,7 @@ impl BreezServicesBuilder {
         self
     }
 
-    pub fn reverse_swap_service_api(
+    pub(crate) fn reverse_swap_service_api(
         &mut self,
         reverse_swap_service_api: Arc<dyn ReverseSwapServiceAPI>,
     ) -> &mut Self {
@@ -1427,7 +2736,20 @@ impl BreezServicesBuilder {
         self
     }
 
-    pub async fn build(
+    /// Builds the services and starts them, like [BreezServices::connect]
+    pub async fn connect(
+        &self,
+        event_listener: Box<dyn EventListener>,
+    ) -> SdkResult<Arc<BreezServices>> {
+        let start = Instant::now();
+        let services = self.build(Some(event_listener)).await?;
+        services.start().await?;
+        let connect_duration = start.elapsed();
+        info!("SDK connected in: {:?}", connect_duration);
+        Ok(services)
+    }
+
+    pub(crate) async fn build(
         &self,
         event_listener: Option<Box<dyn EventListener>>,
     ) -> SdkResult<Arc<BreezServices>> {
@@ -1437,17 +2759,27 @@ impl BreezServicesBuilder {
             });
         }
 
-        // The storage is implemented via sqlite.
-        let persister = self
-            .persister
-            .clone()
-            .unwrap_or_else(|| Arc::new(SqliteStorage::new(self.config.working_dir.clone())));
+        // The storage is implemented via sqlite, encrypted with the app-provided key or else with
+        // a key derived from the seed.
+        let persister: Arc<dyn Storage> = match self.persister.clone() {
+            Some(persister) => persister,
+            None => match storage_key(self.config.storage_key.clone(), self.seed.as_deref())? {
+                Some(key) => Arc::new(SqliteStorage::new_encrypted(
+                    self.config.working_dir.clone(),
+                    key,
+                )),
+                None => Arc::new(SqliteStorage::new(self.config.working_dir.clone())),
+            },
+        };
         persister.init()?;
 
         // mempool space is used to monitor the chain
-        let chain_service = Arc::new(MempoolSpace::from_base_url(
-            self.config.mempoolspace_url.clone(),
-        ));
+        let chain_service: Arc<dyn ChainService> = match self.chain_service.clone() {
+            Some(chain_service) => chain_service,
+            None => Arc::new(MempoolSpace::from_base_url(
+                self.config.mempoolspace_url.clone(),
+            )),
+        };
 
         let mut node_api = self.node_api.clone();
         let mut backup_transport = self.backup_transport.clone();
@@ -1524,11 +2856,14 @@ impl BreezServicesBuilder {
             persister.set_lsp_id(self.config.default_lsp_id.clone().unwrap())?;
         }
 
+        let lsps0_transport = Arc::new(lsps0::Transport::new(unwrapped_node_api.clone()));
         let payment_receiver = Arc::new(PaymentReceiver {
             config: self.config.clone(),
             node_api: unwrapped_node_api.clone(),
             lsp: breez_server.clone(),
             persister: persister.clone(),
+            lsps0_transport: lsps0_transport.clone(),
+            lsps2_support: std::sync::Mutex::new(HashMap::new()),
         });
 
         let btc_receive_swapper = Arc::new(BTCReceiveSwap::new(
@@ -1554,28 +2889,51 @@ impl BreezServicesBuilder {
             unwrapped_node_api.clone(),
         ));
 
+        let mut fiat_providers: Vec<Arc<dyn FiatAPI>> = vec![self
+            .fiat_api
+            .clone()
+            .unwrap_or_else(|| breez_server.clone())];
+        fiat_providers.extend(self.fiat_providers.clone());
+        for url in &self.config.fiat_rates_urls {
+            fiat_providers.push(Arc::new(HttpFiatAPI::new(url.clone())));
+        }
+        let fiat_service = Arc::new(FiatService::new(fiat_providers, persister.clone()));
+        let lsp_api = self.lsp_api.clone().unwrap_or_else(|| breez_server.clone());
+        let lsp_manager = Arc::new(LspManager::new(
+            unwrapped_node_api.clone(),
+            lsp_api.clone(),
+            persister.clone(),
+        ));
+
         // create a shutdown channel (sender and receiver)
         let (shutdown_sender, shutdown_receiver) = watch::channel::<()>(());
+        let (events_notifier, _) = broadcast::channel::<PersistedEvent>(100);
 
         // Create the node services and it them statically
         let breez_services = Arc::new(BreezServices {
+            config: self.config.clone(),
             started: Mutex::new(false),
             node_api: unwrapped_node_api.clone(),
-            lsp_api: self.lsp_api.clone().unwrap_or_else(|| breez_server.clone()),
-            fiat_api: self
-                .fiat_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
+            lsp_api,
+            fiat_service,
+            lsp_manager,
             moonpay_api: self
                 .moonpay_api
                 .clone()
                 .unwrap_or_else(|| breez_server.clone()),
+            chain_notifier_api: self
+                .chain_notifier_api
+                .clone()
+                .unwrap_or_else(|| breez_server.clone()),
             chain_service,
             persister: persister.clone(),
             btc_receive_swapper,
             btc_send_swapper,
             payment_receiver,
             event_listener,
+            event_listeners: std::sync::Mutex::new(HashMap::new()),
+            events_notifier,
+            lsps0_transport,
             backup_watcher: Arc::new(backup_watcher),
             shutdown_sender,
             shutdown_receiver,
@@ -1614,6 +2972,22 @@ impl BreezServer {
         Ok(client)
     }
 
+    pub(crate) async fn get_chain_notifier_client(
+        &self,
+    ) -> Result<ChainNotifierClient<InterceptedService<Channel, ApiKeyInterceptor>>> {
+        let channel = Channel::from_shared(self.server_url.clone())?
+            .connect()
+            .await?;
+
+        let api_key_metadata: Option<MetadataValue<Ascii>> = match &self.api_key {
+            Some(key) => Some(format!("Bearer {key}").parse()?),
+            _ => None,
+        };
+        let client =
+            ChainNotifierClient::with_interceptor(channel, ApiKeyInterceptor { api_key_metadata });
+        Ok(client)
+    }
+
     pub(crate) async fn get_information_client(&self) -> Result<InformationClient<Channel>> {
         InformationClient::connect(Uri::from_str(&self.server_url)?)
             .await
@@ -1678,17 +3052,80 @@ pub(crate) struct PaymentReceiver {
     config: Config,
     node_api: Arc<dyn NodeAPI>,
     lsp: Arc<dyn LspAPI>,
-    persister: Arc<SqliteStorage>,
+    persister: Arc<dyn Storage>,
+    lsps0_transport: Arc<lsps0::Transport>,
+    /// Whether the LSPs, by pubkey, support LSPS2, and when it was checked
+    lsps2_support: std::sync::Mutex<HashMap<String, (bool, Instant)>>,
 }
 
-#[tonic::async_trait]
-impl Receiver for PaymentReceiver {
-    async fn receive_payment(
+impl PaymentReceiver {
+    /// Returns an LSPS2 client of the LSP, if the LSP supports LSPS2
+    async fn lsps2_client(&self, lsp_pubkey: &str) -> SdkResult<Option<lsps2::Client>> {
+        let peer_id = hex::decode(lsp_pubkey).map_err(|e| SdkError::Generic {
+            err: format!("Invalid LSP pubkey: {e}"),
+        })?;
+        // The support is checked again once an LSP is known not to support LSPS2 for a while, as
+        // it may have been unreachable or added the support
+        let cached = self
+            .lsps2_support
+            .lock()
+            .unwrap()
+            .get(lsp_pubkey)
+            .copied()
+            .filter(|(supported, checked_at)| {
+                *supported || checked_at.elapsed() < LSPS2_UNSUPPORTED_TTL
+            });
+        let supported = match cached {
+            Some((supported, _)) => supported,
+            None => {
+                let client = lsps0::Client::new(
+                    self.lsps0_transport.clone(),
+                    peer_id.clone(),
+                    LSPS_PROTOCOLS_TIMEOUT,
+                );
+                let supported = match client.list_protocols().await {
+                    Ok(res) => Some(res.protocols.contains(&2)),
+                    // The LSP doesn't support LSPS0
+                    Err(lsps0::Error::Timeout) => Some(false),
+                    Err(e) => {
+                        warn!("Failed to list the protocols of the LSP: {e}");
+                        None
+                    }
+                };
+                if let Some(supported) = supported {
+                    self.lsps2_support
+                        .lock()
+                        .unwrap()
+                        .insert(lsp_pubkey.to_string(), (supported, Instant::now()));
+                }
+                supported.unwrap_or(false)
+            }
+        };
+
+        Ok(supported.then(|| {
+            lsps2::Client::new(lsps0::Client::new(
+                self.lsps0_transport.clone(),
+                peer_id,
+                LSPS_REQUEST_TIMEOUT,
+            ))
+        }))
+    }
+
+    /// The token to get the LSPS2 fee params with. The API key is only sent to the Breez LSPs.
+    fn lsps2_token(&self, lsp_id: &str) -> SdkResult<Option<String>> {
+        match is_custom_lsp(&self.persister, lsp_id)? {
+            true => Ok(None),
+            false => Ok(self.config.api_key.clone()),
+        }
+    }
+
+    /// Creates an invoice receiving the payment through the given LSP
+    pub(crate) async fn receive_payment_via(
         &self,
         req: ReceivePaymentRequest,
+        lsp_info: LspInformation,
     ) -> SdkResult<ReceivePaymentResponse> {
         self.node_api.start().await?;
-        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
         let node_state = self
             .persister
             .get_node_state()?
@@ -1708,16 +3145,50 @@ impl Receiver for PaymentReceiver {
 
         let mut channel_opening_fee_params = None;
         let mut channel_fees_msat = None;
+        // The CLTV expiry delta of the JIT channel, when it's bought over LSPS2
+        let mut jit_channel_cltv_expiry_delta = None;
 
         // check if we need to open channel
         let open_channel_needed = node_state.inbound_liquidity_msats < req.amount_msat;
         if open_channel_needed {
             info!("We need to open a channel");
+            let lsps2_client = self.lsps2_client(&lsp_info.pubkey).await?;
+            // The LSPs added by URI can't register payments over the Breez API
+            ensure_sdk!(
+                lsps2_client.is_some() || !is_custom_lsp(&self.persister, &lsp_info.id)?,
+                SdkError::ReceivePaymentFailed {
+                    err: format!(
+                        "LSP {} can't open a channel, as LSPS2 is unavailable",
+                        lsp_info.id
+                    )
+                }
+            );
+            let lsps2_info = match &lsps2_client {
+                Some(client) => {
+                    let info = lsps2_info(client, &self.lsps2_token(&lsp_info.id)?).await?;
+                    ensure_sdk!(
+                        (info.min_payment_size_msat..=info.max_payment_size_msat)
+                            .contains(&req.amount_msat),
+                        SdkError::ReceivePaymentFailed {
+                            err: format!(
+                                "Amount must be between {} and {} msat to open a channel with the LSP",
+                                info.min_payment_size_msat, info.max_payment_size_msat
+                            )
+                        }
+                    );
+                    Some(info)
+                }
+                None => None,
+            };
 
             // we need to open channel so we are calculating the fees for the LSP (coming either from the user, or from the LSP)
-            let ofp = match req.opening_fee_params {
-                Some(fee_params) => fee_params,
-                None => lsp_info.cheapest_open_channel_fee(expiry)?.clone(),
+            let ofp = match (req.opening_fee_params, lsps2_info) {
+                (Some(fee_params), _) => fee_params,
+                (None, Some(info)) => info
+                    .opening_fee_params_menu
+                    .cheapest_open_channel_fee(expiry)?
+                    .clone(),
+                (None, None) => lsp_info.cheapest_open_channel_fee(expiry)?.clone(),
             };
 
             channel_opening_fee_params = Some(ofp.clone());
@@ -1737,6 +3208,22 @@ impl Receiver for PaymentReceiver {
                 // remove the fees from the amount to get the small amount on the current node invoice.
                 destination_invoice_amount_msat = req.amount_msat - channel_fees_msat;
             }
+
+            if let Some(client) = lsps2_client {
+                info!("Buying a JIT channel over LSPS2");
+                let buy = client
+                    .buy(lsps2::client::BuyRequest {
+                        version: 1,
+                        opening_fee_params: ofp.into(),
+                        payment_size_msat: Some(req.amount_msat),
+                    })
+                    .await
+                    .map_err(|e| SdkError::ReceivePaymentFailed {
+                        err: format!("Failed to buy a JIT channel: {e}"),
+                    })?;
+                short_channel_id = parse_short_channel_id(&buy.jit_channel_scid)?;
+                jit_channel_cltv_expiry_delta = Some(buy.lsp_cltv_expiry_delta);
+            }
         } else {
             // not opening a channel so we need to get the real channel id into the routing hints
             info!("Finding channel ID for routing hint");
@@ -1790,14 +3277,26 @@ impl Receiver for PaymentReceiver {
         // or if the invoice doesn't have any routing hints that points to the lsp
         let mut lsp_hint: Option<RouteHint> = None;
         if !has_lsp_hint || open_channel_needed {
-            let lsp_hop = RouteHintHop {
-                src_node_id: lsp_info.pubkey,
-                short_channel_id,
-                fees_base_msat: lsp_info.base_fee_msat as u32,
-                fees_proportional_millionths: (lsp_info.fee_rate * 1000000.0) as u32,
-                cltv_expiry_delta: lsp_info.time_lock_delta as u64,
-                htlc_minimum_msat: Some(lsp_info.min_htlc_msat as u64),
-                htlc_maximum_msat: None,
+            let lsp_hop = match jit_channel_cltv_expiry_delta {
+                // The LSP deducts the opening fee from the forwarded payment instead
+                Some(cltv_expiry_delta) => RouteHintHop {
+                    src_node_id: lsp_info.pubkey,
+                    short_channel_id,
+                    fees_base_msat: 0,
+                    fees_proportional_millionths: 0,
+                    cltv_expiry_delta: cltv_expiry_delta as u64,
+                    htlc_minimum_msat: None,
+                    htlc_maximum_msat: None,
+                },
+                None => RouteHintHop {
+                    src_node_id: lsp_info.pubkey,
+                    short_channel_id,
+                    fees_base_msat: lsp_info.base_fee_msat as u32,
+                    fees_proportional_millionths: (lsp_info.fee_rate * 1000000.0) as u32,
+                    cltv_expiry_delta: lsp_info.time_lock_delta as u64,
+                    htlc_minimum_msat: Some(lsp_info.min_htlc_msat as u64),
+                    htlc_maximum_msat: None,
+                },
             };
 
             info!("Adding LSP hop as routing hint: {:?}", lsp_hop);
@@ -1819,8 +3318,8 @@ impl Receiver for PaymentReceiver {
             parsed_invoice = parse_invoice(&signed_invoice_with_hint)?;
         }
 
-        // register the payment at the lsp if needed
-        if open_channel_needed {
+        // register the payment at the lsp if needed, a JIT channel bought over LSPS2 needs none
+        if open_channel_needed && jit_channel_cltv_expiry_delta.is_none() {
             info!("Registering payment with LSP");
 
             if channel_opening_fee_params.is_none() {
@@ -1871,8 +3370,19 @@ impl Receiver for PaymentReceiver {
     }
 }
 
+#[tonic::async_trait]
+impl Receiver for PaymentReceiver {
+    async fn receive_payment(
+        &self,
+        req: ReceivePaymentRequest,
+    ) -> SdkResult<ReceivePaymentResponse> {
+        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
+        self.receive_payment_via(req, lsp_info).await
+    }
+}
+
 /// Convenience method to look up LSP info based on current LSP ID
-async fn get_lsp(persister: Arc<SqliteStorage>, lsp: Arc<dyn LspAPI>) -> Result<LspInformation> {
+async fn get_lsp(persister: Arc<dyn Storage>, lsp: Arc<dyn LspAPI>) -> Result<LspInformation> {
     let lsp_id = persister
         .get_lsp_id()?
         .ok_or("No LSP ID found")
@@ -1883,11 +3393,80 @@ async fn get_lsp(persister: Arc<SqliteStorage>, lsp: Arc<dyn LspAPI>) -> Result<
         .ok_or_else(|| anyhow!("No LSP found for id {}", lsp_id))
 }
 
+/// The JIT channels offered by the LSP over LSPS2
+struct Lsps2Info {
+    opening_fee_params_menu: OpeningFeeParamsMenu,
+    /// The range of the payments that can be received through a JIT channel
+    min_payment_size_msat: u64,
+    max_payment_size_msat: u64,
+}
+
+/// Gets the opening fee params menu and the payment size limits of the LSP over LSPS2
+async fn lsps2_info(client: &lsps2::Client, token: &Option<String>) -> SdkResult<Lsps2Info> {
+    let info = client
+        .get_info(lsps2::client::GetInfoRequest {
+            version: 1,
+            token: token.clone(),
+        })
+        .await
+        .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?;
+    let opening_fee_params_menu = OpeningFeeParamsMenu::try_from(
+        info.opening_fee_params_menu
+            .into_iter()
+            .map(|ofp| OpeningFeeParams::from(ofp).into())
+            .collect(),
+    )
+    .map_err(|e| SdkError::CalculateOpenChannelFeesFailed { err: e.to_string() })?;
+    Ok(Lsps2Info {
+        opening_fee_params_menu,
+        min_payment_size_msat: info.min_payment_size_msat,
+        max_payment_size_msat: info.max_payment_size_msat,
+    })
+}
+
+/// Splits a node URI (`pubkey@host:port`) into the node pubkey and address
+fn parse_node_uri(uri: &str) -> SdkResult<(String, String)> {
+    let (pubkey, host) = uri
+        .trim()
+        .split_once('@')
+        .ok_or_else(|| SdkError::Generic {
+            err: format!("Invalid node URI, expected pubkey@host:port: {uri}"),
+        })?;
+    bitcoin::secp256k1::PublicKey::from_str(pubkey).map_err(|e| SdkError::Generic {
+        err: format!("Invalid node pubkey {pubkey}: {e}"),
+    })?;
+    match host.rsplit_once(':') {
+        Some((address, port)) if !address.is_empty() && port.parse::<u16>().is_ok() => {
+            Ok((pubkey.to_string(), host.to_string()))
+        }
+        _ => Err(SdkError::Generic {
+            err: format!("Invalid node address, expected host:port: {host}"),
+        }),
+    }
+}
+
+/// Whether the LSP was added with [BreezServices::connect_lsp_by_uri], rather than listed by the
+/// Breez server
+fn is_custom_lsp(persister: &Arc<dyn Storage>, lsp_id: &str) -> SdkResult<bool> {
+    Ok(persister
+        .get_custom_lsps()?
+        .iter()
+        .any(|lsp| lsp.id == lsp_id))
+}
+
 async fn get_lsp_by_id(
-    persister: Arc<SqliteStorage>,
+    persister: Arc<dyn Storage>,
     lsp: Arc<dyn LspAPI>,
     lsp_id: &str,
 ) -> Result<Option<LspInformation>> {
+    if let Some(custom_lsp) = persister
+        .get_custom_lsps()?
+        .into_iter()
+        .find(|lsp| lsp.id == lsp_id)
+    {
+        return Ok(Some(custom_lsp));
+    }
+
     let node_pubkey = persister
         .get_node_state()?
         .ok_or("No NodeState found")
@@ -1906,22 +3485,40 @@ async fn get_lsp_by_id(
 pub(crate) mod tests {
     use std::collections::HashMap;
     use std::sync::Arc;
+    use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
-    use anyhow::Result;
+    use anyhow::{anyhow, Result};
+    use futures::StreamExt;
     use regex::Regex;
     use reqwest::Url;
+    use serde_json::json;
+    use tokio::sync::{mpsc, watch};
 
-    use crate::breez_services::{BreezServices, BreezServicesBuilder};
+    use crate::breez_services::{
+        channel_events, validate_liquidity_order, BreezEvent, BreezServices, BreezServicesBuilder,
+        PersistedEvent, PersistedEventListener,
+    };
     use crate::error::{SdkError, SdkResult};
-    use crate::fiat::Rate;
+    use crate::fiat::{FiatRates, PaymentFiatRate, Rate};
     use crate::lnurl::pay::model::MessageSuccessActionData;
     use crate::lnurl::pay::model::SuccessActionProcessed;
-    use crate::models::{LnPaymentDetails, NodeState, Payment, PaymentDetails, PaymentTypeFilter};
+    use crate::lsps0::{
+        self,
+        jsonrpc::{RpcRequest, RpcServerMessage, RpcServerMessageBody},
+        transport::LSPS0_MESSAGE_TYPE,
+    };
+    use crate::lsps1;
+    use crate::models::{
+        Channel, ChannelState, LnPaymentDetails, LspAPI, NodeState, Payment, PaymentDetails,
+        PaymentTypeFilter, SendFiatPaymentRequest,
+    };
+    use crate::persist::{memory::MemoryStorage, storage::Storage};
     use crate::{
         input_parser, parse_short_channel_id, test_utils::*, BuyBitcoinProvider, BuyBitcoinRequest,
-        InputType, ListPaymentsRequest, PaymentStatus, ReceivePaymentRequest,
+        InputType, ListPaymentsRequest, PaymentStatus, ProcessNotificationRequest,
+        ProcessNotificationResult, ReceiveOnchainRequest, ReceivePaymentRequest,
     };
-    use crate::{NodeAPI, PaymentType};
+    use crate::{CustomMessage, NodeAPI, PaymentType};
 
     use super::{PaymentReceiver, Receiver};
 
@@ -1966,6 +3563,8 @@ pub(crate) mod tests {
                         lnurl_withdraw_endpoint: None,
                     },
                 },
+                metadata: None,
+                fiat_rates: vec![],
             },
             Payment {
                 id: payment_hash_lnurl_withdraw.to_string(),
@@ -1989,6 +3588,8 @@ pub(crate) mod tests {
                         lnurl_withdraw_endpoint: Some(test_lnurl_withdraw_endpoint.to_string()),
                     },
                 },
+                metadata: None,
+                fiat_rates: vec![],
             },
             Payment {
                 id: payment_hash_with_lnurl_success_action.to_string(),
@@ -2012,6 +3613,8 @@ pub(crate) mod tests {
                         lnurl_withdraw_endpoint: None,
                     },
                 },
+                metadata: None,
+                fiat_rates: vec![],
             },
         ];
         let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
@@ -2039,6 +3642,7 @@ pub(crate) mod tests {
         let breez_services = builder
             .lsp_api(Arc::new(MockBreezServer {}))
             .fiat_api(Arc::new(MockBreezServer {}))
+            .chain_notifier_api(Arc::new(MockBreezServer {}))
             .node_api(node_api)
             .persister(persister)
             .backup_transport(Arc::new(MockBackupTransport::new()))
@@ -2057,6 +3661,11 @@ pub(crate) mod tests {
                 include_failures: None,
                 offset: None,
                 limit: None,
+                cursor: None,
+                search: None,
+                min_amount_msat: None,
+                max_amount_msat: None,
+                tags: None,
             })
             .await?;
         let mut cloned = all.clone();
@@ -2073,6 +3682,11 @@ pub(crate) mod tests {
                 include_failures: None,
                 offset: None,
                 limit: None,
+                cursor: None,
+                search: None,
+                min_amount_msat: None,
+                max_amount_msat: None,
+                tags: None,
             })
             .await?;
         assert_eq!(received, vec![cloned[1].clone(), cloned[0].clone()]);
@@ -2088,6 +3702,11 @@ pub(crate) mod tests {
                 include_failures: None,
                 offset: None,
                 limit: None,
+                cursor: None,
+                search: None,
+                min_amount_msat: None,
+                max_amount_msat: None,
+                tags: None,
             })
             .await?;
         assert_eq!(sent, vec![cloned[2].clone()]);
@@ -2107,25 +3726,59 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    // Test that the services only rely on the Storage interface
     #[tokio::test]
-    async fn test_receive_with_open_channel() -> SdkResult<()> {
-        let config = create_test_config();
-        let persister = Arc::new(create_test_persister(config.clone()));
-        persister.init().unwrap();
-
+    async fn test_memory_storage() -> SdkResult<()> {
         let dummy_node_state = get_dummy_node_state();
-
         let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+        let persister = Arc::new(MemoryStorage::new());
+        persister.set_lsp_id(MockBreezServer {}.lsp_id())?;
 
-        let breez_server = Arc::new(MockBreezServer {});
+        let mut builder = BreezServicesBuilder::new(create_test_config());
+        let breez_services = builder
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .chain_notifier_api(Arc::new(MockBreezServer {}))
+            .node_api(node_api)
+            .persister(persister.clone())
+            .backup_transport(Arc::new(MockBackupTransport::new()))
+            .build(None)
+            .await?;
+
+        breez_services.sync().await?;
+        assert_eq!(breez_services.node_info()?, dummy_node_state);
+        assert_eq!(persister.get_node_state()?, Some(dummy_node_state));
+        assert_eq!(
+            breez_services.lsp_id().await?,
+            Some(MockBreezServer {}.lsp_id())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_with_open_channel() -> SdkResult<()> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init().unwrap();
+
+        let dummy_node_state = get_dummy_node_state();
+
+        // The LSP doesn't support LSPS0
+        let mut node_api = MockNodeAPI::new(dummy_node_state.clone());
+        node_api.set_on_send_custom_message(Box::new(|_| Err(anyhow!("Unsupported"))));
+        let node_api = Arc::new(node_api);
+
+        let breez_server = Arc::new(MockBreezServer {});
         persister.set_lsp_id(breez_server.lsp_id()).unwrap();
         persister.set_node_state(&dummy_node_state).unwrap();
 
         let receiver: Arc<dyn Receiver> = Arc::new(PaymentReceiver {
             config,
-            node_api,
+            node_api: node_api.clone(),
             persister,
             lsp: breez_server.clone(),
+            lsps0_transport: Arc::new(lsps0::Transport::new(node_api)),
+            lsps2_support: std::sync::Mutex::new(HashMap::new()),
         });
         let ln_invoice = receiver
             .receive_payment(ReceivePaymentRequest {
@@ -2149,6 +3802,154 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_receive_with_lsps2_jit_channel() -> SdkResult<()> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init().unwrap();
+
+        let dummy_node_state = get_dummy_node_state();
+        let breez_server = Arc::new(MockBreezServer {});
+        persister.set_lsp_id(breez_server.lsp_id()).unwrap();
+        persister.set_node_state(&dummy_node_state).unwrap();
+
+        // The LSP replies to the LSPS0 requests sent to it
+        let (tx, rx) = mpsc::channel(10);
+        let on_send_request = move |message: CustomMessage| {
+            let req = serde_json::from_slice::<RpcRequest<serde_json::Value>>(&message.payload)?;
+            let result = match req.method.as_str() {
+                "lsps0.list_protocols" => json!({ "protocols": [1, 2] }),
+                "lsps2.get_info" => json!({
+                    "opening_fee_params_menu": [{
+                        "min_fee_msat": "2000",
+                        "proportional": 1000,
+                        "valid_until": "2099-01-01T00:00:00.000Z",
+                        "min_lifetime": 1008,
+                        "max_client_to_self_delay": 2016,
+                        "promise": "promise"
+                    }],
+                    "min_payment_size_msat": "1000",
+                    "max_payment_size_msat": "1000000000"
+                }),
+                "lsps2.buy" => json!({
+                    "jit_channel_scid": "1x2x3",
+                    "lsp_cltv_expiry_delta": 144
+                }),
+                method => return Err(anyhow!("Unexpected method {method}")),
+            };
+            let resp = RpcServerMessage {
+                jsonrpc: req.jsonrpc,
+                body: RpcServerMessageBody::Response { id: req.id, result },
+            };
+            tx.try_send(CustomMessage {
+                peer_id: message.peer_id,
+                message_type: LSPS0_MESSAGE_TYPE,
+                payload: serde_json::to_vec(&resp)?,
+            })?;
+            Ok(())
+        };
+        let mut node_api = MockNodeAPI::new(dummy_node_state.clone());
+        node_api.set_on_send_custom_message(Box::new(on_send_request));
+        node_api.set_on_stream_custom_messages(rx).await;
+        let node_api = Arc::new(node_api);
+
+        let lsps0_transport = Arc::new(lsps0::Transport::new(node_api.clone()));
+        let (stop, cancel) = watch::channel(());
+        lsps0_transport.start(cancel);
+        let receiver: Arc<dyn Receiver> = Arc::new(PaymentReceiver {
+            config,
+            node_api,
+            persister,
+            lsp: breez_server.clone(),
+            lsps0_transport,
+            lsps2_support: std::sync::Mutex::new(HashMap::new()),
+        });
+        let res = receiver
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: 3_000_000,
+                description: "should use the jit channel".to_string(),
+                preimage: None,
+                opening_fee_params: None,
+                use_description_hash: Some(false),
+                expiry: None,
+                cltv: None,
+            })
+            .await?;
+
+        // Payments out of the limits of the LSP can't go through a JIT channel
+        let too_large = receiver
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: 2_000_000_000,
+                description: "should be rejected".to_string(),
+                preimage: None,
+                opening_fee_params: None,
+                use_description_hash: Some(false),
+                expiry: None,
+                cltv: None,
+            })
+            .await;
+        assert!(matches!(
+            too_large,
+            Err(SdkError::ReceivePaymentFailed { .. })
+        ));
+        let _ = stop.send(());
+
+        assert_eq!(res.opening_fee_msat, Some(3_000));
+        assert_eq!(res.opening_fee_params.unwrap().promise, "promise");
+        assert_eq!(res.ln_invoice.amount_msat, Some(3_000_000));
+        let lsp_hop = &res.ln_invoice.routing_hints[0].hops[0];
+        assert_eq!(lsp_hop.src_node_id, breez_server.lsp_pub_key());
+        assert_eq!(
+            lsp_hop.short_channel_id,
+            parse_short_channel_id("1x2x3").unwrap()
+        );
+        assert_eq!(lsp_hop.cltv_expiry_delta, 144);
+        assert_eq!(lsp_hop.fees_base_msat, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_with_custom_lsp_without_lsps2() -> SdkResult<()> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init().unwrap();
+
+        let dummy_node_state = get_dummy_node_state();
+        let breez_server = Arc::new(MockBreezServer {});
+        let lsp_info = breez_server.list_lsps(dummy_node_state.id.clone()).await?[0].clone();
+        persister.set_custom_lsps(std::slice::from_ref(&lsp_info))?;
+        persister.set_lsp_id(lsp_info.id.clone()).unwrap();
+        persister.set_node_state(&dummy_node_state).unwrap();
+
+        let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+        let receiver: Arc<dyn Receiver> = Arc::new(PaymentReceiver {
+            config,
+            node_api: node_api.clone(),
+            persister,
+            lsp: breez_server,
+            lsps0_transport: Arc::new(lsps0::Transport::new(node_api)),
+            lsps2_support: std::sync::Mutex::new(HashMap::from([(
+                lsp_info.pubkey,
+                (false, Instant::now()),
+            )])),
+        });
+
+        // The payment can't be registered over the Breez API with a custom LSP
+        let res = receiver
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: 3_000_000,
+                description: "should be rejected".to_string(),
+                preimage: None,
+                opening_fee_params: None,
+                use_description_hash: Some(false),
+                expiry: None,
+                cltv: None,
+            })
+            .await;
+        assert!(matches!(res, Err(SdkError::ReceivePaymentFailed { .. })));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_list_lsps() -> SdkResult<()> {
         let storage_path = format!("{}/storage.sql", get_test_working_dir());
@@ -2171,7 +3972,7 @@ pub(crate) mod tests {
         let breez_services = breez_services().await?;
         breez_services.sync().await?;
 
-        let rates = breez_services.fiat_api.fetch_fiat_rates().await?;
+        let rates = breez_services.fiat_service.fiat_rates().await?.rates;
         assert_eq!(rates.len(), 1);
         assert_eq!(
             rates[0],
@@ -2184,6 +3985,573 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_register_webhook_lsps5() -> Result<(), Box<dyn std::error::Error>> {
+        // The LSP supports LSPS5 and records the registered webhooks
+        let webhooks = Arc::new(std::sync::Mutex::new(HashMap::new()));
+        let lsp_webhooks = webhooks.clone();
+        let (tx, rx) = mpsc::channel(10);
+        let on_send_request = move |message: CustomMessage| {
+            let req = serde_json::from_slice::<RpcRequest<serde_json::Value>>(&message.payload)?;
+            let result = match req.method.as_str() {
+                "lsps0.list_protocols" => json!({ "protocols": [2, 5] }),
+                "lsps5.set_webhook" => {
+                    let app_name = req.params["app_name"].as_str().unwrap().to_string();
+                    let webhook = req.params["webhook"].as_str().unwrap().to_string();
+                    let mut webhooks = lsp_webhooks.lock().unwrap();
+                    let no_change = webhooks.get(&app_name) == Some(&webhook);
+                    webhooks.insert(app_name, webhook);
+                    json!({
+                        "num_webhooks": webhooks.len(),
+                        "max_webhooks": 4,
+                        "no_change": no_change
+                    })
+                }
+                method => return Err(anyhow!("Unexpected method {method}")),
+            };
+            let resp = RpcServerMessage {
+                jsonrpc: req.jsonrpc,
+                body: RpcServerMessageBody::Response { id: req.id, result },
+            };
+            tx.try_send(CustomMessage {
+                peer_id: message.peer_id,
+                message_type: LSPS0_MESSAGE_TYPE,
+                payload: serde_json::to_vec(&resp)?,
+            })?;
+            Ok(())
+        };
+        let mut node_api = MockNodeAPI::new(get_dummy_node_state());
+        node_api.set_on_send_custom_message(Box::new(on_send_request));
+        node_api.set_on_stream_custom_messages(rx).await;
+        let breez_services = breez_services_with(Some(Arc::new(node_api)), vec![]).await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+        breez_services
+            .lsps0_transport
+            .start(breez_services.shutdown_receiver.clone());
+
+        let webhook_url = "https://notify.local/hook?platform=ios&token=1234".to_string();
+        breez_services.register_webhook(webhook_url.clone()).await?;
+        assert_eq!(
+            webhooks.lock().unwrap().get("breez-sdk"),
+            Some(&webhook_url)
+        );
+
+        // A new push token replaces the webhook of the app
+        let new_webhook_url = "https://notify.local/hook?platform=ios&token=5678".to_string();
+        breez_services
+            .register_webhook(new_webhook_url.clone())
+            .await?;
+        assert_eq!(webhooks.lock().unwrap().len(), 1);
+        assert_eq!(
+            webhooks.lock().unwrap().get("breez-sdk"),
+            Some(&new_webhook_url)
+        );
+        assert_eq!(
+            breez_services.persister.get_webhook_url()?,
+            Some(new_webhook_url)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_node_uri() {
+        let pubkey = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let (parsed_pubkey, host) =
+            super::parse_node_uri(&format!("{pubkey}@lsp.example.com:9735")).unwrap();
+        assert_eq!(parsed_pubkey, pubkey);
+        assert_eq!(host, "lsp.example.com:9735");
+
+        assert!(super::parse_node_uri(pubkey).is_err());
+        assert!(super::parse_node_uri("02abcd@lsp.example.com:9735").is_err());
+        assert!(super::parse_node_uri(&format!("{pubkey}@lsp.example.com")).is_err());
+        assert!(super::parse_node_uri(&format!("{pubkey}@:9735")).is_err());
+    }
+
+    #[test]
+    fn test_channel_events() {
+        let channel = |funding_txid: &str, state: ChannelState| Channel {
+            funding_txid: funding_txid.to_string(),
+            short_channel_id: "1x2x3".to_string(),
+            state,
+            spendable_msat: 100,
+            receivable_msat: 1000,
+            closed_at: None,
+            funding_outnum: None,
+            alias_local: None,
+            alias_remote: None,
+            closing_txid: Some(format!("closing_{funding_txid}")),
+        };
+
+        let before = vec![
+            channel("pending_open", ChannelState::PendingOpen),
+            channel("closing", ChannelState::Opened),
+            channel("closed", ChannelState::PendingClose),
+            channel("unchanged", ChannelState::Opened),
+        ];
+        let after = vec![
+            channel("pending_open", ChannelState::Opened),
+            channel("closing", ChannelState::PendingClose),
+            channel("closed", ChannelState::Closed),
+            channel("unchanged", ChannelState::Opened),
+            channel("new", ChannelState::Opened),
+            channel("restored", ChannelState::Closed),
+        ];
+
+        assert_eq!(
+            channel_events(&before, &after, false),
+            vec![
+                BreezEvent::ChannelOpened {
+                    funding_txid: "pending_open".to_string()
+                },
+                BreezEvent::ChannelPendingClose {
+                    funding_txid: "closing".to_string()
+                },
+                BreezEvent::ChannelClosed {
+                    funding_txid: "closed".to_string(),
+                    closing_txid: Some("closing_closed".to_string())
+                },
+                BreezEvent::ChannelOpened {
+                    funding_txid: "new".to_string()
+                },
+            ]
+        );
+        assert!(channel_events(&after, &after, false).is_empty());
+
+        // Without a previous node state, only the transitions of known channels are reported
+        assert_eq!(
+            channel_events(&before, &after, true).len(),
+            3,
+            "The first seen channels should be skipped"
+        );
+        assert!(channel_events(&[], &after, true).is_empty());
+    }
+
+    #[test]
+    fn test_validate_liquidity_order() {
+        let order = |lsp_balance_sat: u64, channel_expiry_blocks: u32, invoice_amount_msat: u64| {
+            lsps1::client::Order {
+                order_id: "order".to_string(),
+                lsp_balance_sat,
+                client_balance_sat: 0,
+                required_channel_confirmations: 0,
+                funding_confirms_within_blocks: 6,
+                channel_expiry_blocks,
+                created_at: "2023-01-01T00:00:00.000Z".to_string(),
+                expires_at: "2023-01-02T00:00:00.000Z".to_string(),
+                order_state: lsps1::client::OrderState::Created,
+                payment: lsps1::client::Payment {
+                    state: lsps1::client::PaymentState::ExpectPayment,
+                    fee_total_sat: 8888,
+                    order_total_sat: 8888,
+                    bolt11_invoice: create_invoice(
+                        "order".into(),
+                        invoice_amount_msat,
+                        vec![],
+                        None,
+                    )
+                    .bolt11,
+                },
+                channel: None,
+            }
+        };
+
+        assert!(
+            validate_liquidity_order(&order(1_000_000, 13140, 8_888_000), 1_000_000, 13140).is_ok()
+        );
+        // The LSP balance, lease and invoice amount must match the order
+        assert!(
+            validate_liquidity_order(&order(500_000, 13140, 8_888_000), 1_000_000, 13140).is_err()
+        );
+        assert!(
+            validate_liquidity_order(&order(1_000_000, 144, 8_888_000), 1_000_000, 13140).is_err()
+        );
+        assert!(
+            validate_liquidity_order(&order(1_000_000, 13140, 9_000_000), 1_000_000, 13140)
+                .is_err()
+        );
+    }
+
+    struct RecordingEventListener {
+        events: Arc<std::sync::Mutex<Vec<PersistedEvent>>>,
+    }
+
+    impl PersistedEventListener for RecordingEventListener {
+        fn on_event(&self, e: PersistedEvent) {
+            self.events.lock().unwrap().push(e);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_subscribers() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let mut first_stream = Box::pin(breez_services.subscribe_events());
+        let mut second_stream = Box::pin(breez_services.subscribe_events());
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let listener_id = breez_services.add_event_listener(Box::new(RecordingEventListener {
+            events: events.clone(),
+        }));
+
+        breez_services
+            .notify_event_listeners(BreezEvent::Synced)
+            .await?;
+        let synced = first_stream.next().await.unwrap();
+        assert_eq!(synced.event, BreezEvent::Synced);
+        assert_eq!(second_stream.next().await, Some(synced.clone()));
+        assert_eq!(*events.lock().unwrap(), vec![synced.clone()]);
+
+        breez_services.remove_event_listener(listener_id.clone())?;
+        assert!(breez_services.remove_event_listener(listener_id).is_err());
+        breez_services
+            .notify_event_listeners(BreezEvent::BackupStarted)
+            .await?;
+        let backup_started = first_stream.next().await.unwrap();
+        assert_eq!(backup_started.event, BreezEvent::BackupStarted);
+        assert_eq!(events.lock().unwrap().len(), 1);
+
+        // Only the backup event was logged, with the sequence number it was delivered with
+        assert_eq!(synced.seq, 0);
+        assert_ne!(backup_started.seq, 0);
+        assert_eq!(breez_services.events_since(0)?, vec![backup_started]);
+
+        Ok(())
+    }
+
+    /// A listener that removes itself on the first event
+    struct OneShotEventListener {
+        breez_services: Arc<BreezServices>,
+        id: Arc<std::sync::Mutex<Option<String>>>,
+    }
+
+    impl PersistedEventListener for OneShotEventListener {
+        fn on_event(&self, _e: PersistedEvent) {
+            if let Some(id) = self.id.lock().unwrap().take() {
+                self.breez_services.remove_event_listener(id).unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_listener_removes_itself() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let id = Arc::new(std::sync::Mutex::new(None));
+        let listener_id = breez_services.add_event_listener(Box::new(OneShotEventListener {
+            breez_services: breez_services.clone(),
+            id: id.clone(),
+        }));
+        *id.lock().unwrap() = Some(listener_id.clone());
+
+        breez_services
+            .notify_event_listeners(BreezEvent::Synced)
+            .await?;
+        assert!(id.lock().unwrap().is_none());
+        assert!(breez_services.remove_event_listener(listener_id).is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fiat_quote() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+
+        // The mock server rates 1 BTC at 20000 USD
+        let (rate, amount_msat) = breez_services.fiat_quote("USD", 5.0).await?;
+        assert_eq!(rate, 20_000.0);
+        assert_eq!(amount_msat, 25_000_000);
+
+        assert!(breez_services.fiat_quote("EUR", 5.0).await.is_err());
+        assert!(breez_services.fiat_quote("USD", 0.0).await.is_err());
+        assert!(breez_services.fiat_quote("USD", f64::NAN).await.is_err());
+
+        // Rates that couldn't be refreshed are not used for quotes
+        let fiat_rates = breez_services.fiat_rates().await?;
+        breez_services.persister.set_fiat_rates(&FiatRates {
+            is_stale: true,
+            ..fiat_rates
+        })?;
+        assert!(breez_services.fiat_quote("USD", 5.0).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_fiat_payment_rejects_invoice_with_amount(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let invoice = create_invoice("with amount".to_string(), 1_000, vec![], None);
+
+        let res = breez_services
+            .send_fiat_payment(SendFiatPaymentRequest {
+                bolt11: invoice.bolt11,
+                fiat_currency: "USD".to_string(),
+                fiat_amount: 5.0,
+            })
+            .await;
+        assert!(matches!(res, Err(SdkError::SendPaymentFailed { .. })));
+        assert!(breez_services
+            .persister
+            .get_payment_by_hash(&invoice.payment_hash)?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_record_fiat_rates() -> Result<(), Box<dyn std::error::Error>> {
+        let payment = Payment {
+            id: "1234".to_string(),
+            payment_type: PaymentType::Received,
+            payment_time: 100000,
+            amount_msat: 10_000,
+            fee_msat: 0,
+            status: PaymentStatus::Complete,
+            description: Some("test receive".to_string()),
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    payment_hash: "1234".to_string(),
+                    label: "".to_string(),
+                    destination_pubkey: "1111".to_string(),
+                    payment_preimage: "2222".to_string(),
+                    keysend: false,
+                    bolt11: "1111".to_string(),
+                    lnurl_success_action: None,
+                    lnurl_metadata: None,
+                    ln_address: None,
+                    lnurl_withdraw_endpoint: None,
+                },
+            },
+            metadata: None,
+            fiat_rates: vec![],
+        };
+        let breez_services = breez_services_with(None, vec![payment]).await?;
+        let payment_hash = "1234".to_string();
+
+        // Rates that couldn't be refreshed are not recorded
+        let fiat_rates = breez_services.fiat_rates().await?;
+        breez_services.persister.set_fiat_rates(&FiatRates {
+            is_stale: true,
+            ..fiat_rates.clone()
+        })?;
+        breez_services.record_fiat_rates(&payment_hash).await;
+        let payment = breez_services
+            .persister
+            .get_payment_by_hash(&payment_hash)?;
+        assert_eq!(payment.unwrap().fiat_rates, vec![]);
+
+        breez_services.persister.set_fiat_rates(&fiat_rates)?;
+        breez_services.record_fiat_rates(&payment_hash).await;
+        let payment = breez_services
+            .persister
+            .get_payment_by_hash(&payment_hash)?;
+        assert_eq!(
+            payment.unwrap().fiat_rates,
+            vec![PaymentFiatRate {
+                coin: "USD".to_string(),
+                value_micros: 20_000_000_000,
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_register_webhook() -> Result<(), Box<dyn std::error::Error>> {
+        // The LSP doesn't support LSPS0, so the webhook is registered with the LSP API
+        let mut node_api = MockNodeAPI::new(get_dummy_node_state());
+        node_api.set_on_send_custom_message(Box::new(|_| Err(anyhow!("Unsupported"))));
+        let breez_services = breez_services_with(Some(Arc::new(node_api)), vec![]).await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+        let webhook_url = "https://notify.local/hook?platform=ios&token=1234".to_string();
+
+        breez_services.register_webhook(webhook_url.clone()).await?;
+        assert_eq!(
+            breez_services.persister.get_webhook_url()?,
+            Some(webhook_url)
+        );
+        assert_eq!(
+            breez_services
+                .persister
+                .get_chain_notifier_registration_id()?,
+            Some("registration".to_string())
+        );
+
+        // Registering a new url keeps the registration
+        let new_webhook_url = "https://notify.local/hook?platform=ios&token=5678".to_string();
+        breez_services
+            .register_webhook(new_webhook_url.clone())
+            .await?;
+        assert_eq!(
+            breez_services.persister.get_webhook_url()?,
+            Some(new_webhook_url)
+        );
+        assert_eq!(
+            breez_services
+                .persister
+                .get_chain_notifier_registration_id()?,
+            Some("registration".to_string())
+        );
+
+        Ok(())
+    }
+
+    /// Builds the services with the given chain notifier and a mock swapper, with the webhook
+    /// registered with the LSP API
+    async fn breez_services_with_chain_notifier(
+        chain_notifier_api: Arc<MockChainNotifierAPI>,
+    ) -> Result<Arc<BreezServices>> {
+        let mut node_api = MockNodeAPI::new(get_dummy_node_state());
+        node_api.set_on_send_custom_message(Box::new(|_| Err(anyhow!("Unsupported"))));
+
+        let test_config = create_test_config();
+        let persister = Arc::new(create_test_persister(test_config.clone()));
+        persister.init()?;
+        persister.set_lsp_id(MockBreezServer {}.lsp_id())?;
+        persister.set_node_state(&get_dummy_node_state())?;
+
+        let mut builder = BreezServicesBuilder::new(test_config);
+        let breez_services = builder
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .chain_notifier_api(chain_notifier_api)
+            .chain_service(Arc::new(MockChainService::default()))
+            .swapper_api(Arc::new(MockSwapperAPI {}))
+            .persister(persister)
+            .node_api(Arc::new(node_api))
+            .backup_transport(Arc::new(MockBackupTransport::new()))
+            .build(None)
+            .await?;
+        Ok(breez_services)
+    }
+
+    #[tokio::test]
+    async fn test_register_webhook_subscribes_swap_addresses(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let chain_notifier_api = Arc::new(MockChainNotifierAPI::default());
+        let breez_services = breez_services_with_chain_notifier(chain_notifier_api.clone()).await?;
+        let webhook_url = "https://notify.local/hook?platform=ios&token=1234".to_string();
+
+        // Without a registered webhook, new swap addresses are not subscribed
+        let swap_info = breez_services
+            .receive_onchain(ReceiveOnchainRequest {
+                opening_fee_params: None,
+            })
+            .await?;
+        assert!(chain_notifier_api
+            .subscribed_addresses
+            .lock()
+            .unwrap()
+            .is_empty());
+
+        // The registration subscribes the existing monitored swaps
+        breez_services.register_webhook(webhook_url).await?;
+        assert_eq!(
+            *chain_notifier_api.subscribed_addresses.lock().unwrap(),
+            vec![swap_info.bitcoin_address.clone()]
+        );
+
+        // Once registered, the address returned for a new swap is subscribed
+        chain_notifier_api
+            .subscribed_addresses
+            .lock()
+            .unwrap()
+            .clear();
+        let swap_info = breez_services
+            .receive_onchain(ReceiveOnchainRequest {
+                opening_fee_params: None,
+            })
+            .await?;
+        assert_eq!(
+            *chain_notifier_api.subscribed_addresses.lock().unwrap(),
+            vec![swap_info.bitcoin_address]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_register_webhook_ignores_failed_subscriptions(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let chain_notifier_api = Arc::new(MockChainNotifierAPI {
+            fail_subscriptions: true,
+            ..Default::default()
+        });
+        let breez_services = breez_services_with_chain_notifier(chain_notifier_api).await?;
+        let webhook_url = "https://notify.local/hook?platform=ios&token=1234".to_string();
+
+        breez_services
+            .receive_onchain(ReceiveOnchainRequest {
+                opening_fee_params: None,
+            })
+            .await?;
+        breez_services.register_webhook(webhook_url.clone()).await?;
+        assert_eq!(
+            breez_services.persister.get_webhook_url()?,
+            Some(webhook_url)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_process_payment_received_notification() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let payment = Payment {
+            id: "1234".to_string(),
+            payment_type: PaymentType::Received,
+            payment_time: 100000,
+            amount_msat: 10_000,
+            fee_msat: 0,
+            status: PaymentStatus::Complete,
+            description: Some("test receive".to_string()),
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    payment_hash: "1234".to_string(),
+                    label: "".to_string(),
+                    destination_pubkey: "1111".to_string(),
+                    payment_preimage: "2222".to_string(),
+                    keysend: false,
+                    bolt11: "1111".to_string(),
+                    lnurl_success_action: None,
+                    lnurl_metadata: None,
+                    ln_address: None,
+                    lnurl_withdraw_endpoint: None,
+                },
+            },
+            metadata: None,
+            fiat_rates: vec![],
+        };
+        let breez_services = breez_services_with(None, vec![payment]).await?;
+        let deadline = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() + 30;
+
+        let res = breez_services
+            .process_notification(ProcessNotificationRequest {
+                payload: r#"{"type": "payment_received", "data": {"payment_hash": "1234"}}"#
+                    .to_string(),
+                deadline,
+            })
+            .await?;
+        match res {
+            ProcessNotificationResult::PaymentReceived { payment } => {
+                assert_eq!(payment.id, "1234");
+            }
+            _ => panic!("Expected the payment to be received"),
+        }
+
+        assert!(breez_services
+            .process_notification(ProcessNotificationRequest {
+                payload: r#"{"type": "unknown"}"#.to_string(),
+                deadline,
+            })
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_buy_bitcoin_with_moonpay() -> Result<(), Box<dyn std::error::Error>> {
         let breez_services = breez_services().await?;
@@ -2233,6 +4601,7 @@ pub(crate) mod tests {
         let breez_services = builder
             .lsp_api(Arc::new(MockBreezServer {}))
             .fiat_api(Arc::new(MockBreezServer {}))
+            .chain_notifier_api(Arc::new(MockBreezServer {}))
             .moonpay_api(Arc::new(MockBreezServer {}))
             .persister(persister)
             .node_api(node_api)
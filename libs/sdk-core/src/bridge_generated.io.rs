@@ -36,6 +36,16 @@ pub extern "C" fn wire_check_message(port_: i64, req: *mut wire_CheckMessageRequ
     wire_check_message_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_process_notification(port_: i64, req: *mut wire_ProcessNotificationRequest) {
+    wire_process_notification_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_register_webhook(port_: i64, webhook_url: *mut wire_uint_8_list) {
+    wire_register_webhook_impl(port_, webhook_url)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_mnemonic_to_seed(port_: i64, phrase: *mut wire_uint_8_list) {
     wire_mnemonic_to_seed_impl(port_, phrase)
@@ -61,6 +71,21 @@ pub extern "C" fn wire_breez_events_stream(port_: i64) {
     wire_breez_events_stream_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_add_event_stream(port_: i64) {
+    wire_add_event_stream_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_remove_event_stream(port_: i64, id: *mut wire_uint_8_list) {
+    wire_remove_event_stream_impl(port_, id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_events_since(port_: i64, seq: u64) {
+    wire_events_since_impl(port_, seq)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_breez_log_stream(port_: i64) {
     wire_breez_log_stream_impl(port_)
@@ -76,6 +101,11 @@ pub extern "C" fn wire_connect_lsp(port_: i64, lsp_id: *mut wire_uint_8_list) {
     wire_connect_lsp_impl(port_, lsp_id)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_connect_lsp_by_uri(port_: i64, uri: *mut wire_uint_8_list) {
+    wire_connect_lsp_by_uri_impl(port_, uri)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_lsp_id(port_: i64) {
     wire_lsp_id_impl(port_)
@@ -96,6 +126,22 @@ pub extern "C" fn wire_close_lsp_channels(port_: i64) {
     wire_close_lsp_channels_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_prepare_buy_inbound_liquidity(
+    port_: i64,
+    req: *mut wire_PrepareBuyInboundLiquidityRequest,
+) {
+    wire_prepare_buy_inbound_liquidity_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_buy_inbound_liquidity(
+    port_: i64,
+    req: *mut wire_BuyInboundLiquidityRequest,
+) {
+    wire_buy_inbound_liquidity_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_backup(port_: i64) {
     wire_backup_impl(port_)
@@ -106,6 +152,52 @@ pub extern "C" fn wire_backup_status(port_: i64) {
     wire_backup_status_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_verify_backup(port_: i64) {
+    wire_verify_backup_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_backup_versions(port_: i64) {
+    wire_list_backup_versions_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_restore_backup(port_: i64, version: u64) {
+    wire_restore_backup_impl(port_, version)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_change_backup_passphrase(
+    port_: i64,
+    req: *mut wire_ChangeBackupPassphraseRequest,
+) {
+    wire_change_backup_passphrase_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_export_backup(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+) {
+    wire_export_backup_impl(port_, path, passphrase)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_import_backup(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+) {
+    wire_import_backup_impl(port_, path, passphrase)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_db_maintenance(port_: i64, req: *mut wire_DbMaintenanceRequest) {
+    wire_db_maintenance_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_parse_invoice(port_: i64, invoice: *mut wire_uint_8_list) {
     wire_parse_invoice_impl(port_, invoice)
@@ -126,6 +218,21 @@ pub extern "C" fn wire_payment_by_hash(port_: i64, hash: *mut wire_uint_8_list)
     wire_payment_by_hash_impl(port_, hash)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_export_payments(port_: i64, req: *mut wire_ExportPaymentsRequest) {
+    wire_export_payments_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_payment_metadata(
+    port_: i64,
+    hash: *mut wire_uint_8_list,
+    note: *mut wire_uint_8_list,
+    tags: *mut wire_StringList,
+) {
+    wire_set_payment_metadata_impl(port_, hash, note, tags)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_send_payment(port_: i64, req: *mut wire_SendPaymentRequest) {
     wire_send_payment_impl(port_, req)
@@ -144,6 +251,16 @@ pub extern "C" fn wire_receive_payment(port_: i64, req: *mut wire_ReceivePayment
     wire_receive_payment_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_send_fiat_payment(port_: i64, req: *mut wire_SendFiatPaymentRequest) {
+    wire_send_fiat_payment_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_fiat_payment(port_: i64, req: *mut wire_ReceiveFiatPaymentRequest) {
+    wire_receive_fiat_payment_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_lnurl_pay(port_: i64, req: *mut wire_LnUrlPayRequest) {
     wire_lnurl_pay_impl(port_, req)
@@ -164,6 +281,31 @@ pub extern "C" fn wire_fetch_fiat_rates(port_: i64) {
     wire_fetch_fiat_rates_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_fiat_rates(port_: i64) {
+    wire_fiat_rates_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_format_fiat(
+    port_: i64,
+    amount: f64,
+    currency_id: *mut wire_uint_8_list,
+    locale: *mut wire_uint_8_list,
+) {
+    wire_format_fiat_impl(port_, amount, currency_id, locale)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_format_sats(
+    port_: i64,
+    amount_msat: u64,
+    unit: i32,
+    locale: *mut wire_uint_8_list,
+) {
+    wire_format_sats_impl(port_, amount_msat, unit, locale)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_fiat_currencies(port_: i64) {
     wire_list_fiat_currencies_impl(port_)
@@ -241,6 +383,15 @@ pub extern "C" fn wire_execute_command(port_: i64, command: *mut wire_uint_8_lis
 
 // Section: allocate functions
 
+#[no_mangle]
+pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
+    let wrap = wire_StringList {
+        ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_bool_0(value: bool) -> *mut bool {
     support::new_leak_box_ptr(value)
@@ -251,6 +402,18 @@ pub extern "C" fn new_box_autoadd_buy_bitcoin_request_0() -> *mut wire_BuyBitcoi
     support::new_leak_box_ptr(wire_BuyBitcoinRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_buy_inbound_liquidity_request_0(
+) -> *mut wire_BuyInboundLiquidityRequest {
+    support::new_leak_box_ptr(wire_BuyInboundLiquidityRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_change_backup_passphrase_request_0(
+) -> *mut wire_ChangeBackupPassphraseRequest {
+    support::new_leak_box_ptr(wire_ChangeBackupPassphraseRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_check_message_request_0() -> *mut wire_CheckMessageRequest {
     support::new_leak_box_ptr(wire_CheckMessageRequest::new_with_null_ptr())
@@ -261,6 +424,16 @@ pub extern "C" fn new_box_autoadd_config_0() -> *mut wire_Config {
     support::new_leak_box_ptr(wire_Config::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_db_maintenance_request_0() -> *mut wire_DbMaintenanceRequest {
+    support::new_leak_box_ptr(wire_DbMaintenanceRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_export_payments_request_0() -> *mut wire_ExportPaymentsRequest {
+    support::new_leak_box_ptr(wire_ExportPaymentsRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_greenlight_credentials_0() -> *mut wire_GreenlightCredentials {
     support::new_leak_box_ptr(wire_GreenlightCredentials::new_with_null_ptr())
@@ -311,6 +484,17 @@ pub extern "C" fn new_box_autoadd_opening_fee_params_0() -> *mut wire_OpeningFee
     support::new_leak_box_ptr(wire_OpeningFeeParams::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_payments_cursor_0() -> *mut wire_PaymentsCursor {
+    support::new_leak_box_ptr(wire_PaymentsCursor::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_prepare_buy_inbound_liquidity_request_0(
+) -> *mut wire_PrepareBuyInboundLiquidityRequest {
+    support::new_leak_box_ptr(wire_PrepareBuyInboundLiquidityRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_prepare_refund_request_0() -> *mut wire_PrepareRefundRequest {
     support::new_leak_box_ptr(wire_PrepareRefundRequest::new_with_null_ptr())
@@ -321,6 +505,18 @@ pub extern "C" fn new_box_autoadd_prepare_sweep_request_0() -> *mut wire_Prepare
     support::new_leak_box_ptr(wire_PrepareSweepRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_process_notification_request_0(
+) -> *mut wire_ProcessNotificationRequest {
+    support::new_leak_box_ptr(wire_ProcessNotificationRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_receive_fiat_payment_request_0(
+) -> *mut wire_ReceiveFiatPaymentRequest {
+    support::new_leak_box_ptr(wire_ReceiveFiatPaymentRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_receive_onchain_request_0() -> *mut wire_ReceiveOnchainRequest {
     support::new_leak_box_ptr(wire_ReceiveOnchainRequest::new_with_null_ptr())
@@ -342,6 +538,12 @@ pub extern "C" fn new_box_autoadd_reverse_swap_fees_request_0() -> *mut wire_Rev
     support::new_leak_box_ptr(wire_ReverseSwapFeesRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_send_fiat_payment_request_0() -> *mut wire_SendFiatPaymentRequest
+{
+    support::new_leak_box_ptr(wire_SendFiatPaymentRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_send_onchain_request_0() -> *mut wire_SendOnchainRequest {
     support::new_leak_box_ptr(wire_SendOnchainRequest::new_with_null_ptr())
@@ -411,6 +613,15 @@ impl Wire2Api<String> for *mut wire_uint_8_list {
         String::from_utf8_lossy(&vec).into_owned()
     }
 }
+impl Wire2Api<Vec<String>> for *mut wire_StringList {
+    fn wire2api(self) -> Vec<String> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
 
 impl Wire2Api<bool> for *mut bool {
     fn wire2api(self) -> bool {
@@ -423,6 +634,18 @@ impl Wire2Api<BuyBitcoinRequest> for *mut wire_BuyBitcoinRequest {
         Wire2Api::<BuyBitcoinRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<BuyInboundLiquidityRequest> for *mut wire_BuyInboundLiquidityRequest {
+    fn wire2api(self) -> BuyInboundLiquidityRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<BuyInboundLiquidityRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ChangeBackupPassphraseRequest> for *mut wire_ChangeBackupPassphraseRequest {
+    fn wire2api(self) -> ChangeBackupPassphraseRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ChangeBackupPassphraseRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<CheckMessageRequest> for *mut wire_CheckMessageRequest {
     fn wire2api(self) -> CheckMessageRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -435,6 +658,18 @@ impl Wire2Api<Config> for *mut wire_Config {
         Wire2Api::<Config>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<DbMaintenanceRequest> for *mut wire_DbMaintenanceRequest {
+    fn wire2api(self) -> DbMaintenanceRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<DbMaintenanceRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ExportPaymentsRequest> for *mut wire_ExportPaymentsRequest {
+    fn wire2api(self) -> ExportPaymentsRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ExportPaymentsRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<GreenlightCredentials> for *mut wire_GreenlightCredentials {
     fn wire2api(self) -> GreenlightCredentials {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -494,6 +729,18 @@ impl Wire2Api<OpeningFeeParams> for *mut wire_OpeningFeeParams {
         Wire2Api::<OpeningFeeParams>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<PaymentsCursor> for *mut wire_PaymentsCursor {
+    fn wire2api(self) -> PaymentsCursor {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PaymentsCursor>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<PrepareBuyInboundLiquidityRequest> for *mut wire_PrepareBuyInboundLiquidityRequest {
+    fn wire2api(self) -> PrepareBuyInboundLiquidityRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PrepareBuyInboundLiquidityRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<PrepareRefundRequest> for *mut wire_PrepareRefundRequest {
     fn wire2api(self) -> PrepareRefundRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -506,6 +753,18 @@ impl Wire2Api<PrepareSweepRequest> for *mut wire_PrepareSweepRequest {
         Wire2Api::<PrepareSweepRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<ProcessNotificationRequest> for *mut wire_ProcessNotificationRequest {
+    fn wire2api(self) -> ProcessNotificationRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ProcessNotificationRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ReceiveFiatPaymentRequest> for *mut wire_ReceiveFiatPaymentRequest {
+    fn wire2api(self) -> ReceiveFiatPaymentRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ReceiveFiatPaymentRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<ReceiveOnchainRequest> for *mut wire_ReceiveOnchainRequest {
     fn wire2api(self) -> ReceiveOnchainRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -530,6 +789,12 @@ impl Wire2Api<ReverseSwapFeesRequest> for *mut wire_ReverseSwapFeesRequest {
         Wire2Api::<ReverseSwapFeesRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<SendFiatPaymentRequest> for *mut wire_SendFiatPaymentRequest {
+    fn wire2api(self) -> SendFiatPaymentRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<SendFiatPaymentRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<SendOnchainRequest> for *mut wire_SendOnchainRequest {
     fn wire2api(self) -> SendOnchainRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -585,6 +850,20 @@ impl Wire2Api<BuyBitcoinRequest> for wire_BuyBitcoinRequest {
         }
     }
 }
+impl Wire2Api<BuyInboundLiquidityRequest> for wire_BuyInboundLiquidityRequest {
+    fn wire2api(self) -> BuyInboundLiquidityRequest {
+        BuyInboundLiquidityRequest {
+            order_id: self.order_id.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ChangeBackupPassphraseRequest> for wire_ChangeBackupPassphraseRequest {
+    fn wire2api(self) -> ChangeBackupPassphraseRequest {
+        ChangeBackupPassphraseRequest {
+            passphrase: self.passphrase.wire2api(),
+        }
+    }
+}
 impl Wire2Api<CheckMessageRequest> for wire_CheckMessageRequest {
     fn wire2api(self) -> CheckMessageRequest {
         CheckMessageRequest {
@@ -600,6 +879,7 @@ impl Wire2Api<Config> for wire_Config {
             breezserver: self.breezserver.wire2api(),
             mempoolspace_url: self.mempoolspace_url.wire2api(),
             working_dir: self.working_dir.wire2api(),
+            storage_key: self.storage_key.wire2api(),
             network: self.network.wire2api(),
             payment_timeout_sec: self.payment_timeout_sec.wire2api(),
             default_lsp_id: self.default_lsp_id.wire2api(),
@@ -607,6 +887,30 @@ impl Wire2Api<Config> for wire_Config {
             maxfee_percent: self.maxfee_percent.wire2api(),
             exemptfee_msat: self.exemptfee_msat.wire2api(),
             node_config: self.node_config.wire2api(),
+            fiat_rates_urls: self.fiat_rates_urls.wire2api(),
+            recorded_fiat_currencies: self.recorded_fiat_currencies.wire2api(),
+            event_log_retention: self.event_log_retention.wire2api(),
+            auto_select_lsp: self.auto_select_lsp.wire2api(),
+            persist_lsp_failover: self.persist_lsp_failover.wire2api(),
+        }
+    }
+}
+impl Wire2Api<DbMaintenanceRequest> for wire_DbMaintenanceRequest {
+    fn wire2api(self) -> DbMaintenanceRequest {
+        DbMaintenanceRequest {
+            failed_payments_retention_days: self.failed_payments_retention_days.wire2api(),
+            backup_versions_to_keep: self.backup_versions_to_keep.wire2api(),
+        }
+    }
+}
+
+impl Wire2Api<ExportPaymentsRequest> for wire_ExportPaymentsRequest {
+    fn wire2api(self) -> ExportPaymentsRequest {
+        ExportPaymentsRequest {
+            format: self.format.wire2api(),
+            from_timestamp: self.from_timestamp.wire2api(),
+            to_timestamp: self.to_timestamp.wire2api(),
+            fiat_currency: self.fiat_currency.wire2api(),
         }
     }
 }
@@ -646,6 +950,11 @@ impl Wire2Api<ListPaymentsRequest> for wire_ListPaymentsRequest {
             include_failures: self.include_failures.wire2api(),
             offset: self.offset.wire2api(),
             limit: self.limit.wire2api(),
+            cursor: self.cursor.wire2api(),
+            search: self.search.wire2api(),
+            min_amount_msat: self.min_amount_msat.wire2api(),
+            max_amount_msat: self.max_amount_msat.wire2api(),
+            tags: self.tags.wire2api(),
         }
     }
 }
@@ -737,6 +1046,22 @@ impl Wire2Api<OpeningFeeParams> for wire_OpeningFeeParams {
     }
 }
 
+impl Wire2Api<PaymentsCursor> for wire_PaymentsCursor {
+    fn wire2api(self) -> PaymentsCursor {
+        PaymentsCursor {
+            payment_time: self.payment_time.wire2api(),
+            id: self.id.wire2api(),
+        }
+    }
+}
+impl Wire2Api<PrepareBuyInboundLiquidityRequest> for wire_PrepareBuyInboundLiquidityRequest {
+    fn wire2api(self) -> PrepareBuyInboundLiquidityRequest {
+        PrepareBuyInboundLiquidityRequest {
+            amount_sat: self.amount_sat.wire2api(),
+            lease_blocks: self.lease_blocks.wire2api(),
+        }
+    }
+}
 impl Wire2Api<PrepareRefundRequest> for wire_PrepareRefundRequest {
     fn wire2api(self) -> PrepareRefundRequest {
         PrepareRefundRequest {
@@ -754,6 +1079,27 @@ impl Wire2Api<PrepareSweepRequest> for wire_PrepareSweepRequest {
         }
     }
 }
+impl Wire2Api<ProcessNotificationRequest> for wire_ProcessNotificationRequest {
+    fn wire2api(self) -> ProcessNotificationRequest {
+        ProcessNotificationRequest {
+            payload: self.payload.wire2api(),
+            deadline: self.deadline.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ReceiveFiatPaymentRequest> for wire_ReceiveFiatPaymentRequest {
+    fn wire2api(self) -> ReceiveFiatPaymentRequest {
+        ReceiveFiatPaymentRequest {
+            fiat_currency: self.fiat_currency.wire2api(),
+            fiat_amount: self.fiat_amount.wire2api(),
+            description: self.description.wire2api(),
+            opening_fee_params: self.opening_fee_params.wire2api(),
+            use_description_hash: self.use_description_hash.wire2api(),
+            expiry: self.expiry.wire2api(),
+            cltv: self.cltv.wire2api(),
+        }
+    }
+}
 impl Wire2Api<ReceiveOnchainRequest> for wire_ReceiveOnchainRequest {
     fn wire2api(self) -> ReceiveOnchainRequest {
         ReceiveOnchainRequest {
@@ -790,6 +1136,15 @@ impl Wire2Api<ReverseSwapFeesRequest> for wire_ReverseSwapFeesRequest {
         }
     }
 }
+impl Wire2Api<SendFiatPaymentRequest> for wire_SendFiatPaymentRequest {
+    fn wire2api(self) -> SendFiatPaymentRequest {
+        SendFiatPaymentRequest {
+            bolt11: self.bolt11.wire2api(),
+            fiat_currency: self.fiat_currency.wire2api(),
+            fiat_amount: self.fiat_amount.wire2api(),
+        }
+    }
+}
 impl Wire2Api<SendOnchainRequest> for wire_SendOnchainRequest {
     fn wire2api(self) -> SendOnchainRequest {
         SendOnchainRequest {
@@ -827,6 +1182,8 @@ impl Wire2Api<StaticBackupRequest> for wire_StaticBackupRequest {
     fn wire2api(self) -> StaticBackupRequest {
         StaticBackupRequest {
             working_dir: self.working_dir.wire2api(),
+            seed: self.seed.wire2api(),
+            storage_key: self.storage_key.wire2api(),
         }
     }
 }
@@ -849,6 +1206,13 @@ impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
 }
 // Section: wire structs
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_StringList {
+    ptr: *mut *mut wire_uint_8_list,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_BuyBitcoinRequest {
@@ -856,6 +1220,18 @@ pub struct wire_BuyBitcoinRequest {
     opening_fee_params: *mut wire_OpeningFeeParams,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_BuyInboundLiquidityRequest {
+    order_id: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChangeBackupPassphraseRequest {
+    passphrase: *mut wire_uint_8_list,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_CheckMessageRequest {
@@ -870,6 +1246,7 @@ pub struct wire_Config {
     breezserver: *mut wire_uint_8_list,
     mempoolspace_url: *mut wire_uint_8_list,
     working_dir: *mut wire_uint_8_list,
+    storage_key: *mut wire_uint_8_list,
     network: i32,
     payment_timeout_sec: u32,
     default_lsp_id: *mut wire_uint_8_list,
@@ -877,6 +1254,27 @@ pub struct wire_Config {
     maxfee_percent: f64,
     exemptfee_msat: u64,
     node_config: wire_NodeConfig,
+    fiat_rates_urls: *mut wire_StringList,
+    recorded_fiat_currencies: *mut wire_StringList,
+    event_log_retention: u32,
+    auto_select_lsp: bool,
+    persist_lsp_failover: bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_DbMaintenanceRequest {
+    failed_payments_retention_days: *mut u32,
+    backup_versions_to_keep: *mut u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ExportPaymentsRequest {
+    format: i32,
+    from_timestamp: *mut i64,
+    to_timestamp: *mut i64,
+    fiat_currency: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
@@ -909,6 +1307,11 @@ pub struct wire_ListPaymentsRequest {
     include_failures: *mut bool,
     offset: *mut u32,
     limit: *mut u32,
+    cursor: *mut wire_PaymentsCursor,
+    search: *mut wire_uint_8_list,
+    min_amount_msat: *mut u64,
+    max_amount_msat: *mut u64,
+    tags: *mut wire_StringList,
 }
 
 #[repr(C)]
@@ -976,6 +1379,20 @@ pub struct wire_OpeningFeeParams {
     promise: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PaymentsCursor {
+    payment_time: i64,
+    id: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PrepareBuyInboundLiquidityRequest {
+    amount_sat: u64,
+    lease_blocks: u32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_PrepareRefundRequest {
@@ -991,6 +1408,25 @@ pub struct wire_PrepareSweepRequest {
     sats_per_vbyte: u64,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ProcessNotificationRequest {
+    payload: *mut wire_uint_8_list,
+    deadline: u64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ReceiveFiatPaymentRequest {
+    fiat_currency: *mut wire_uint_8_list,
+    fiat_amount: f64,
+    description: *mut wire_uint_8_list,
+    opening_fee_params: *mut wire_OpeningFeeParams,
+    use_description_hash: *mut bool,
+    expiry: *mut u32,
+    cltv: *mut u32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ReceiveOnchainRequest {
@@ -1023,6 +1459,14 @@ pub struct wire_ReverseSwapFeesRequest {
     send_amount_sat: *mut u64,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_SendFiatPaymentRequest {
+    bolt11: *mut wire_uint_8_list,
+    fiat_currency: *mut wire_uint_8_list,
+    fiat_amount: f64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_SendOnchainRequest {
@@ -1056,6 +1500,8 @@ pub struct wire_SignMessageRequest {
 #[derive(Clone)]
 pub struct wire_StaticBackupRequest {
     working_dir: *mut wire_uint_8_list,
+    seed: *mut wire_uint_8_list,
+    storage_key: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
@@ -1117,6 +1563,34 @@ impl Default for wire_BuyBitcoinRequest {
     }
 }
 
+impl NewWithNullPtr for wire_BuyInboundLiquidityRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            order_id: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_BuyInboundLiquidityRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ChangeBackupPassphraseRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            passphrase: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ChangeBackupPassphraseRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_CheckMessageRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1139,6 +1613,7 @@ impl NewWithNullPtr for wire_Config {
             breezserver: core::ptr::null_mut(),
             mempoolspace_url: core::ptr::null_mut(),
             working_dir: core::ptr::null_mut(),
+            storage_key: core::ptr::null_mut(),
             network: Default::default(),
             payment_timeout_sec: Default::default(),
             default_lsp_id: core::ptr::null_mut(),
@@ -1146,6 +1621,11 @@ impl NewWithNullPtr for wire_Config {
             maxfee_percent: Default::default(),
             exemptfee_msat: Default::default(),
             node_config: Default::default(),
+            fiat_rates_urls: core::ptr::null_mut(),
+            recorded_fiat_currencies: core::ptr::null_mut(),
+            event_log_retention: Default::default(),
+            auto_select_lsp: Default::default(),
+            persist_lsp_failover: Default::default(),
         }
     }
 }
@@ -1156,6 +1636,38 @@ impl Default for wire_Config {
     }
 }
 
+impl NewWithNullPtr for wire_DbMaintenanceRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            failed_payments_retention_days: core::ptr::null_mut(),
+            backup_versions_to_keep: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_DbMaintenanceRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ExportPaymentsRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            format: Default::default(),
+            from_timestamp: core::ptr::null_mut(),
+            to_timestamp: core::ptr::null_mut(),
+            fiat_currency: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ExportPaymentsRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_GreenlightCredentials {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1195,6 +1707,11 @@ impl NewWithNullPtr for wire_ListPaymentsRequest {
             include_failures: core::ptr::null_mut(),
             offset: core::ptr::null_mut(),
             limit: core::ptr::null_mut(),
+            cursor: core::ptr::null_mut(),
+            search: core::ptr::null_mut(),
+            min_amount_msat: core::ptr::null_mut(),
+            max_amount_msat: core::ptr::null_mut(),
+            tags: core::ptr::null_mut(),
         }
     }
 }
@@ -1350,6 +1867,36 @@ impl Default for wire_OpeningFeeParams {
     }
 }
 
+impl NewWithNullPtr for wire_PaymentsCursor {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payment_time: Default::default(),
+            id: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_PaymentsCursor {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_PrepareBuyInboundLiquidityRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_sat: Default::default(),
+            lease_blocks: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_PrepareBuyInboundLiquidityRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_PrepareRefundRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1381,6 +1928,41 @@ impl Default for wire_PrepareSweepRequest {
     }
 }
 
+impl NewWithNullPtr for wire_ProcessNotificationRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payload: core::ptr::null_mut(),
+            deadline: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_ProcessNotificationRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ReceiveFiatPaymentRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            fiat_currency: core::ptr::null_mut(),
+            fiat_amount: Default::default(),
+            description: core::ptr::null_mut(),
+            opening_fee_params: core::ptr::null_mut(),
+            use_description_hash: core::ptr::null_mut(),
+            expiry: core::ptr::null_mut(),
+            cltv: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ReceiveFiatPaymentRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_ReceiveOnchainRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1445,6 +2027,22 @@ impl Default for wire_ReverseSwapFeesRequest {
     }
 }
 
+impl NewWithNullPtr for wire_SendFiatPaymentRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            bolt11: core::ptr::null_mut(),
+            fiat_currency: core::ptr::null_mut(),
+            fiat_amount: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_SendFiatPaymentRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_SendOnchainRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1510,6 +2108,8 @@ impl NewWithNullPtr for wire_StaticBackupRequest {
     fn new_with_null_ptr() -> Self {
         Self {
             working_dir: core::ptr::null_mut(),
+            seed: core::ptr::null_mut(),
+            storage_key: core::ptr::null_mut(),
         }
     }
 }
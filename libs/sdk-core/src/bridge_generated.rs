@@ -24,16 +24,21 @@ use crate::breez_services::BreezEvent;
 use crate::breez_services::CheckMessageRequest;
 use crate::breez_services::CheckMessageResponse;
 use crate::breez_services::InvoicePaidDetails;
+use crate::breez_services::NodeBalance;
 use crate::breez_services::PaymentFailedData;
+use crate::breez_services::PersistedEvent;
 use crate::breez_services::SignMessageRequest;
 use crate::breez_services::SignMessageResponse;
 use crate::chain::RecommendedFees;
 use crate::fiat::CurrencyInfo;
 use crate::fiat::FiatCurrency;
+use crate::fiat::FiatRates;
 use crate::fiat::LocaleOverrides;
 use crate::fiat::LocalizedName;
+use crate::fiat::PaymentFiatRate;
 use crate::fiat::Rate;
 use crate::fiat::Symbol;
+use crate::format::BitcoinUnit;
 use crate::input_parser::BitcoinAddressData;
 use crate::input_parser::InputType;
 use crate::input_parser::LnUrlAuthRequestData;
@@ -50,15 +55,27 @@ use crate::lnurl::pay::model::SuccessActionProcessed;
 use crate::lnurl::pay::model::UrlSuccessActionData;
 use crate::lsp::LspInformation;
 use crate::models::BackupStatus;
+use crate::models::BackupTableDiff;
+use crate::models::BackupVerificationReport;
+use crate::models::BackupVersion;
 use crate::models::BuyBitcoinProvider;
 use crate::models::BuyBitcoinRequest;
 use crate::models::BuyBitcoinResponse;
+use crate::models::BuyInboundLiquidityRequest;
+use crate::models::BuyInboundLiquidityResponse;
+use crate::models::ChangeBackupPassphraseRequest;
 use crate::models::ChannelState;
 use crate::models::ClosedChannelPaymentDetails;
 use crate::models::Config;
+use crate::models::DbMaintenanceReport;
+use crate::models::DbMaintenanceRequest;
 use crate::models::EnvironmentType;
+use crate::models::ExportPaymentsRequest;
+use crate::models::FiatQuote;
 use crate::models::GreenlightCredentials;
 use crate::models::GreenlightNodeConfig;
+use crate::models::LiquidityOrder;
+use crate::models::LiquidityOrderState;
 use crate::models::ListPaymentsRequest;
 use crate::models::LnPaymentDetails;
 use crate::models::LnUrlCallbackStatus;
@@ -76,13 +93,22 @@ use crate::models::OpeningFeeParams;
 use crate::models::OpeningFeeParamsMenu;
 use crate::models::Payment;
 use crate::models::PaymentDetails;
+use crate::models::PaymentExportFormat;
+use crate::models::PaymentMetadata;
 use crate::models::PaymentStatus;
 use crate::models::PaymentType;
 use crate::models::PaymentTypeFilter;
+use crate::models::PaymentsCursor;
+use crate::models::PrepareBuyInboundLiquidityRequest;
+use crate::models::PrepareBuyInboundLiquidityResponse;
 use crate::models::PrepareRefundRequest;
 use crate::models::PrepareRefundResponse;
 use crate::models::PrepareSweepRequest;
 use crate::models::PrepareSweepResponse;
+use crate::models::ProcessNotificationRequest;
+use crate::models::ProcessNotificationResult;
+use crate::models::ReceiveFiatPaymentRequest;
+use crate::models::ReceiveFiatPaymentResponse;
 use crate::models::ReceiveOnchainRequest;
 use crate::models::ReceivePaymentRequest;
 use crate::models::ReceivePaymentResponse;
@@ -92,6 +118,8 @@ use crate::models::ReverseSwapFeesRequest;
 use crate::models::ReverseSwapInfo;
 use crate::models::ReverseSwapPairInfo;
 use crate::models::ReverseSwapStatus;
+use crate::models::SendFiatPaymentRequest;
+use crate::models::SendFiatPaymentResponse;
 use crate::models::SendOnchainRequest;
 use crate::models::SendOnchainResponse;
 use crate::models::SendPaymentRequest;
@@ -194,6 +222,35 @@ fn wire_check_message_impl(
         },
     )
 }
+fn wire_process_notification_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ProcessNotificationRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "process_notification",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| process_notification(api_req)
+        },
+    )
+}
+fn wire_register_webhook_impl(port_: MessagePort, webhook_url: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "register_webhook",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_webhook_url = webhook_url.wire2api();
+            move |task_callback| register_webhook(api_webhook_url)
+        },
+    )
+}
 fn wire_mnemonic_to_seed_impl(port_: MessagePort, phrase: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -253,6 +310,42 @@ fn wire_breez_events_stream_impl(port_: MessagePort) {
         move || move |task_callback| breez_events_stream(task_callback.stream_sink()),
     )
 }
+fn wire_add_event_stream_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "add_event_stream",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || move |task_callback| add_event_stream(task_callback.stream_sink()),
+    )
+}
+fn wire_remove_event_stream_impl(port_: MessagePort, id: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "remove_event_stream",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.wire2api();
+            move |task_callback| remove_event_stream(api_id)
+        },
+    )
+}
+fn wire_events_since_impl(port_: MessagePort, seq: impl Wire2Api<u64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "events_since",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_seq = seq.wire2api();
+            move |task_callback| events_since(api_seq)
+        },
+    )
+}
 fn wire_breez_log_stream_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -286,6 +379,19 @@ fn wire_connect_lsp_impl(port_: MessagePort, lsp_id: impl Wire2Api<String> + Unw
         },
     )
 }
+fn wire_connect_lsp_by_uri_impl(port_: MessagePort, uri: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "connect_lsp_by_uri",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_uri = uri.wire2api();
+            move |task_callback| connect_lsp_by_uri(api_uri)
+        },
+    )
+}
 fn wire_lsp_id_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -329,6 +435,38 @@ fn wire_close_lsp_channels_impl(port_: MessagePort) {
         move || move |task_callback| close_lsp_channels(),
     )
 }
+fn wire_prepare_buy_inbound_liquidity_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<PrepareBuyInboundLiquidityRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "prepare_buy_inbound_liquidity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| prepare_buy_inbound_liquidity(api_req)
+        },
+    )
+}
+fn wire_buy_inbound_liquidity_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<BuyInboundLiquidityRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "buy_inbound_liquidity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| buy_inbound_liquidity(api_req)
+        },
+    )
+}
 fn wire_backup_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -349,6 +487,107 @@ fn wire_backup_status_impl(port_: MessagePort) {
         move || move |task_callback| backup_status(),
     )
 }
+fn wire_verify_backup_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "verify_backup",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| verify_backup(),
+    )
+}
+fn wire_list_backup_versions_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_backup_versions",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_backup_versions(),
+    )
+}
+fn wire_restore_backup_impl(port_: MessagePort, version: impl Wire2Api<u64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "restore_backup",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_version = version.wire2api();
+            move |task_callback| restore_backup(api_version)
+        },
+    )
+}
+fn wire_change_backup_passphrase_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ChangeBackupPassphraseRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "change_backup_passphrase",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| change_backup_passphrase(api_req)
+        },
+    )
+}
+fn wire_export_backup_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "export_backup",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            move |task_callback| export_backup(api_path, api_passphrase)
+        },
+    )
+}
+fn wire_import_backup_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "import_backup",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            move |task_callback| import_backup(api_path, api_passphrase)
+        },
+    )
+}
+fn wire_db_maintenance_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<DbMaintenanceRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "db_maintenance",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| db_maintenance(api_req)
+        },
+    )
+}
 fn wire_parse_invoice_impl(port_: MessagePort, invoice: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -404,6 +643,42 @@ fn wire_payment_by_hash_impl(port_: MessagePort, hash: impl Wire2Api<String> + U
         },
     )
 }
+fn wire_export_payments_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ExportPaymentsRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "export_payments",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| export_payments(api_req)
+        },
+    )
+}
+fn wire_set_payment_metadata_impl(
+    port_: MessagePort,
+    hash: impl Wire2Api<String> + UnwindSafe,
+    note: impl Wire2Api<Option<String>> + UnwindSafe,
+    tags: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_payment_metadata",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_hash = hash.wire2api();
+            let api_note = note.wire2api();
+            let api_tags = tags.wire2api();
+            move |task_callback| set_payment_metadata(api_hash, api_note, api_tags)
+        },
+    )
+}
 fn wire_send_payment_impl(port_: MessagePort, req: impl Wire2Api<SendPaymentRequest> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -449,6 +724,38 @@ fn wire_receive_payment_impl(
         },
     )
 }
+fn wire_send_fiat_payment_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<SendFiatPaymentRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "send_fiat_payment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| send_fiat_payment(api_req)
+        },
+    )
+}
+fn wire_receive_fiat_payment_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ReceiveFiatPaymentRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "receive_fiat_payment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| receive_fiat_payment(api_req)
+        },
+    )
+}
 fn wire_lnurl_pay_impl(port_: MessagePort, req: impl Wire2Api<LnUrlPayRequest> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -504,6 +811,56 @@ fn wire_fetch_fiat_rates_impl(port_: MessagePort) {
         move || move |task_callback| fetch_fiat_rates(),
     )
 }
+fn wire_fiat_rates_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "fiat_rates",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| fiat_rates(),
+    )
+}
+fn wire_format_fiat_impl(
+    port_: MessagePort,
+    amount: impl Wire2Api<f64> + UnwindSafe,
+    currency_id: impl Wire2Api<String> + UnwindSafe,
+    locale: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "format_fiat",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount = amount.wire2api();
+            let api_currency_id = currency_id.wire2api();
+            let api_locale = locale.wire2api();
+            move |task_callback| format_fiat(api_amount, api_currency_id, api_locale)
+        },
+    )
+}
+fn wire_format_sats_impl(
+    port_: MessagePort,
+    amount_msat: impl Wire2Api<u64> + UnwindSafe,
+    unit: impl Wire2Api<BitcoinUnit> + UnwindSafe,
+    locale: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "format_sats",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount_msat = amount_msat.wire2api();
+            let api_unit = unit.wire2api();
+            let api_locale = locale.wire2api();
+            move |task_callback| Ok(format_sats(api_amount_msat, api_unit, api_locale))
+        },
+    )
+}
 fn wire_list_fiat_currencies_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -722,6 +1079,16 @@ where
     }
 }
 
+impl Wire2Api<BitcoinUnit> for i32 {
+    fn wire2api(self) -> BitcoinUnit {
+        match self {
+            0 => BitcoinUnit::Btc,
+            1 => BitcoinUnit::Sat,
+            2 => BitcoinUnit::Msat,
+            _ => unreachable!("Invalid variant for BitcoinUnit: {}", self),
+        }
+    }
+}
 impl Wire2Api<bool> for bool {
     fn wire2api(self) -> bool {
         self
@@ -746,6 +1113,7 @@ impl Wire2Api<EnvironmentType> for i32 {
         }
     }
 }
+
 impl Wire2Api<f64> for f64 {
     fn wire2api(self) -> f64 {
         self
@@ -775,6 +1143,15 @@ impl Wire2Api<Network> for i32 {
     }
 }
 
+impl Wire2Api<PaymentExportFormat> for i32 {
+    fn wire2api(self) -> PaymentExportFormat {
+        match self {
+            0 => PaymentExportFormat::Csv,
+            1 => PaymentExportFormat::Json,
+            _ => unreachable!("Invalid variant for PaymentExportFormat: {}", self),
+        }
+    }
+}
 impl Wire2Api<PaymentTypeFilter> for i32 {
     fn wire2api(self) -> PaymentTypeFilter {
         match self {
@@ -834,6 +1211,40 @@ impl support::IntoDart for BackupStatus {
 }
 impl support::IntoDartExceptPrimitive for BackupStatus {}
 
+impl support::IntoDart for BackupTableDiff {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.table.into_dart(),
+            self.local_rows.into_dart(),
+            self.remote_rows.into_dart(),
+            self.in_sync.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BackupTableDiff {}
+
+impl support::IntoDart for BackupVerificationReport {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.remote_generation.into_dart(),
+            self.local_version.into_dart(),
+            self.integrity_ok.into_dart(),
+            self.in_sync.into_dart(),
+            self.diff.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BackupVerificationReport {}
+
+impl support::IntoDart for BackupVersion {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.version.into_dart(), self.created_at.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BackupVersion {}
+
 impl support::IntoDart for BitcoinAddressData {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -859,6 +1270,24 @@ impl support::IntoDart for BreezEvent {
             Self::BackupStarted => vec![5.into_dart()],
             Self::BackupSucceeded => vec![6.into_dart()],
             Self::BackupFailed { details } => vec![7.into_dart(), details.into_dart()],
+            Self::RemoteStateChanged => vec![8.into_dart()],
+            Self::ChannelOpened { funding_txid } => vec![9.into_dart(), funding_txid.into_dart()],
+            Self::ChannelPendingClose { funding_txid } => {
+                vec![10.into_dart(), funding_txid.into_dart()]
+            }
+            Self::ChannelClosed {
+                funding_txid,
+                closing_txid,
+            } => vec![
+                11.into_dart(),
+                funding_txid.into_dart(),
+                closing_txid.into_dart(),
+            ],
+            Self::BalanceChanged { previous, current } => {
+                vec![12.into_dart(), previous.into_dart(), current.into_dart()]
+            }
+            Self::LiquidityOrderUpdated { details } => vec![13.into_dart(), details.into_dart()],
+            Self::BackupRestored { version } => vec![14.into_dart(), version.into_dart()],
         }
         .into_dart()
     }
@@ -871,6 +1300,13 @@ impl support::IntoDart for BuyBitcoinResponse {
 }
 impl support::IntoDartExceptPrimitive for BuyBitcoinResponse {}
 
+impl support::IntoDart for BuyInboundLiquidityResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.order.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BuyInboundLiquidityResponse {}
+
 impl support::IntoDart for ChannelState {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -909,6 +1345,7 @@ impl support::IntoDart for Config {
             self.breezserver.into_dart(),
             self.mempoolspace_url.into_dart(),
             self.working_dir.into_dart(),
+            self.storage_key.into_dart(),
             self.network.into_dart(),
             self.payment_timeout_sec.into_dart(),
             self.default_lsp_id.into_dart(),
@@ -916,6 +1353,11 @@ impl support::IntoDart for Config {
             self.maxfee_percent.into_dart(),
             self.exemptfee_msat.into_dart(),
             self.node_config.into_dart(),
+            self.fiat_rates_urls.into_dart(),
+            self.recorded_fiat_currencies.into_dart(),
+            self.event_log_retention.into_dart(),
+            self.auto_select_lsp.into_dart(),
+            self.persist_lsp_failover.into_dart(),
         ]
         .into_dart()
     }
@@ -938,6 +1380,23 @@ impl support::IntoDart for CurrencyInfo {
 }
 impl support::IntoDartExceptPrimitive for CurrencyInfo {}
 
+impl support::IntoDart for DbMaintenanceReport {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.main_db_ok.into_dart(),
+            self.sync_db_ok.into_dart(),
+            self.recovered_payments.into_dart(),
+            self.recovered_swaps.into_dart(),
+            self.recovered_reverse_swaps.into_dart(),
+            self.pruned_failed_payments.into_dart(),
+            self.pruned_backup_versions.into_dart(),
+            self.pruned_cached_items.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DbMaintenanceReport {}
+
 impl support::IntoDart for FiatCurrency {
     fn into_dart(self) -> support::DartAbi {
         vec![self.id.into_dart(), self.info.into_dart()].into_dart()
@@ -945,6 +1404,32 @@ impl support::IntoDart for FiatCurrency {
 }
 impl support::IntoDartExceptPrimitive for FiatCurrency {}
 
+impl support::IntoDart for FiatQuote {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.fiat_currency.into_dart(),
+            self.fiat_amount.into_dart(),
+            self.rate.into_dart(),
+            self.amount_msat.into_dart(),
+            self.expires_at.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FiatQuote {}
+
+impl support::IntoDart for FiatRates {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.rates.into_dart(),
+            self.fetched_at.into_dart(),
+            self.is_stale.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FiatRates {}
+
 impl support::IntoDart for GreenlightCredentials {
     fn into_dart(self) -> support::DartAbi {
         vec![self.device_key.into_dart(), self.device_cert.into_dart()].into_dart()
@@ -986,6 +1471,35 @@ impl support::IntoDart for InvoicePaidDetails {
 }
 impl support::IntoDartExceptPrimitive for InvoicePaidDetails {}
 
+impl support::IntoDart for LiquidityOrder {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.order_id.into_dart(),
+            self.state.into_dart(),
+            self.lsp_balance_sat.into_dart(),
+            self.lease_blocks.into_dart(),
+            self.fee_sat.into_dart(),
+            self.bolt11.into_dart(),
+            self.funding_outpoint.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LiquidityOrder {}
+
+impl support::IntoDart for LiquidityOrderState {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Created => 0,
+            Self::Paid => 1,
+            Self::Completed => 2,
+            Self::Failed => 3,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LiquidityOrderState {}
+
 impl support::IntoDart for LNInvoice {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1178,6 +1692,17 @@ impl support::IntoDart for Network {
     }
 }
 impl support::IntoDartExceptPrimitive for Network {}
+impl support::IntoDart for NodeBalance {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.channels_balance_msat.into_dart(),
+            self.onchain_balance_msat.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for NodeBalance {}
+
 impl support::IntoDart for NodeConfig {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -1247,6 +1772,8 @@ impl support::IntoDart for Payment {
             self.status.into_dart(),
             self.description.into_dart(),
             self.details.into_dart(),
+            self.metadata.into_dart(),
+            self.fiat_rates.into_dart(),
         ]
         .into_dart()
     }
@@ -1275,6 +1802,20 @@ impl support::IntoDart for PaymentFailedData {
 }
 impl support::IntoDartExceptPrimitive for PaymentFailedData {}
 
+impl support::IntoDart for PaymentFiatRate {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.coin.into_dart(), self.value_micros.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentFiatRate {}
+
+impl support::IntoDart for PaymentMetadata {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.note.into_dart(), self.tags.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentMetadata {}
+
 impl support::IntoDart for PaymentStatus {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -1297,6 +1838,25 @@ impl support::IntoDart for PaymentType {
     }
 }
 impl support::IntoDartExceptPrimitive for PaymentType {}
+impl support::IntoDart for PersistedEvent {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.seq.into_dart(),
+            self.created_at.into_dart(),
+            self.event.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PersistedEvent {}
+
+impl support::IntoDart for PrepareBuyInboundLiquidityResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.order.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PrepareBuyInboundLiquidityResponse {}
+
 impl support::IntoDart for PrepareRefundResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1319,6 +1879,17 @@ impl support::IntoDart for PrepareSweepResponse {
 }
 impl support::IntoDartExceptPrimitive for PrepareSweepResponse {}
 
+impl support::IntoDart for ProcessNotificationResult {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::PaymentReceived { payment } => vec![0.into_dart(), payment.into_dart()],
+            Self::SwapUpdated { swap_info } => vec![1.into_dart(), swap_info.into_dart()],
+            Self::DeadlineReached => vec![2.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ProcessNotificationResult {}
 impl support::IntoDart for Rate {
     fn into_dart(self) -> support::DartAbi {
         vec![self.coin.into_dart(), self.value.into_dart()].into_dart()
@@ -1326,6 +1897,19 @@ impl support::IntoDart for Rate {
 }
 impl support::IntoDartExceptPrimitive for Rate {}
 
+impl support::IntoDart for ReceiveFiatPaymentResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.ln_invoice.into_dart(),
+            self.opening_fee_params.into_dart(),
+            self.opening_fee_msat.into_dart(),
+            self.quote.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ReceiveFiatPaymentResponse {}
+
 impl support::IntoDart for ReceivePaymentResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1426,6 +2010,13 @@ impl support::IntoDart for RouteHintHop {
 }
 impl support::IntoDartExceptPrimitive for RouteHintHop {}
 
+impl support::IntoDart for SendFiatPaymentResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.payment.into_dart(), self.quote.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SendFiatPaymentResponse {}
+
 impl support::IntoDart for SendOnchainResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![self.reverse_swap_info.into_dart()].into_dart()
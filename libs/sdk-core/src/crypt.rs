@@ -1,4 +1,6 @@
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
 
 pub fn encrypt(key: Vec<u8>, msg: Vec<u8>) -> Result<Vec<u8>> {
     match ecies::encrypt(key.as_slice(), msg.as_slice()) {
@@ -14,3 +16,38 @@ pub fn decrypt(key: Vec<u8>, msg: Vec<u8>) -> Result<Vec<u8>> {
         Err(err) => Err(anyhow!(err)),
     }
 }
+
+/// Derives a 32 bytes symmetric key from a user passphrase using PBKDF2-HMAC-SHA256
+pub fn derive_passphrase_key(passphrase: &str, salt: &[u8], rounds: u32) -> Vec<u8> {
+    let mut key = vec![0; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase.as_bytes(), salt, rounds, &mut key);
+    key
+}
+
+/// Derives a 32 bytes symmetric key from a user passphrase using scrypt (`N = 2^15, r = 8, p = 1`)
+pub fn derive_scrypt_key(passphrase: &str, salt: &[u8]) -> Result<Vec<u8>> {
+    let params = scrypt::Params::new(15, 8, 1).map_err(|e| anyhow!("{e}"))?;
+    let mut key = vec![0; 32];
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key).map_err(|e| anyhow!("{e}"))?;
+    Ok(key)
+}
+
+/// Derives the 32 bytes key used to encrypt the local storage from the seed
+pub fn derive_storage_key(seed: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"breez-sdk-storage")?;
+    mac.update(seed);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// Returns the key used to encrypt the local storage: the app-provided one if set, otherwise
+/// one derived from the seed
+pub fn storage_key(app_key: Option<Vec<u8>>, seed: Option<&[u8]>) -> Result<Option<Vec<u8>>> {
+    match (app_key, seed) {
+        (Some(key), _) => {
+            ensure!(key.len() == 32, "The storage key must be 32 bytes long");
+            Ok(Some(key))
+        }
+        (None, Some(seed)) => Ok(Some(derive_storage_key(seed)?)),
+        (None, None) => Ok(None),
+    }
+}
@@ -0,0 +1,365 @@
+use anyhow::Result;
+use bitcoin::hashes::{hex::ToHex, sha256, Hash};
+use chrono::{SecondsFormat, TimeZone, Utc};
+use serde::Serialize;
+
+use crate::fiat::Rate;
+use crate::models::{
+    FullReverseSwapInfo, Payment, PaymentDetails, PaymentExportFormat, PaymentStatus, SwapInfo,
+};
+
+const CSV_HEADER: [&str; 22] = [
+    "id",
+    "payment_time",
+    "timestamp",
+    "payment_type",
+    "status",
+    "amount_msat",
+    "fee_msat",
+    "description",
+    "note",
+    "tags",
+    "payment_hash",
+    "ln_address",
+    "lnurl_withdraw_endpoint",
+    "swap_address",
+    "reverse_swap_id",
+    "short_channel_id",
+    "funding_txid",
+    "closing_txid",
+    "fiat_currency",
+    "fiat_rate",
+    "fiat_rate_is_current",
+    "fiat_value",
+];
+
+/// A row of the exported payment history, see [crate::BreezServices::export_payments]
+#[derive(Serialize, Debug)]
+pub(crate) struct PaymentExportRow {
+    pub id: String,
+    pub payment_time: i64,
+    /// The payment time, in RFC 3339 format
+    pub timestamp: String,
+    pub payment_type: String,
+    pub status: String,
+    pub amount_msat: u64,
+    pub fee_msat: u64,
+    pub description: Option<String>,
+    pub note: Option<String>,
+    pub tags: Vec<String>,
+    pub payment_hash: Option<String>,
+    pub ln_address: Option<String>,
+    pub lnurl_withdraw_endpoint: Option<String>,
+    /// Address of the swap in which this payment was received
+    pub swap_address: Option<String>,
+    /// Id of the reverse swap in which this payment was sent
+    pub reverse_swap_id: Option<String>,
+    pub short_channel_id: Option<String>,
+    pub funding_txid: Option<String>,
+    pub closing_txid: Option<String>,
+    pub fiat_currency: Option<String>,
+    /// The rate recorded with the payment or, for payments without recorded rates, the current one
+    pub fiat_rate: Option<f64>,
+    /// Whether `fiat_rate` is the current rate, because no rate was recorded with the payment
+    pub fiat_rate_is_current: bool,
+    /// The amount of the payment in the fiat currency, using `fiat_rate`
+    pub fiat_value: Option<f64>,
+}
+
+impl PaymentExportRow {
+    fn new(
+        payment: Payment,
+        swaps: &[SwapInfo],
+        reverse_swaps: &[FullReverseSwapInfo],
+        fiat_currency: Option<&str>,
+        current_rate: Option<&Rate>,
+    ) -> Self {
+        let recorded_rate = fiat_currency.and_then(|currency| {
+            payment
+                .fiat_rates
+                .iter()
+                .find(|r| r.coin == currency)
+                .map(|r| r.value())
+        });
+        let fiat_rate_is_current =
+            fiat_currency.is_some() && recorded_rate.is_none() && current_rate.is_some();
+        let fiat_rate = match fiat_rate_is_current {
+            true => current_rate.map(|r| r.value),
+            false => recorded_rate,
+        };
+        let mut row = PaymentExportRow {
+            id: payment.id,
+            payment_time: payment.payment_time,
+            timestamp: Utc
+                .timestamp_opt(payment.payment_time, 0)
+                .single()
+                .map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true))
+                .unwrap_or_default(),
+            payment_type: payment.payment_type.to_string(),
+            status: status_name(payment.status).to_string(),
+            amount_msat: payment.amount_msat,
+            fee_msat: payment.fee_msat,
+            description: payment.description,
+            note: None,
+            tags: vec![],
+            payment_hash: None,
+            ln_address: None,
+            lnurl_withdraw_endpoint: None,
+            swap_address: None,
+            reverse_swap_id: None,
+            short_channel_id: None,
+            funding_txid: None,
+            closing_txid: None,
+            fiat_currency: fiat_currency.map(String::from),
+            fiat_rate,
+            fiat_rate_is_current,
+            fiat_value: fiat_rate.map(|rate| payment.amount_msat as f64 * rate / 100_000_000_000.0),
+        };
+
+        if let Some(metadata) = payment.metadata {
+            row.note = metadata.note;
+            row.tags = metadata.tags;
+        }
+
+        match payment.details {
+            PaymentDetails::Ln { data } => {
+                row.swap_address = swaps
+                    .iter()
+                    .find(|s| s.payment_hash.to_hex() == data.payment_hash)
+                    .map(|s| s.bitcoin_address.clone());
+                row.reverse_swap_id = reverse_swaps
+                    .iter()
+                    .find(|rs| sha256::Hash::hash(&rs.preimage).to_hex() == data.payment_hash)
+                    .map(|rs| rs.id.clone());
+                row.payment_hash = Some(data.payment_hash);
+                row.ln_address = data.ln_address;
+                row.lnurl_withdraw_endpoint = data.lnurl_withdraw_endpoint;
+            }
+            PaymentDetails::ClosedChannel { data } => {
+                row.short_channel_id = Some(data.short_channel_id);
+                row.funding_txid = Some(data.funding_txid);
+                row.closing_txid = data.closing_txid;
+            }
+        }
+
+        row
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        let opt = |v: &Option<String>| v.clone().unwrap_or_default();
+        let opt_f64 = |v: Option<f64>| v.map(|v| v.to_string()).unwrap_or_default();
+        vec![
+            self.id.clone(),
+            self.payment_time.to_string(),
+            self.timestamp.clone(),
+            self.payment_type.clone(),
+            self.status.clone(),
+            self.amount_msat.to_string(),
+            self.fee_msat.to_string(),
+            opt(&self.description),
+            opt(&self.note),
+            self.tags.join(";"),
+            opt(&self.payment_hash),
+            opt(&self.ln_address),
+            opt(&self.lnurl_withdraw_endpoint),
+            opt(&self.swap_address),
+            opt(&self.reverse_swap_id),
+            opt(&self.short_channel_id),
+            opt(&self.funding_txid),
+            opt(&self.closing_txid),
+            opt(&self.fiat_currency),
+            opt_f64(self.fiat_rate),
+            self.fiat_rate_is_current.to_string(),
+            opt_f64(self.fiat_value),
+        ]
+    }
+}
+
+fn status_name(status: PaymentStatus) -> &'static str {
+    match status {
+        PaymentStatus::Pending => "Pending",
+        PaymentStatus::Complete => "Complete",
+        PaymentStatus::Failed => "Failed",
+    }
+}
+
+/// Quotes a CSV field in case it contains a separator, a quote or a line break.
+///
+/// Fields starting with a character that spreadsheets interpret as a formula are prefixed with
+/// a `'`, so that descriptions and notes set by others can't inject formulas.
+fn escape_csv_field(field: &str) -> String {
+    let field = match field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        true => format!("'{field}"),
+        false => field.to_string(),
+    };
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field,
+    }
+}
+
+/// Converts the given payments to rows in the requested format, resolving their swap,
+/// reverse swap and channel references and their value in the given fiat currency.
+///
+/// The fiat value uses the rate recorded with the payment, falling back to `current_rate`.
+pub(crate) fn export_payments(
+    payments: Vec<Payment>,
+    swaps: &[SwapInfo],
+    reverse_swaps: &[FullReverseSwapInfo],
+    fiat_currency: Option<&str>,
+    current_rate: Option<&Rate>,
+    format: PaymentExportFormat,
+) -> Result<String> {
+    let rows: Vec<PaymentExportRow> = payments
+        .into_iter()
+        .map(|p| PaymentExportRow::new(p, swaps, reverse_swaps, fiat_currency, current_rate))
+        .collect();
+
+    match format {
+        PaymentExportFormat::Json => Ok(serde_json::to_string_pretty(&rows)?),
+        PaymentExportFormat::Csv => {
+            let mut csv = CSV_HEADER.join(",");
+            csv.push('\n');
+            for row in rows {
+                let fields: Vec<String> = row
+                    .csv_fields()
+                    .iter()
+                    .map(|f| escape_csv_field(f))
+                    .collect();
+                csv.push_str(&fields.join(","));
+                csv.push('\n');
+            }
+            Ok(csv)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::export::{escape_csv_field, export_payments};
+    use crate::fiat::{PaymentFiatRate, Rate};
+    use crate::models::{
+        ClosedChannelPaymentDetails, LnPaymentDetails, Payment, PaymentDetails,
+        PaymentExportFormat, PaymentMetadata, PaymentStatus, PaymentType,
+    };
+    use crate::ChannelState;
+
+    fn test_payments() -> Vec<Payment> {
+        vec![
+            Payment {
+                id: "1234".to_string(),
+                payment_type: PaymentType::Received,
+                payment_time: 1700000000,
+                amount_msat: 2_000_000,
+                fee_msat: 0,
+                status: PaymentStatus::Complete,
+                description: Some("coffee, \"large\"".to_string()),
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails {
+                        payment_hash: "1234".to_string(),
+                        label: "".to_string(),
+                        destination_pubkey: "pubkey".to_string(),
+                        payment_preimage: "".to_string(),
+                        keysend: false,
+                        bolt11: "bolt11".to_string(),
+                        lnurl_success_action: None,
+                        lnurl_metadata: None,
+                        ln_address: Some("user@domain.com".to_string()),
+                        lnurl_withdraw_endpoint: None,
+                    },
+                },
+                metadata: Some(PaymentMetadata {
+                    note: Some("office".to_string()),
+                    tags: vec!["food".to_string(), "work".to_string()],
+                }),
+                fiat_rates: vec![PaymentFiatRate {
+                    coin: "USD".to_string(),
+                    value_micros: 40_000_000_000,
+                }],
+            },
+            Payment {
+                id: "closed".to_string(),
+                payment_type: PaymentType::ClosedChannel,
+                payment_time: 1700000100,
+                amount_msat: 1_000_000,
+                fee_msat: 0,
+                status: PaymentStatus::Complete,
+                description: Some("Closed Channel".to_string()),
+                details: PaymentDetails::ClosedChannel {
+                    data: ClosedChannelPaymentDetails {
+                        short_channel_id: "1x2x3".to_string(),
+                        state: ChannelState::Closed,
+                        funding_txid: "funding".to_string(),
+                        closing_txid: Some("closing".to_string()),
+                    },
+                },
+                metadata: None,
+                fiat_rates: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_export_payments_csv() -> Result<()> {
+        let rate = Rate {
+            coin: "USD".to_string(),
+            value: 50000.0,
+        };
+        // The first payment has a recorded rate, the second one uses the current rate
+        let csv = export_payments(
+            test_payments(),
+            &[],
+            &[],
+            Some("USD"),
+            Some(&rate),
+            PaymentExportFormat::Csv,
+        )?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("id,payment_time,timestamp,"));
+        assert_eq!(
+            lines[1],
+            "1234,1700000000,2023-11-14T22:13:20Z,Received,Complete,2000000,0,\
+             \"coffee, \"\"large\"\"\",office,food;work,1234,user@domain.com,,,,,,,USD,40000,false,0.8"
+        );
+        assert_eq!(
+            lines[2],
+            "closed,1700000100,2023-11-14T22:15:00Z,ClosedChannel,Complete,1000000,0,\
+             Closed Channel,,,,,,,,1x2x3,funding,closing,USD,50000,true,0.5"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_payments_json() -> Result<()> {
+        let json = export_payments(
+            test_payments(),
+            &[],
+            &[],
+            None,
+            None,
+            PaymentExportFormat::Json,
+        )?;
+        let rows: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(rows.as_array().unwrap().len(), 2);
+        assert_eq!(rows[0]["ln_address"], "user@domain.com");
+        assert_eq!(rows[0]["tags"], serde_json::json!(["food", "work"]));
+        assert_eq!(rows[1]["closing_txid"], "closing");
+        assert!(rows[1]["fiat_value"].is_null());
+        assert_eq!(rows[1]["fiat_rate_is_current"], false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("coffee"), "coffee");
+        assert_eq!(escape_csv_field("=1+2"), "'=1+2");
+        assert_eq!(escape_csv_field("@SUM(A1:A2)"), "'@SUM(A1:A2)");
+        assert_eq!(escape_csv_field("-1,+2"), "\"'-1,+2\"");
+        assert_eq!(escape_csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+    }
+}
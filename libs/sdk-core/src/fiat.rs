@@ -1,12 +1,28 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::breez_services::BreezServer;
 use crate::grpc::RatesRequest;
+use crate::input_parser::get_parse_and_log_response;
 use crate::models::FiatAPI;
-use anyhow::Result;
+use crate::persist::storage::Storage;
+use anyhow::{ensure, Result};
+use futures::future::join_all;
 use serde::{Deserialize, Serialize};
 use tonic::Request;
 
+/// How long fetched fiat rates are served from the cache before they are refreshed
+const FIAT_RATES_TTL_SECS: u64 = 300;
+
+/// The maximum age of the rates a fiat amount is converted with, see
+/// [crate::BreezServices::send_fiat_payment]
+pub(crate) const MAX_QUOTE_RATES_AGE_SECS: u64 = FIAT_RATES_TTL_SECS;
+
+/// The maximum relative deviation from the median rate of a currency, above which the rate of a
+/// provider is rejected as an outlier
+const MAX_RATE_DEVIATION: f64 = 0.1;
+
 /// Settings for the symbol representation of a currency
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Symbol {
@@ -58,28 +74,247 @@ pub struct Rate {
     pub value: f64,
 }
 
+/// The rate of a fiat currency at the time a [crate::Payment] was made
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentFiatRate {
+    pub coin: String,
+    /// The price of one bitcoin in the currency, in millionths of the currency unit. Unlike
+    /// [Rate::value], it keeps [crate::Payment] comparable with `Eq`.
+    pub value_micros: u64,
+}
+
+impl PaymentFiatRate {
+    /// The price of one bitcoin in the currency
+    pub(crate) fn value(&self) -> f64 {
+        self.value_micros as f64 / 1_000_000.0
+    }
+}
+
+/// Adds the `rates` of currencies not yet in `recorded`. The recorded rates of the other
+/// currencies are updated only if `replace` is set.
+pub(crate) fn merge_payment_fiat_rates(
+    recorded: &mut Vec<PaymentFiatRate>,
+    rates: &[PaymentFiatRate],
+    replace: bool,
+) {
+    for rate in rates {
+        match recorded.iter_mut().find(|r| r.coin == rate.coin) {
+            Some(recorded_rate) if replace => *recorded_rate = rate.clone(),
+            Some(_) => {}
+            None => recorded.push(rate.clone()),
+        }
+    }
+}
+
+impl From<&Rate> for PaymentFiatRate {
+    fn from(rate: &Rate) -> Self {
+        PaymentFiatRate {
+            coin: rate.coin.clone(),
+            value_micros: (rate.value * 1_000_000.0).round() as u64,
+        }
+    }
+}
+
+/// Fiat rates along with the time they were fetched
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FiatRates {
+    pub rates: Vec<Rate>,
+    /// The time the rates were fetched, in seconds since epoch
+    pub fetched_at: u64,
+    /// Whether the rates could not be refreshed, in which case the last known rates are returned
+    #[serde(default)]
+    pub is_stale: bool,
+}
+
+impl FiatRates {
+    /// Whether the rates were refreshed less than [MAX_QUOTE_RATES_AGE_SECS] ago, so amounts can
+    /// be priced with them
+    pub(crate) fn is_fresh(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        !self.is_stale && now.saturating_sub(self.fetched_at) <= MAX_QUOTE_RATES_AGE_SECS
+    }
+}
+
+/// Converts an amount in a fiat currency to millisatoshis, given the price of one bitcoin in
+/// that currency
+pub(crate) fn fiat_to_msat(fiat_amount: f64, rate: f64) -> u64 {
+    (fiat_amount / rate * 100_000_000_000.0).round() as u64
+}
+
 fn convert_to_fiat_currency_with_id(id: String, info: CurrencyInfo) -> FiatCurrency {
     FiatCurrency { id, info }
 }
 
+/// The details of all the known currencies, by currency id
+pub(crate) fn known_currencies() -> Result<HashMap<String, CurrencyInfo>> {
+    let data = include_str!("../assets/json/currencies.json");
+    Ok(serde_json::from_str(data)?)
+}
+
+/// Lists the known currencies for which there is a rate in `known_rates`
+fn list_fiat_currencies_with_rates(known_rates: &[Rate]) -> Result<Vec<FiatCurrency>> {
+    let known_rates_currencies = known_rates
+        .iter()
+        .map(|r| r.coin.clone())
+        .collect::<Vec<String>>();
+
+    let fiat_currency_map = known_currencies()?;
+    let mut fiat_currency_list: Vec<FiatCurrency> = Vec::new();
+    for (key, value) in fiat_currency_map {
+        if known_rates_currencies.contains(&key) {
+            fiat_currency_list.push(convert_to_fiat_currency_with_id(key, value));
+        }
+    }
+    Ok(fiat_currency_list)
+}
+
+/// Returns the median of the given values, which must not be empty
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    match values.len() % 2 {
+        0 => (values[mid - 1] + values[mid]) / 2.0,
+        _ => values[mid],
+    }
+}
+
+/// Aggregates the rates fetched from several providers into one rate per currency.
+///
+/// For each currency, the rates deviating from the median by more than [MAX_RATE_DEVIATION] are
+/// rejected as outliers and the median of the remaining ones is used.
+pub(crate) fn aggregate_rates(provider_rates: Vec<Vec<Rate>>) -> Vec<Rate> {
+    let mut values_by_coin: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+    for rate in provider_rates.into_iter().flatten() {
+        if rate.value.is_finite() && rate.value > 0.0 {
+            values_by_coin
+                .entry(rate.coin)
+                .or_default()
+                .push(rate.value);
+        }
+    }
+
+    values_by_coin
+        .into_iter()
+        .map(|(coin, values)| {
+            let median_value = median(values.clone());
+            let inliers: Vec<f64> = values
+                .into_iter()
+                .filter(|v| ((v - median_value) / median_value).abs() <= MAX_RATE_DEVIATION)
+                .collect();
+            let value = match inliers.is_empty() {
+                true => median_value,
+                false => median(inliers),
+            };
+            Rate { coin, value }
+        })
+        .collect()
+}
+
+/// Fetches the rates from all the configured [FiatAPI] providers, aggregates them and caches the
+/// result, falling back to the last known rates when none of the providers can be reached.
+pub(crate) struct FiatService {
+    providers: Vec<Arc<dyn FiatAPI>>,
+    persister: Arc<dyn Storage>,
+}
+
+impl FiatService {
+    pub(crate) fn new(providers: Vec<Arc<dyn FiatAPI>>, persister: Arc<dyn Storage>) -> Self {
+        FiatService {
+            providers,
+            persister,
+        }
+    }
+
+    /// Returns the cached rates if they are fresh, otherwise fetches and caches new ones.
+    pub(crate) async fn fiat_rates(&self) -> Result<FiatRates> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let cached = self.persister.get_fiat_rates()?;
+        if let Some(cached) = &cached {
+            if now.saturating_sub(cached.fetched_at) < FIAT_RATES_TTL_SECS {
+                return Ok(cached.clone());
+            }
+        }
+
+        match self.fetch_aggregated_rates().await {
+            Ok(rates) => {
+                let fiat_rates = FiatRates {
+                    rates,
+                    fetched_at: now,
+                    is_stale: false,
+                };
+                self.persister.set_fiat_rates(&fiat_rates)?;
+                Ok(fiat_rates)
+            }
+            Err(e) => match cached {
+                Some(cached) => {
+                    warn!("Failed to refresh the fiat rates, using the cached ones: {e}");
+                    Ok(FiatRates {
+                        is_stale: true,
+                        ..cached
+                    })
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    async fn fetch_aggregated_rates(&self) -> Result<Vec<Rate>> {
+        let results = join_all(self.providers.iter().map(|p| p.fetch_fiat_rates())).await;
+        let mut provider_rates = Vec::new();
+        for res in results {
+            match res {
+                Ok(rates) => provider_rates.push(rates),
+                Err(e) => warn!("Failed to fetch fiat rates from a provider: {e}"),
+            }
+        }
+        ensure!(
+            !provider_rates.is_empty(),
+            "Failed to fetch fiat rates from any provider"
+        );
+        Ok(aggregate_rates(provider_rates))
+    }
+}
+
+#[tonic::async_trait]
+impl FiatAPI for FiatService {
+    async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
+        list_fiat_currencies_with_rates(&self.fiat_rates().await?.rates)
+    }
+
+    async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
+        Ok(self.fiat_rates().await?.rates)
+    }
+}
+
+/// A fiat rate feed served over HTTP, which responds to a GET request with a JSON list of [Rate]
+pub struct HttpFiatAPI {
+    url: String,
+}
+
+impl HttpFiatAPI {
+    pub fn new(url: String) -> Self {
+        HttpFiatAPI { url }
+    }
+}
+
+#[tonic::async_trait]
+impl FiatAPI for HttpFiatAPI {
+    async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
+        list_fiat_currencies_with_rates(&self.fetch_fiat_rates().await?)
+    }
+
+    async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
+        get_parse_and_log_response(&self.url).await
+    }
+}
+
 #[tonic::async_trait]
 impl FiatAPI for BreezServer {
     async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
-        let known_rates = self.fetch_fiat_rates().await?;
-        let known_rates_currencies = known_rates
-            .iter()
-            .map(|r| r.coin.clone())
-            .collect::<Vec<String>>();
-
-        let data = include_str!("../assets/json/currencies.json");
-        let fiat_currency_map: HashMap<String, CurrencyInfo> = serde_json::from_str(data)?;
-        let mut fiat_currency_list: Vec<FiatCurrency> = Vec::new();
-        for (key, value) in fiat_currency_map {
-            if known_rates_currencies.contains(&key) {
-                fiat_currency_list.push(convert_to_fiat_currency_with_id(key, value));
-            }
-        }
-        Ok(fiat_currency_list)
+        list_fiat_currencies_with_rates(&self.fetch_fiat_rates().await?)
     }
 
     async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
@@ -98,3 +333,84 @@ impl FiatAPI for BreezServer {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::{anyhow, Result};
+
+    use crate::fiat::{aggregate_rates, FiatCurrency, FiatRates, FiatService, Rate};
+    use crate::models::FiatAPI;
+    use crate::test_utils::{create_test_config, create_test_persister, MockBreezServer};
+
+    struct UnreachableFiatAPI {}
+
+    #[tonic::async_trait]
+    impl FiatAPI for UnreachableFiatAPI {
+        async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
+            Err(anyhow!("Unreachable"))
+        }
+
+        async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
+            Err(anyhow!("Unreachable"))
+        }
+    }
+
+    fn rates(values: &[(&str, f64)]) -> Vec<Rate> {
+        values
+            .iter()
+            .map(|(coin, value)| Rate {
+                coin: coin.to_string(),
+                value: *value,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregate_rates() {
+        let aggregated = aggregate_rates(vec![
+            rates(&[("USD", 20_000.0), ("EUR", 18_000.0)]),
+            rates(&[("USD", 20_200.0), ("EUR", 18_400.0)]),
+            // The USD rate of this provider is an outlier and is ignored
+            rates(&[("USD", 30_000.0), ("EUR", 18_200.0)]),
+        ]);
+        assert_eq!(aggregated, rates(&[("EUR", 18_200.0), ("USD", 20_100.0)]));
+    }
+
+    #[tokio::test]
+    async fn test_fiat_rates_cache_and_fallback() -> Result<()> {
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+
+        let fiat_service =
+            FiatService::new(vec![Arc::new(UnreachableFiatAPI {})], persister.clone());
+        assert!(fiat_service.fiat_rates().await.is_err());
+
+        let fiat_service = FiatService::new(
+            vec![
+                Arc::new(MockBreezServer {}),
+                Arc::new(UnreachableFiatAPI {}),
+            ],
+            persister.clone(),
+        );
+        let fetched = fiat_service.fiat_rates().await?;
+        assert_eq!(fetched.rates, rates(&[("USD", 20_000.0)]));
+        assert!(!fetched.is_stale);
+        assert_eq!(persister.get_fiat_rates()?, Some(fetched.clone()));
+
+        // Once expired, the cached rates are returned as stale if they can't be refreshed
+        let expired = FiatRates {
+            fetched_at: fetched.fetched_at - 3600,
+            ..fetched
+        };
+        persister.set_fiat_rates(&expired)?;
+        let fiat_service = FiatService::new(vec![Arc::new(UnreachableFiatAPI {})], persister);
+        let stale = fiat_service.fiat_rates().await?;
+        assert!(stale.is_stale);
+        assert_eq!(stale.fetched_at, expired.fetched_at);
+        assert_eq!(stale.rates, expired.rates);
+
+        Ok(())
+    }
+}
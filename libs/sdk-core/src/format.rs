@@ -0,0 +1,222 @@
+use anyhow::{anyhow, ensure, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::fiat::{known_currencies, CurrencyInfo, Symbol};
+
+/// The unit in which a bitcoin amount is formatted, see [format_sats]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BitcoinUnit {
+    Btc,
+    Sat,
+    Msat,
+}
+
+/// The separators used by a locale to format numbers
+struct NumberFormat {
+    group_separator: &'static str,
+    decimal_separator: &'static str,
+}
+
+impl NumberFormat {
+    /// Picks the separators for the given locale, like `en`, `de-AT` or `fr_CA`
+    fn for_locale(locale: &str) -> Self {
+        let (language, region) = split_locale(locale);
+        let (group_separator, decimal_separator) = match region.as_deref() {
+            Some("CH" | "LI") => ("’", "."),
+            _ => match language.as_str() {
+                "de" | "es" | "it" | "nl" | "pt" | "tr" | "id" | "da" | "el" | "ro" | "hr"
+                | "sl" | "sr" | "vi" => (".", ","),
+                "fr" | "ru" | "sv" | "fi" | "nb" | "no" | "cs" | "pl" | "uk" | "sk" | "bg"
+                | "hu" | "et" | "lt" | "lv" => ("\u{a0}", ","),
+                _ => (",", "."),
+            },
+        };
+        NumberFormat {
+            group_separator,
+            decimal_separator,
+        }
+    }
+
+    /// Formats a non-negative integer part and optional fraction digits
+    fn format(&self, integer: &str, fraction: &str) -> String {
+        let mut res = String::new();
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && i % 3 == integer.len() % 3 {
+                res.push_str(self.group_separator);
+            }
+            res.push(c);
+        }
+        if !fraction.is_empty() {
+            res.push_str(self.decimal_separator);
+            res.push_str(fraction);
+        }
+        res
+    }
+}
+
+/// Splits a locale into its lowercase language and uppercase region, if any
+fn split_locale(locale: &str) -> (String, Option<String>) {
+    let mut parts = locale.split(['-', '_']);
+    let language = parts.next().unwrap_or_default().to_lowercase();
+    let region = parts.next().map(|r| r.to_uppercase());
+    (language, region)
+}
+
+/// Applies the overrides of the given locale to the symbol and spacing of a currency.
+///
+/// An override for the full locale takes precedence over one for its language only.
+fn localized_symbol(info: &CurrencyInfo, locale: &str) -> (Option<Symbol>, Option<u32>) {
+    let mut symbol = info.symbol.clone();
+    let mut spacing = info.spacing;
+
+    let (language, region) = split_locale(locale);
+    let overrides = info.locale_overrides.clone().unwrap_or_default();
+    let matching = overrides
+        .iter()
+        .find(|o| {
+            let (o_language, o_region) = split_locale(&o.locale);
+            o_language == language && o_region.is_some() && o_region == region
+        })
+        .or_else(|| {
+            overrides
+                .iter()
+                .find(|o| split_locale(&o.locale) == (language.clone(), None))
+        });
+
+    if let Some(o) = matching {
+        spacing = o.spacing.or(spacing);
+        if let Some(s) = symbol.as_mut() {
+            s.grapheme = o.symbol.grapheme.clone().or(s.grapheme.take());
+            s.rtl = o.symbol.rtl.or(s.rtl);
+            // An overridden position replaces the template of the currency
+            match (&o.symbol.template, o.symbol.position) {
+                (Some(template), _) => s.template = Some(template.clone()),
+                (None, Some(position)) => {
+                    s.template = None;
+                    s.position = Some(position);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+    (symbol, spacing)
+}
+
+/// Formats an amount in a fiat currency for the given locale, e.g. `$1,234.50` for `en` or
+/// `1.234,50 €` for `de`.
+///
+/// The amount is rounded to the fraction size of the currency and its symbol is placed according
+/// to the currency template or symbol position, spacing and the overrides of the locale, as found
+/// in the currency details of [crate::BreezServices::list_fiat_currencies]. Currencies without a
+/// symbol are formatted with their id, e.g. `CHF 10.00`.
+pub fn format_fiat(amount: f64, currency_id: &str, locale: &str) -> Result<String> {
+    ensure!(amount.is_finite(), "Invalid fiat amount {amount}");
+    let info = known_currencies()?
+        .remove(currency_id)
+        .ok_or(anyhow!("Unknown currency {currency_id}"))?;
+
+    let rounded = format!("{:.*}", info.fraction_size as usize, amount.abs());
+    let (integer, fraction) = rounded.split_once('.').unwrap_or((rounded.as_str(), ""));
+    let number = NumberFormat::for_locale(locale).format(integer, fraction);
+    let is_negative = amount < 0.0 && rounded.chars().any(|c| c.is_ascii_digit() && c != '0');
+
+    let (symbol, spacing) = localized_symbol(&info, locale);
+    let grapheme = symbol
+        .as_ref()
+        .and_then(|s| s.grapheme.clone())
+        .unwrap_or(currency_id.to_string());
+    let formatted = match symbol.as_ref().and_then(|s| s.template.clone()) {
+        // In templates, `1` stands for the amount and `$` for the symbol
+        Some(template) => template
+            .chars()
+            .map(|c| match c {
+                '1' => number.clone(),
+                '$' => grapheme.clone(),
+                c => c.to_string(),
+            })
+            .collect(),
+        None => {
+            let space = " ".repeat(spacing.unwrap_or(1) as usize);
+            match symbol.and_then(|s| s.position) {
+                Some(1) => format!("{number}{space}{grapheme}"),
+                _ => format!("{grapheme}{space}{number}"),
+            }
+        }
+    };
+
+    Ok(match is_negative {
+        true => format!("-{formatted}"),
+        false => formatted,
+    })
+}
+
+/// Formats an amount of bitcoin in the given unit for the given locale, e.g. `1,234 sat` or
+/// `0.00001234 BTC` for `en`. Amounts in sat are rounded down to the whole sat.
+pub fn format_sats(amount_msat: u64, unit: BitcoinUnit, locale: &str) -> String {
+    let number_format = NumberFormat::for_locale(locale);
+    match unit {
+        BitcoinUnit::Btc => {
+            let sats = amount_msat / 1000;
+            let integer = (sats / 100_000_000).to_string();
+            let fraction = format!("{:08}", sats % 100_000_000);
+            format!("{} BTC", number_format.format(&integer, &fraction))
+        }
+        BitcoinUnit::Sat => {
+            let sats = (amount_msat / 1000).to_string();
+            format!("{} sat", number_format.format(&sats, ""))
+        }
+        BitcoinUnit::Msat => {
+            format!(
+                "{} msat",
+                number_format.format(&amount_msat.to_string(), "")
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::format::{format_fiat, format_sats, BitcoinUnit};
+
+    #[test]
+    fn test_format_fiat() -> Result<()> {
+        assert_eq!(format_fiat(1234.5, "USD", "en")?, "$1,234.50");
+        assert_eq!(format_fiat(-0.004, "USD", "en")?, "$0.00");
+        assert_eq!(format_fiat(-5.0, "USD", "en")?, "-$5.00");
+        assert_eq!(format_fiat(1234.4, "JPY", "en")?, "¥1,234");
+        assert_eq!(format_fiat(1234.5, "SEK", "sv")?, "1\u{a0}234,50 kr");
+        assert_eq!(format_fiat(10.0, "CHF", "de-CH")?, "CHF 10.00");
+
+        // Euro defaults to a trailing symbol, with overrides for some locales
+        assert_eq!(format_fiat(1234.5, "EUR", "de")?, "1.234,50 €");
+        assert_eq!(format_fiat(1234.5, "EUR", "de-AT")?, "€ 1.234,50");
+        assert_eq!(format_fiat(1234.5, "EUR", "en-IE")?, "€1,234.50");
+
+        assert!(format_fiat(1.0, "XYZ", "en").is_err());
+        assert!(format_fiat(f64::NAN, "USD", "en").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_sats() {
+        assert_eq!(
+            format_sats(1_234_567_890, BitcoinUnit::Sat, "en"),
+            "1,234,567 sat"
+        );
+        assert_eq!(format_sats(999, BitcoinUnit::Sat, "en"), "0 sat");
+        assert_eq!(
+            format_sats(1_234_567_890, BitcoinUnit::Msat, "de"),
+            "1.234.567.890 msat"
+        );
+        assert_eq!(
+            format_sats(123_456_789_000_000, BitcoinUnit::Btc, "en"),
+            "1,234.56789000 BTC"
+        );
+        assert_eq!(
+            format_sats(1_234_000, BitcoinUnit::Btc, "fr"),
+            "0,00001234 BTC"
+        );
+    }
+}
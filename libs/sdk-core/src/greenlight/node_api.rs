@@ -41,7 +41,7 @@ use tonic::Streaming;
 
 use crate::invoice::parse_invoice;
 use crate::models::*;
-use crate::persist::db::SqliteStorage;
+use crate::persist::storage::Storage;
 use crate::{Channel, ChannelState, NodeConfig, PrepareSweepRequest, PrepareSweepResponse};
 use std::iter::Iterator;
 
@@ -54,7 +54,7 @@ pub(crate) struct Greenlight {
     tls_config: TlsConfig,
     gl_client: Mutex<Option<node::Client>>,
     node_client: Mutex<Option<ClnClient>>,
-    persister: Arc<SqliteStorage>,
+    persister: Arc<dyn Storage>,
 }
 
 impl Greenlight {
@@ -67,7 +67,7 @@ impl Greenlight {
     pub async fn connect(
         config: Config,
         seed: Vec<u8>,
-        persister: Arc<SqliteStorage>,
+        persister: Arc<dyn Storage>,
     ) -> Result<Self> {
         // Derive the encryption key from the seed
         let signer = Signer::new(seed.clone(), config.network.into(), TlsConfig::new()?)?;
@@ -164,7 +164,7 @@ impl Greenlight {
         sdk_config: Config,
         seed: Vec<u8>,
         connection_credentials: GreenlightCredentials,
-        persister: Arc<SqliteStorage>,
+        persister: Arc<dyn Storage>,
     ) -> Result<Greenlight> {
         let greenlight_network = sdk_config.network.into();
         let tls_config = TlsConfig::new()?.identity(
@@ -273,7 +273,7 @@ impl Greenlight {
 
     async fn fetch_channels_and_balance_with_retry(
         cln_client: node::ClnClient,
-        persister: Arc<SqliteStorage>,
+        persister: Arc<dyn Storage>,
         balance_changed: bool,
     ) -> Result<(
         Vec<cln::ListpeersPeersChannels>,
@@ -747,6 +747,32 @@ impl NodeAPI for Greenlight {
         Ok(signed_invoice?.to_string())
     }
 
+    async fn fetch_channel_policy(&self, node_id: String) -> Result<Option<NodeChannelPolicy>> {
+        let mut client = self.get_node_client().await?;
+        let channels = client
+            .list_channels(cln::ListchannelsRequest {
+                source: Some(hex::decode(node_id)?),
+                ..Default::default()
+            })
+            .await?
+            .into_inner()
+            .channels;
+        Ok(channels
+            .into_iter()
+            .map(|c| NodeChannelPolicy {
+                base_fee_msat: c.base_fee_millisatoshi as u64,
+                fee_rate_ppm: c.fee_per_millionth,
+                cltv_expiry_delta: c.delay,
+                htlc_minimum_msat: c.htlc_minimum_msat.map(|a| a.msat).unwrap_or_default(),
+            })
+            .reduce(|a, b| NodeChannelPolicy {
+                base_fee_msat: a.base_fee_msat.max(b.base_fee_msat),
+                fee_rate_ppm: a.fee_rate_ppm.max(b.fee_rate_ppm),
+                cltv_expiry_delta: a.cltv_expiry_delta.max(b.cltv_expiry_delta),
+                htlc_minimum_msat: a.htlc_minimum_msat.max(b.htlc_minimum_msat),
+            }))
+    }
+
     async fn close_peer_channels(&self, node_id: String) -> Result<Vec<String>> {
         let mut client = self.get_node_client().await?;
         let closed_channels = client
@@ -1066,6 +1092,8 @@ impl TryFrom<OffChainPayment> for Payment {
                     lnurl_withdraw_endpoint: None,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         })
     }
     // fn from(p: OffChainPayment) -> Self {
@@ -1101,6 +1129,8 @@ impl TryFrom<pb::Invoice> for Payment {
                     lnurl_withdraw_endpoint: None,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         })
     }
 }
@@ -1151,6 +1181,8 @@ impl TryFrom<pb::Payment> for Payment {
                     lnurl_withdraw_endpoint: None,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         })
     }
 }
@@ -1190,6 +1222,8 @@ impl TryFrom<ListinvoicesInvoices> for Payment {
                     lnurl_withdraw_endpoint: None,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         })
     }
 }
@@ -1252,6 +1286,8 @@ impl TryFrom<ListpaysPays> for Payment {
                     lnurl_withdraw_endpoint: None,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         })
     }
 }
@@ -166,7 +166,9 @@ mod breez_services;
 mod chain;
 mod crypt;
 pub mod error;
+mod export;
 mod fiat;
+mod format;
 mod greenlight;
 // GRPC structs are documented as follows:
 // - if they are mirrored in Rust model structs, documented in the model structs
@@ -177,22 +179,31 @@ mod invoice;
 mod lnurl;
 mod lsp;
 mod lsps0;
+mod lsps1;
 mod lsps2;
+mod lsps5;
 mod models;
 mod moonpay;
+mod notifications;
 mod persist;
 mod reverseswap;
 mod swap;
 #[cfg(test)]
 mod test_utils;
 
+pub use backup::{BackupState, BackupTransport};
 pub use breez_services::{
-    mnemonic_to_seed, BackupFailedData, BreezEvent, BreezServices, CheckMessageRequest,
-    CheckMessageResponse, EventListener, InvoicePaidDetails, LogStream, PaymentFailedData,
-    SignMessageRequest, SignMessageResponse,
+    mnemonic_to_seed, BackupFailedData, BreezEvent, BreezServices, BreezServicesBuilder,
+    CheckMessageRequest, CheckMessageResponse, EventListener, InvoicePaidDetails, LogStream,
+    NodeBalance, PaymentFailedData, PersistedEvent, PersistedEventListener, SignMessageRequest,
+    SignMessageResponse,
 };
 pub use chain::RecommendedFees;
-pub use fiat::{CurrencyInfo, FiatCurrency, LocaleOverrides, LocalizedName, Rate, Symbol};
+pub use fiat::{
+    CurrencyInfo, FiatCurrency, FiatRates, HttpFiatAPI, LocaleOverrides, LocalizedName,
+    PaymentFiatRate, Rate, Symbol,
+};
+pub use format::{format_fiat, format_sats, BitcoinUnit};
 pub use input_parser::{
     parse, BitcoinAddressData, InputType, LnUrlAuthRequestData, LnUrlErrorData,
     LnUrlPayRequestData, LnUrlRequestData, LnUrlWithdrawRequestData, MetadataItem,
@@ -202,4 +213,8 @@ pub use invoice::{parse_invoice, LNInvoice, RouteHint, RouteHintHop};
 pub use lnurl::pay::model::*;
 pub use lsp::LspInformation;
 pub use models::*;
+pub use persist::{
+    db::HookEvent, maintenance::DbIntegrity, memory::MemoryStorage, storage::Storage,
+    sync::SyncVersion,
+};
 pub use reverseswap::{ESTIMATED_CLAIM_TX_VSIZE, ESTIMATED_LOCKUP_TX_VSIZE};
@@ -1,15 +1,29 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
 use crate::breez_services::BreezServer;
 use crate::crypt::encrypt;
 use crate::error::{SdkError, SdkResult};
 use crate::grpc::{
-    self, LspListRequest, PaymentInformation, RegisterPaymentReply, RegisterPaymentRequest,
+    self, LspListRequest, NotificationSubscription, PaymentInformation, RegisterPaymentReply,
+    RegisterPaymentRequest, SubscribeNotificationsReply, SubscribeNotificationsRequest,
+};
+use crate::models::{
+    Channel, ChannelState, LspAPI, NodeAPI, OpeningFeeParams, OpeningFeeParamsMenu,
 };
-use crate::models::{LspAPI, OpeningFeeParams, OpeningFeeParamsMenu};
+use crate::persist::storage::Storage;
 use anyhow::Result;
 use prost::Message;
 use serde::{Deserialize, Serialize};
 use tonic::Request;
 
+/// How long an LSP that failed to connect is ranked after the reachable ones
+const UNREACHABLE_LSP_BACKOFF: Duration = Duration::from_secs(10 * 60);
+/// The amount whose channel opening fee is compared when ranking the LSPs by cost
+const RANKING_FEE_REFERENCE_MSAT: u64 = 100_000_000;
+
 /// Details of supported LSP
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LspInformation {
@@ -77,23 +91,8 @@ impl LspInformation {
     /// If the LSP fees are needed, the LSP is expected to have at least one dynamic fee entry in its menu,
     /// otherwise this will result in an error.
     pub(crate) fn cheapest_open_channel_fee(&self, expiry: u32) -> SdkResult<&OpeningFeeParams> {
-        for fee in &self.opening_fee_params_list.values {
-            match fee.valid_for(expiry) {
-                Ok(valid) => {
-                    if valid {
-                        return Ok(fee);
-                    }
-                }
-                Err(e) => {
-                    return Err(SdkError::CalculateOpenChannelFeesFailed { err: e.to_string() })
-                }
-            }
-        }
-        self.opening_fee_params_list.values.last().ok_or_else(|| {
-            SdkError::LspOpenChannelNotSupported {
-                err: "Dynamic fees menu contains no values".to_string(),
-            }
-        })
+        self.opening_fee_params_list
+            .cheapest_open_channel_fee(expiry)
     }
 }
 
@@ -135,15 +134,245 @@ impl LspAPI for BreezServer {
 
         Ok(response.into_inner())
     }
+
+    async fn subscribe_notifications(
+        &self,
+        lsp_id: String,
+        lsp_pubkey: Vec<u8>,
+        webhook_url: String,
+        signature: String,
+    ) -> Result<SubscribeNotificationsReply> {
+        let mut client = self.get_channel_opener_client().await?;
+
+        let subscription = NotificationSubscription {
+            url: webhook_url,
+            signature,
+        };
+        let mut buf = Vec::new();
+        buf.reserve(subscription.encoded_len());
+        subscription.encode(&mut buf)?;
+
+        let request = Request::new(SubscribeNotificationsRequest {
+            lsp_id,
+            blob: encrypt(lsp_pubkey, buf)?,
+        });
+        let response = client.subscribe_notifications(request).await?;
+
+        Ok(response.into_inner())
+    }
+}
+
+/// Selects the LSP to use among the available ones.
+///
+/// The LSPs are ranked by:
+/// - reachability: the ones that failed to connect recently come last
+/// - cost: the opening fee of a channel for a reference amount, with the cheapest opening fee
+///   params of the LSP
+/// - capacity: the inbound liquidity of the open channels with the LSP, as receiving through it
+///   doesn't need a new channel, then the channel capacity the LSP offers
+pub(crate) struct LspManager {
+    node_api: Arc<dyn NodeAPI>,
+    lsp_api: Arc<dyn LspAPI>,
+    persister: Arc<dyn Storage>,
+    /// When the LSPs, by ID, last failed to connect
+    unreachable: Mutex<HashMap<String, Instant>>,
+}
+
+impl LspManager {
+    pub(crate) fn new(
+        node_api: Arc<dyn NodeAPI>,
+        lsp_api: Arc<dyn LspAPI>,
+        persister: Arc<dyn Storage>,
+    ) -> Self {
+        LspManager {
+            node_api,
+            lsp_api,
+            persister,
+            unreachable: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Lists the LSPs of the LSP API and the ones connected by their node URI
+    pub(crate) async fn list_lsps(&self) -> SdkResult<Vec<LspInformation>> {
+        let node_pubkey = self
+            .persister
+            .get_node_state()?
+            .ok_or(SdkError::PersistenceFailure {
+                err: "No node info found".into(),
+            })?
+            .id;
+        let mut lsps = self
+            .lsp_api
+            .list_lsps(node_pubkey)
+            .await
+            .map_err(|e| SdkError::LspConnectFailed { err: e.to_string() })?;
+        lsps.extend(self.persister.get_custom_lsps()?);
+        Ok(lsps)
+    }
+
+    /// Sorts the LSPs from the best ranked to the worst
+    pub(crate) fn rank(&self, mut lsps: Vec<LspInformation>) -> SdkResult<Vec<LspInformation>> {
+        let channel_lsps = self.persister.list_channel_lsps()?;
+        let mut inbound_msat: HashMap<String, u64> = HashMap::new();
+        for channel in self.persister.list_channels()? {
+            if let (ChannelState::Opened, Some(lsp_id)) =
+                (&channel.state, channel_lsps.get(&channel.funding_txid))
+            {
+                *inbound_msat.entry(lsp_id.clone()).or_default() += channel.receivable_msat;
+            }
+        }
+
+        let now = Instant::now();
+        let unreachable = self.unreachable.lock().unwrap();
+        lsps.sort_by_cached_key(|lsp| {
+            let is_unreachable = unreachable
+                .get(&lsp.id)
+                .map(|failed_at| now.duration_since(*failed_at) < UNREACHABLE_LSP_BACKOFF)
+                .unwrap_or(false);
+            let cost = lsp
+                .opening_fee_params_list
+                .get_cheapest_opening_fee_params()
+                .map(|params| params.get_channel_fees_msat_for(RANKING_FEE_REFERENCE_MSAT))
+                .unwrap_or(u64::MAX);
+            (
+                is_unreachable,
+                cost,
+                Reverse(inbound_msat.get(&lsp.id).copied().unwrap_or_default()),
+                Reverse(lsp.channel_capacity),
+            )
+        });
+        Ok(lsps)
+    }
+
+    /// Connects to the selected LSP, if any, and returns the LSP connected to.
+    ///
+    /// With `auto_select`, the best ranked LSP is selected if none is, and if the selected LSP is
+    /// unreachable, the first reachable LSP in the ranking is used instead. That LSP only replaces
+    /// the selected one with `persist_failover`, otherwise it is only returned for the current
+    /// call.
+    pub(crate) async fn connect(
+        &self,
+        auto_select: bool,
+        persist_failover: bool,
+    ) -> SdkResult<Option<LspInformation>> {
+        let node_state = match self.persister.get_node_state()? {
+            Some(node_state) => node_state,
+            None => return Ok(None),
+        };
+        let lsps = match self.list_lsps().await {
+            Ok(lsps) => lsps,
+            Err(e) => {
+                warn!("Failed to list the LSPs: {e}");
+                return Ok(None);
+            }
+        };
+        let selected = self
+            .persister
+            .get_lsp_id()?
+            .and_then(|lsp_id| lsps.iter().find(|lsp| lsp.id == lsp_id).cloned());
+
+        let mut connect_err = None;
+        if let Some(lsp) = &selected {
+            match self.connect_peer(lsp, &node_state.connected_peers).await {
+                Ok(()) => return Ok(selected),
+                Err(e) if !auto_select => return Err(e),
+                Err(e) => {
+                    warn!("Failed to connect to the selected LSP {}: {e}", lsp.id);
+                    connect_err = Some(e);
+                }
+            }
+        }
+        if !auto_select {
+            return Ok(None);
+        }
+
+        let candidates = self
+            .rank(lsps)?
+            .into_iter()
+            .filter(|lsp| selected.as_ref().is_none_or(|s| s.id != lsp.id));
+        for lsp in candidates {
+            match self.connect_peer(&lsp, &node_state.connected_peers).await {
+                Ok(()) if selected.is_none() || persist_failover => {
+                    info!("Selecting the LSP {} ({})", lsp.id, lsp.name);
+                    self.persister.set_lsp_id(lsp.id.clone())?;
+                    return Ok(Some(lsp));
+                }
+                Ok(()) => {
+                    info!("Failing over to the LSP {} ({})", lsp.id, lsp.name);
+                    return Ok(Some(lsp));
+                }
+                Err(e) => warn!("Failed to connect to the LSP {}: {e}", lsp.id),
+            }
+        }
+        connect_err.map_or(Ok(None), Err)
+    }
+
+    async fn connect_peer(
+        &self,
+        lsp: &LspInformation,
+        connected_peers: &[String],
+    ) -> SdkResult<()> {
+        if !connected_peers.contains(&lsp.pubkey) {
+            debug!("connecting to lsp {}@{}", lsp.pubkey, lsp.host);
+            if let Err(e) = self
+                .node_api
+                .connect_peer(lsp.pubkey.clone(), lsp.host.clone())
+                .await
+            {
+                self.unreachable
+                    .lock()
+                    .unwrap()
+                    .insert(lsp.id.clone(), Instant::now());
+                return Err(SdkError::LspConnectFailed { err: e.to_string() });
+            }
+        }
+        self.unreachable.lock().unwrap().remove(&lsp.id);
+        debug!("connected to lsp {}@{}", lsp.pubkey, lsp.host);
+        Ok(())
+    }
+
+    /// Records the LSP each of the given channels belongs to, if the channel peer is an LSP
+    pub(crate) async fn record_channel_lsps(&self, channels: &[Channel]) -> SdkResult<()> {
+        let channel_lsps = self.persister.list_channel_lsps()?;
+        let unknown: Vec<&str> = channels
+            .iter()
+            .map(|c| c.funding_txid.as_str())
+            .filter(|funding_txid| !channel_lsps.contains_key(*funding_txid))
+            .collect();
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        let lsps = self.list_lsps().await?;
+        for peer in self.node_api.list_peers().await? {
+            let peer_id = hex::encode(&peer.id);
+            if let Some(lsp) = lsps.iter().find(|lsp| lsp.pubkey == peer_id) {
+                for channel in peer
+                    .channels
+                    .iter()
+                    .filter(|c| unknown.contains(&c.funding_txid.as_str()))
+                {
+                    self.persister
+                        .set_channel_lsp(&channel.funding_txid, &lsp.id)?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use std::sync::Arc;
+
+    use crate::breez_services::tests::get_dummy_node_state;
+    use crate::models::{Channel, ChannelState, LspAPI};
+    use crate::test_utils::*;
     use crate::{error::SdkError, LspInformation, OpeningFeeParams};
 
-    use super::OpeningFeeParamsMenu;
-    use anyhow::Result;
+    use super::{LspManager, OpeningFeeParamsMenu, RANKING_FEE_REFERENCE_MSAT};
+    use anyhow::{anyhow, Result};
     use chrono::{Duration, Utc};
 
     #[test]
@@ -200,6 +429,128 @@ mod tests {
             panic!("Expected LspOpenChannelNotSupported error");
         }
 
+        Ok(())
+    }
+    #[tokio::test]
+    async fn test_lsp_failover() -> Result<()> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config));
+        persister.init()?;
+        persister.set_node_state(&get_dummy_node_state())?;
+        let breez_server = MockBreezServer {};
+        persister.set_lsp_id(breez_server.lsp_id())?;
+
+        // A third-party LSP with a smaller capacity
+        let mut custom_lsp = breez_server.list_lsps(String::new()).await?.remove(0);
+        custom_lsp.id = "custom".to_string();
+        custom_lsp.pubkey =
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".to_string();
+        custom_lsp.channel_capacity = 1000;
+        persister.set_custom_lsps(&[custom_lsp.clone()])?;
+
+        // The selected LSP is unreachable
+        let unreachable_pubkey = breez_server.lsp_pub_key();
+        let mut node_api = MockNodeAPI::new(get_dummy_node_state());
+        node_api.set_on_connect_peer(Box::new(move |node_id, _| {
+            match node_id == unreachable_pubkey {
+                true => Err(anyhow!("Unreachable")),
+                false => Ok(()),
+            }
+        }));
+        let lsp_manager = LspManager::new(
+            Arc::new(node_api),
+            Arc::new(MockBreezServer {}),
+            persister.clone(),
+        );
+
+        // At the same cost, the LSP with the larger capacity is ranked first
+        let ranked = lsp_manager.rank(lsp_manager.list_lsps().await?)?;
+        assert_eq!(ranked[0].id, breez_server.lsp_id());
+        assert_eq!(ranked[1].id, custom_lsp.id);
+
+        // A cheaper LSP is ranked first, despite its smaller capacity
+        let mut cheaper_lsp = custom_lsp.clone();
+        for params in cheaper_lsp.opening_fee_params_list.values.iter_mut() {
+            params.min_msat = 0;
+            params.proportional = 0;
+        }
+        let mut lsps = lsp_manager.list_lsps().await?;
+        lsps.retain(|lsp| lsp.id != custom_lsp.id);
+        lsps.push(cheaper_lsp);
+        let ranked = lsp_manager.rank(lsps)?;
+        assert_eq!(ranked[0].id, custom_lsp.id);
+
+        // Without auto selection, the connection fails and the LSP is kept
+        assert!(lsp_manager.connect(false, false).await.is_err());
+        assert_eq!(persister.get_lsp_id()?, Some(breez_server.lsp_id()));
+
+        // The unreachable LSP is ranked last
+        let ranked = lsp_manager.rank(lsp_manager.list_lsps().await?)?;
+        assert_eq!(ranked[0].id, custom_lsp.id);
+
+        // With auto selection, the reachable LSP is used for the call only
+        let connected = lsp_manager.connect(true, false).await?;
+        assert_eq!(connected.map(|lsp| lsp.id), Some(custom_lsp.id.clone()));
+        assert_eq!(persister.get_lsp_id()?, Some(breez_server.lsp_id()));
+
+        // With the failover persisted, the reachable LSP is selected
+        lsp_manager.connect(true, true).await?;
+        assert_eq!(persister.get_lsp_id()?, Some(custom_lsp.id.clone()));
+
+        // Channels with the LSP rank it first
+        persister.update_channels(&[Channel {
+            funding_txid: "123".to_string(),
+            short_channel_id: "1x2x3".to_string(),
+            state: ChannelState::Opened,
+            spendable_msat: 0,
+            receivable_msat: 1000,
+            closed_at: None,
+            funding_outnum: None,
+            alias_local: None,
+            alias_remote: None,
+            closing_txid: None,
+        }])?;
+        persister.set_channel_lsp("123", &custom_lsp.id)?;
+        lsp_manager.unreachable.lock().unwrap().clear();
+        let ranked = lsp_manager.rank(lsp_manager.list_lsps().await?)?;
+        assert_eq!(ranked[0].id, custom_lsp.id);
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn test_rank_by_opening_fee() -> Result<()> {
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+        persister.set_node_state(&get_dummy_node_state())?;
+        let lsp_manager = LspManager::new(
+            Arc::new(MockNodeAPI::new(get_dummy_node_state())),
+            Arc::new(MockBreezServer {}),
+            persister,
+        );
+        let lsp = MockBreezServer {}.list_lsps(String::new()).await?.remove(0);
+        let with_fees = |id: &str, min_msat: u64, proportional: u32| {
+            let mut lsp = lsp.clone();
+            lsp.id = id.to_string();
+            for params in lsp.opening_fee_params_list.values.iter_mut() {
+                params.min_msat = min_msat;
+                params.proportional = proportional;
+            }
+            lsp
+        };
+
+        // The LSP with the lower minimum fee charges more for the reference amount
+        let low_min = with_fees("low_min", 1_000_000, 40_000);
+        let low_proportional = with_fees("low_proportional", 2_000_000, 1_000);
+        let fee = |lsp: &LspInformation| {
+            lsp.opening_fee_params_list.values[0]
+                .get_channel_fees_msat_for(RANKING_FEE_REFERENCE_MSAT)
+        };
+        assert!(fee(&low_proportional) < fee(&low_min));
+
+        let ranked = lsp_manager.rank(vec![low_min, low_proportional])?;
+        assert_eq!(ranked[0].id, "low_proportional");
+        assert_eq!(ranked[1].id, "low_min");
+
         Ok(())
     }
 }
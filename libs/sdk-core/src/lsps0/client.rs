@@ -19,7 +19,6 @@ pub struct Client {
 }
 
 impl Client {
-    #[allow(dead_code)]
     pub fn new(transport: Arc<Transport>, peer_id: Vec<u8>, timeout: Duration) -> Self {
         Self {
             transport,
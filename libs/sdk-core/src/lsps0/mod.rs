@@ -6,5 +6,4 @@ pub(crate) mod transport;
 pub(crate) use client::Client;
 
 pub(crate) use error::Error;
-#[allow(unused_imports)]
 pub(crate) use transport::Transport;
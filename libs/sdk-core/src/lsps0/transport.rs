@@ -17,7 +17,7 @@ use super::error::Error;
 use super::jsonrpc::RpcServerMessageBody;
 use super::jsonrpc::{RpcError, RpcRequest, RpcServerMessage};
 
-const LSPS0_MESSAGE_TYPE: u16 = 37913;
+pub(crate) const LSPS0_MESSAGE_TYPE: u16 = 37913;
 const JSONRPC_VERSION: &str = "2.0";
 
 #[tonic::async_trait]
@@ -64,7 +64,6 @@ pub struct Transport {
 }
 
 impl Transport {
-    #[allow(dead_code)]
     pub fn new(node: Arc<dyn NodeAPI>) -> Transport {
         Transport {
             node,
@@ -74,7 +73,6 @@ impl Transport {
         }
     }
 
-    #[allow(dead_code)]
     pub fn start(self: &Arc<Transport>, cancel: watch::Receiver<()>) {
         debug!("starting lsps0 transport.");
         let cloned = self.clone();
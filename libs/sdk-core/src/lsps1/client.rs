@@ -0,0 +1,332 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::lsps0;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetInfoRequest {}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetInfoResponse {
+    pub options: Options,
+}
+
+/// The limits of the channels the LSP sells
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Options {
+    pub min_required_channel_confirmations: u16,
+    pub min_funding_confirms_within_blocks: u16,
+    pub supports_zero_channel_reserve: bool,
+    pub max_channel_expiry_blocks: u32,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_initial_client_balance_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_initial_client_balance_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_initial_lsp_balance_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_initial_lsp_balance_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_channel_balance_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_channel_balance_sat: u64,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateOrderRequest {
+    #[serde_as(as = "DisplayFromStr")]
+    pub lsp_balance_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub client_balance_sat: u64,
+
+    pub required_channel_confirmations: u16,
+    pub funding_confirms_within_blocks: u16,
+    pub channel_expiry_blocks: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_onchain_address: Option<String>,
+
+    pub announce_channel: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetOrderRequest {
+    pub order_id: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderState {
+    Created,
+    Completed,
+    Failed,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentState {
+    ExpectPayment,
+    Hold,
+    Paid,
+    Refunded,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Payment {
+    pub state: PaymentState,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub fee_total_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub order_total_sat: u64,
+
+    pub bolt11_invoice: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ChannelInfo {
+    pub funded_at: String,
+    pub funding_outpoint: String,
+    pub expires_at: String,
+}
+
+/// The order returned by both `lsps1.create_order` and `lsps1.get_order`
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Order {
+    pub order_id: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub lsp_balance_sat: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub client_balance_sat: u64,
+
+    pub required_channel_confirmations: u16,
+    pub funding_confirms_within_blocks: u16,
+    pub channel_expiry_blocks: u32,
+    pub created_at: String,
+    pub expires_at: String,
+    pub order_state: OrderState,
+    pub payment: Payment,
+    pub channel: Option<ChannelInfo>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateOrderError {
+    #[error("lsps1.create_order option_mismatch error: {0:?}")]
+    OptionMismatch(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps1.create_order client_rejected error: {0:?}")]
+    ClientRejected(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps1.create_order general error: {0}")]
+    Lsps0(lsps0::Error),
+}
+
+impl From<lsps0::Error> for CreateOrderError {
+    fn from(value: lsps0::Error) -> Self {
+        match value {
+            lsps0::Error::Remote(e) => match e.code {
+                100 => Self::OptionMismatch(e),
+                1001 => Self::ClientRejected(e),
+                _ => Self::Lsps0(lsps0::Error::Remote(e)),
+            },
+            _ => Self::Lsps0(value),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetOrderError {
+    #[error("lsps1.get_order not_found error: {0:?}")]
+    NotFound(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps1.get_order general error: {0}")]
+    Lsps0(lsps0::Error),
+}
+
+impl From<lsps0::Error> for GetOrderError {
+    fn from(value: lsps0::Error) -> Self {
+        match value {
+            lsps0::Error::Remote(e) => match e.code {
+                101 => Self::NotFound(e),
+                _ => Self::Lsps0(lsps0::Error::Remote(e)),
+            },
+            _ => Self::Lsps0(value),
+        }
+    }
+}
+
+pub struct Client {
+    client: lsps0::Client,
+}
+
+impl Client {
+    pub fn new(client: lsps0::Client) -> Self {
+        Self { client }
+    }
+
+    pub async fn get_info(&self) -> Result<GetInfoResponse, lsps0::Error> {
+        self.client
+            .call(String::from("lsps1.get_info"), GetInfoRequest {})
+            .await
+    }
+
+    pub async fn create_order(&self, req: CreateOrderRequest) -> Result<Order, CreateOrderError> {
+        match self
+            .client
+            .call(String::from("lsps1.create_order"), req)
+            .await
+        {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub async fn get_order(&self, req: GetOrderRequest) -> Result<Order, GetOrderError> {
+        match self.client.call(String::from("lsps1.get_order"), req).await {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lsps1::client::{
+        ChannelInfo, CreateOrderRequest, GetInfoResponse, Options, Order, OrderState, Payment,
+        PaymentState,
+    };
+
+    #[test]
+    fn test_get_info_response_deserialize() {
+        let json = r#"{
+            "options": {
+                "min_required_channel_confirmations": 0,
+                "min_funding_confirms_within_blocks": 6,
+                "supports_zero_channel_reserve": true,
+                "max_channel_expiry_blocks": 20160,
+                "min_initial_client_balance_sat": "0",
+                "max_initial_client_balance_sat": "0",
+                "min_initial_lsp_balance_sat": "100000",
+                "max_initial_lsp_balance_sat": "100000000",
+                "min_channel_balance_sat": "100000",
+                "max_channel_balance_sat": "100000000"
+            }
+        }"#;
+
+        let result = serde_json::from_str::<GetInfoResponse>(json).unwrap();
+        assert_eq!(
+            result,
+            GetInfoResponse {
+                options: Options {
+                    min_required_channel_confirmations: 0,
+                    min_funding_confirms_within_blocks: 6,
+                    supports_zero_channel_reserve: true,
+                    max_channel_expiry_blocks: 20160,
+                    min_initial_client_balance_sat: 0,
+                    max_initial_client_balance_sat: 0,
+                    min_initial_lsp_balance_sat: 100_000,
+                    max_initial_lsp_balance_sat: 100_000_000,
+                    min_channel_balance_sat: 100_000,
+                    max_channel_balance_sat: 100_000_000,
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_create_order_request_serialize() {
+        let req = CreateOrderRequest {
+            lsp_balance_sat: 1_000_000,
+            client_balance_sat: 0,
+            required_channel_confirmations: 0,
+            funding_confirms_within_blocks: 6,
+            channel_expiry_blocks: 13140,
+            token: None,
+            refund_onchain_address: None,
+            announce_channel: false,
+        };
+        let result = serde_json::to_string(&req).unwrap();
+        assert_eq!(
+            r#"{"lsp_balance_sat":"1000000","client_balance_sat":"0","required_channel_confirmations":0,"funding_confirms_within_blocks":6,"channel_expiry_blocks":13140,"announce_channel":false}"#,
+            result
+        )
+    }
+
+    #[test]
+    fn test_order_deserialize() {
+        let json = r#"{
+            "order_id": "bb4b5d0a-8334-49d8-9463-90a6d413af7c",
+            "lsp_balance_sat": "1000000",
+            "client_balance_sat": "0",
+            "required_channel_confirmations": 0,
+            "funding_confirms_within_blocks": 6,
+            "channel_expiry_blocks": 13140,
+            "token": "",
+            "created_at": "2023-01-01T00:00:00.000Z",
+            "expires_at": "2023-01-02T00:00:00.000Z",
+            "announce_channel": false,
+            "order_state": "COMPLETED",
+            "payment": {
+                "state": "PAID",
+                "fee_total_sat": "8888",
+                "order_total_sat": "8888",
+                "bolt11_invoice": "lnbc252u1p3aht9ysp580g4633gd2x9lc5al0wd8wx0mpn9748jeyz46kqjrpxn52uhfpjqpp5qgf67tcqmuqehzgjm8mzya90h73deafvr4m5705l5u5l4r05l8cqdpud3h8ymm4w3jhytnpwpczqmt0de6xsmre9ccqz",
+                "onchain_address": null,
+                "min_onchain_payment_confirmations": null,
+                "min_fee_for_0conf": 253,
+                "onchain_payment": null
+            },
+            "channel": {
+                "funded_at": "2023-01-01T00:10:00.000Z",
+                "funding_outpoint": "0301e0480b374b32851a9462db29dc19fe830a7f7d7a88b81612b9d42099c0ae:0",
+                "expires_at": "2023-04-01T00:10:00.000Z"
+            }
+        }"#;
+
+        let result = serde_json::from_str::<Order>(json).unwrap();
+        assert_eq!(
+            result,
+            Order {
+                order_id: String::from("bb4b5d0a-8334-49d8-9463-90a6d413af7c"),
+                lsp_balance_sat: 1_000_000,
+                client_balance_sat: 0,
+                required_channel_confirmations: 0,
+                funding_confirms_within_blocks: 6,
+                channel_expiry_blocks: 13140,
+                created_at: String::from("2023-01-01T00:00:00.000Z"),
+                expires_at: String::from("2023-01-02T00:00:00.000Z"),
+                order_state: OrderState::Completed,
+                payment: Payment {
+                    state: PaymentState::Paid,
+                    fee_total_sat: 8888,
+                    order_total_sat: 8888,
+                    bolt11_invoice: String::from("lnbc252u1p3aht9ysp580g4633gd2x9lc5al0wd8wx0mpn9748jeyz46kqjrpxn52uhfpjqpp5qgf67tcqmuqehzgjm8mzya90h73deafvr4m5705l5u5l4r05l8cqdpud3h8ymm4w3jhytnpwpczqmt0de6xsmre9ccqz"),
+                },
+                channel: Some(ChannelInfo {
+                    funded_at: String::from("2023-01-01T00:10:00.000Z"),
+                    funding_outpoint: String::from(
+                        "0301e0480b374b32851a9462db29dc19fe830a7f7d7a88b81612b9d42099c0ae:0"
+                    ),
+                    expires_at: String::from("2023-04-01T00:10:00.000Z"),
+                }),
+            }
+        )
+    }
+}
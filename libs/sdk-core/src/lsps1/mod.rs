@@ -0,0 +1,3 @@
+pub(crate) mod client;
+
+pub(crate) use client::Client;
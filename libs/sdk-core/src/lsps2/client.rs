@@ -123,7 +123,6 @@ pub struct Client {
 }
 
 impl Client {
-    #[allow(dead_code)]
     pub fn new(client: lsps0::Client) -> Self {
         Self { client }
     }
@@ -135,7 +134,6 @@ impl Client {
             .await
     }
 
-    #[allow(dead_code)]
     pub async fn get_info(&self, req: GetInfoRequest) -> Result<GetInfoResponse, GetInfoError> {
         match self.client.call(String::from("lsps2.get_info"), req).await {
             Ok(v) => Ok(v),
@@ -143,7 +141,6 @@ impl Client {
         }
     }
 
-    #[allow(dead_code)]
     pub async fn buy(&self, req: BuyRequest) -> Result<BuyResponse, BuyError> {
         match self.client.call(String::from("lsps2.buy"), req).await {
             Ok(v) => Ok(v),
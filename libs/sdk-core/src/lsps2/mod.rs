@@ -1,4 +1,3 @@
 pub(crate) mod client;
 
-#[allow(unused_imports)]
 pub(crate) use client::Client;
@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+
+use crate::lsps0;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetWebhookRequest {
+    pub app_name: String,
+    pub webhook: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SetWebhookResponse {
+    pub num_webhooks: u32,
+    pub max_webhooks: u32,
+
+    /// Whether the webhook was already registered with the same url
+    pub no_change: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ListWebhooksRequest {}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ListWebhooksResponse {
+    pub app_names: Vec<String>,
+    pub max_webhooks: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveWebhookRequest {
+    pub app_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RemoveWebhookResponse {}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetWebhookError {
+    #[error("lsps5.set_webhook too_long error: {0:?}")]
+    TooLong(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps5.set_webhook url_parse_error error: {0:?}")]
+    UrlParseError(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps5.set_webhook unsupported_protocol error: {0:?}")]
+    UnsupportedProtocol(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps5.set_webhook too_many_webhooks error: {0:?}")]
+    TooManyWebhooks(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps5.set_webhook general error: {0}")]
+    Lsps0(lsps0::Error),
+}
+
+impl From<lsps0::Error> for SetWebhookError {
+    fn from(value: lsps0::Error) -> Self {
+        match value {
+            lsps0::Error::Remote(e) => match e.code {
+                500 => Self::TooLong(e),
+                501 => Self::UrlParseError(e),
+                502 => Self::UnsupportedProtocol(e),
+                503 => Self::TooManyWebhooks(e),
+                _ => Self::Lsps0(lsps0::Error::Remote(e)),
+            },
+            _ => Self::Lsps0(value),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RemoveWebhookError {
+    #[error("lsps5.remove_webhook app_name_not_found error: {0:?}")]
+    AppNameNotFound(lsps0::jsonrpc::RpcError),
+
+    #[error("lsps5.remove_webhook general error: {0}")]
+    Lsps0(lsps0::Error),
+}
+
+impl From<lsps0::Error> for RemoveWebhookError {
+    fn from(value: lsps0::Error) -> Self {
+        match value {
+            lsps0::Error::Remote(e) => match e.code {
+                1010 => Self::AppNameNotFound(e),
+                _ => Self::Lsps0(lsps0::Error::Remote(e)),
+            },
+            _ => Self::Lsps0(value),
+        }
+    }
+}
+
+pub struct Client {
+    client: lsps0::Client,
+}
+
+impl Client {
+    pub fn new(client: lsps0::Client) -> Self {
+        Self { client }
+    }
+
+    pub async fn set_webhook(
+        &self,
+        req: SetWebhookRequest,
+    ) -> Result<SetWebhookResponse, SetWebhookError> {
+        match self
+            .client
+            .call(String::from("lsps5.set_webhook"), req)
+            .await
+        {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn list_webhooks(&self) -> Result<ListWebhooksResponse, lsps0::Error> {
+        self.client
+            .call(String::from("lsps5.list_webhooks"), ListWebhooksRequest {})
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_webhook(
+        &self,
+        req: RemoveWebhookRequest,
+    ) -> Result<RemoveWebhookResponse, RemoveWebhookError> {
+        match self
+            .client
+            .call(String::from("lsps5.remove_webhook"), req)
+            .await
+        {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lsps0;
+    use crate::lsps5::client::{
+        ListWebhooksResponse, RemoveWebhookError, RemoveWebhookRequest, SetWebhookError,
+        SetWebhookRequest, SetWebhookResponse,
+    };
+
+    #[test]
+    fn test_set_webhook_request_serialize() {
+        let req = SetWebhookRequest {
+            app_name: String::from("breez-sdk"),
+            webhook: String::from("https://notify.local/hook?token=1234"),
+        };
+        let result = serde_json::to_string(&req).unwrap();
+        assert_eq!(
+            r#"{"app_name":"breez-sdk","webhook":"https://notify.local/hook?token=1234"}"#,
+            result
+        )
+    }
+
+    #[test]
+    fn test_set_webhook_response_deserialize() {
+        let json = r#"{"num_webhooks":2,"max_webhooks":4,"no_change":false}"#;
+        let result = serde_json::from_str::<SetWebhookResponse>(json).unwrap();
+        assert_eq!(
+            result,
+            SetWebhookResponse {
+                num_webhooks: 2,
+                max_webhooks: 4,
+                no_change: false,
+            }
+        )
+    }
+
+    #[test]
+    fn test_list_webhooks_response_deserialize() {
+        let json = r#"{"app_names":["breez-sdk","other-app"],"max_webhooks":4}"#;
+        let result = serde_json::from_str::<ListWebhooksResponse>(json).unwrap();
+        assert_eq!(
+            result,
+            ListWebhooksResponse {
+                app_names: vec![String::from("breez-sdk"), String::from("other-app")],
+                max_webhooks: 4,
+            }
+        )
+    }
+
+    #[test]
+    fn test_remove_webhook_request_serialize() {
+        let req = RemoveWebhookRequest {
+            app_name: String::from("breez-sdk"),
+        };
+        let result = serde_json::to_string(&req).unwrap();
+        assert_eq!(r#"{"app_name":"breez-sdk"}"#, result)
+    }
+
+    #[test]
+    fn test_errors_from_remote() {
+        let remote = |code| {
+            lsps0::Error::Remote(lsps0::jsonrpc::RpcError {
+                code,
+                message: String::from("error"),
+                data: None,
+            })
+        };
+        assert!(matches!(
+            SetWebhookError::from(remote(503)),
+            SetWebhookError::TooManyWebhooks(_)
+        ));
+        assert!(matches!(
+            SetWebhookError::from(remote(1)),
+            SetWebhookError::Lsps0(lsps0::Error::Remote(_))
+        ));
+        assert!(matches!(
+            RemoveWebhookError::from(remote(1010)),
+            RemoveWebhookError::AppNameNotFound(_)
+        ));
+    }
+}
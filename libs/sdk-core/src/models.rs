@@ -24,10 +24,15 @@ use tokio_stream::Stream;
 use tonic::Streaming;
 
 use crate::boltzswap::{BoltzApiCreateReverseSwapResponse, BoltzApiReverseSwapStatus};
-use crate::fiat::{FiatCurrency, Rate};
-use crate::grpc::{self, GetReverseRoutingNodeRequest, PaymentInformation, RegisterPaymentReply};
+use crate::fiat::{FiatCurrency, PaymentFiatRate, Rate};
+use crate::grpc::{
+    self, GetReverseRoutingNodeRequest, PaymentInformation, RegisterPaymentReply,
+    SubscribeNotificationsReply,
+};
 use crate::lnurl::pay::model::SuccessActionProcessed;
 use crate::lsp::LspInformation;
+use crate::lsps1;
+use crate::lsps2;
 use crate::models::Network::*;
 use crate::{LNInvoice, LnUrlErrorData, LnUrlPayRequestData, LnUrlWithdrawRequestData};
 
@@ -58,6 +63,16 @@ pub struct Peer {
     pub channels: Vec<Channel>,
 }
 
+/// The forwarding policy a node announces for its channels
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeChannelPolicy {
+    pub base_fee_msat: u64,
+    /// The proportional fee, in millionths of the forwarded amount
+    pub fee_rate_ppm: u32,
+    pub cltv_expiry_delta: u32,
+    pub htlc_minimum_msat: u64,
+}
+
 /// Trait covering functions affecting the LN node
 #[tonic::async_trait]
 pub trait NodeAPI: Send + Sync {
@@ -94,6 +109,9 @@ pub trait NodeAPI: Send + Sync {
     async fn connect_peer(&self, node_id: String, addr: String) -> Result<()>;
     fn sign_invoice(&self, invoice: RawInvoice) -> Result<String>;
     async fn close_peer_channels(&self, node_id: String) -> Result<Vec<String>>;
+    /// Gets the forwarding policy of the node's public channels from the gossip. When the
+    /// channels have different policies, the highest fees and delays are returned.
+    async fn fetch_channel_policy(&self, node_id: String) -> Result<Option<NodeChannelPolicy>>;
     async fn stream_incoming_payments(&self) -> Result<Streaming<gl_client::pb::IncomingPayment>>;
     async fn stream_log_messages(&self) -> Result<Streaming<gl_client::pb::LogEntry>>;
     async fn static_backup(&self) -> Result<Vec<String>>;
@@ -120,6 +138,36 @@ pub trait LspAPI: Send + Sync {
         lsp_pubkey: Vec<u8>,
         payment_info: PaymentInformation,
     ) -> Result<RegisterPaymentReply>;
+
+    /// Registers the webhook on which the LSP notifies the node of incoming payments while it's
+    /// offline. The `signature` is the webhook url signed by the node key.
+    async fn subscribe_notifications(
+        &self,
+        lsp_id: String,
+        lsp_pubkey: Vec<u8>,
+        webhook_url: String,
+        signature: String,
+    ) -> Result<SubscribeNotificationsReply>;
+}
+
+/// Trait covering the chain notification service, which triggers webhooks on chain events
+#[tonic::async_trait]
+pub trait ChainNotifierAPI: Send + Sync {
+    /// Associates the webhook url with the current registration, returning its id. A new
+    /// registration is created if there is none yet or the current one was expired.
+    async fn add_registration(
+        &self,
+        current_registration_id: Option<String>,
+        webhook_url: String,
+    ) -> Result<String>;
+
+    /// Subscribes the webhook to the transactions of the address
+    async fn subscribe_address_transactions(
+        &self,
+        registration_id: String,
+        webhook_url: String,
+        address: String,
+    ) -> Result<()>;
 }
 
 /// Trait covering fiat-related functionality
@@ -474,6 +522,9 @@ pub struct Config {
     /// Directory in which all SDK files (DB, log) are stored. Defaults to ".", otherwise if it's customized,
     /// the folder should exist before starting the SDK.
     pub working_dir: String,
+    /// The 32 bytes key used to encrypt the local databases. When not set, the key is derived
+    /// from the seed.
+    pub storage_key: Option<Vec<u8>>,
     pub network: Network,
     pub payment_timeout_sec: u32,
     pub default_lsp_id: Option<String>,
@@ -483,6 +534,21 @@ pub struct Config {
     /// Maps to the CLN `exemptfee` config when paying invoices (`lightning-pay`)
     pub exemptfee_msat: u64,
     pub node_config: NodeConfig,
+    /// URLs of additional fiat rate feeds, whose rates are aggregated with the ones of the Breez
+    /// server. Each must respond to a GET request with a JSON list of [Rate].
+    pub fiat_rates_urls: Vec<String>,
+    /// The fiat currencies whose rates are recorded with each payment, see [Payment::fiat_rates]
+    pub recorded_fiat_currencies: Vec<String>,
+    /// Number of emitted events kept in the event log, see [crate::BreezServices::events_since].
+    /// Set to 0 to disable the event log.
+    pub event_log_retention: u32,
+    /// Whether the LSP is selected automatically: the best ranked one when none is selected, and
+    /// another one when the selected one is unreachable. LSPs are ranked by reachability, their
+    /// fees, the inbound liquidity of the channels with them and their channel capacity.
+    pub auto_select_lsp: bool,
+    /// Whether the LSP used when the selected one is unreachable replaces the selected one. By
+    /// default, it is only used for the failed over call. Requires [Config::auto_select_lsp].
+    pub persist_lsp_failover: bool,
 }
 
 impl Config {
@@ -491,6 +557,7 @@ impl Config {
             breezserver: "https://bs1.breez.technology:443".to_string(),
             mempoolspace_url: "https://mempool.space".to_string(),
             working_dir: ".".to_string(),
+            storage_key: None,
             network: Bitcoin,
             payment_timeout_sec: 60,
             default_lsp_id: Some(String::from("03cea51f-b654-4fb0-8e82-eca137f236a0")),
@@ -498,6 +565,11 @@ impl Config {
             maxfee_percent: 1.0,
             exemptfee_msat: 20000,
             node_config,
+            fiat_rates_urls: vec![],
+            recorded_fiat_currencies: vec!["USD".to_string(), "EUR".to_string()],
+            event_log_retention: 1000,
+            auto_select_lsp: false,
+            persist_lsp_failover: false,
         }
     }
 
@@ -506,6 +578,7 @@ impl Config {
             breezserver: "https://bs1-st.breez.technology:443".to_string(),
             mempoolspace_url: "https://mempool.space".to_string(),
             working_dir: ".".to_string(),
+            storage_key: None,
             network: Bitcoin,
             payment_timeout_sec: 60,
             default_lsp_id: Some(String::from("ea51d025-042d-456c-8325-63e430797481")),
@@ -513,6 +586,11 @@ impl Config {
             maxfee_percent: 0.5,
             exemptfee_msat: 20000,
             node_config,
+            fiat_rates_urls: vec![],
+            recorded_fiat_currencies: vec!["USD".to_string(), "EUR".to_string()],
+            event_log_retention: 1000,
+            auto_select_lsp: false,
+            persist_lsp_failover: false,
         }
     }
 }
@@ -610,6 +688,73 @@ pub struct BackupStatus {
     pub last_backup_time: Option<u64>,
 }
 
+/// A version of the backup that was pushed to the remote storage and can be restored
+/// with [crate::BreezServices::restore_backup]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct BackupVersion {
+    pub version: u64,
+    /// The time this version was pushed, formatted as `YYYY-MM-DD HH:MM:SS` (UTC)
+    pub created_at: String,
+}
+
+/// Result of [crate::BreezServices::verify_backup]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct BackupVerificationReport {
+    /// The generation of the remote backup, `None` if there is no remote backup yet
+    pub remote_generation: Option<u64>,
+    /// The last backup version pushed from this instance
+    pub local_version: Option<u64>,
+    /// Whether the remote backup could be opened and passed the SQLite integrity check
+    pub integrity_ok: bool,
+    /// Whether all the synced tables have the same content locally and in the remote backup
+    pub in_sync: bool,
+    pub diff: Vec<BackupTableDiff>,
+}
+
+/// Represents a database maintenance request, see [crate::BreezServices::db_maintenance]
+#[derive(Clone, Debug, Default)]
+pub struct DbMaintenanceRequest {
+    /// Failed payments older than this number of days are deleted. Nothing is deleted if not set.
+    pub failed_payments_retention_days: Option<u32>,
+    /// Number of most recent backup versions to keep in the versions history. All the versions
+    /// are kept if not set.
+    pub backup_versions_to_keep: Option<u32>,
+}
+
+/// Result of [crate::BreezServices::db_maintenance]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DbMaintenanceReport {
+    /// Whether the main DB passed the integrity check. If not, it was rebuilt from the node.
+    pub main_db_ok: bool,
+    /// Whether the sync DB passed the integrity check. If not, it was restored from the remote backup.
+    pub sync_db_ok: bool,
+    /// Number of payments pulled from the node when rebuilding the main DB
+    pub recovered_payments: u32,
+    /// Number of swaps restored from the remote backup when restoring the sync DB
+    pub recovered_swaps: u32,
+    /// Number of reverse swaps restored from the remote backup when restoring the sync DB
+    pub recovered_reverse_swaps: u32,
+    pub pruned_failed_payments: u32,
+    pub pruned_backup_versions: u32,
+    pub pruned_cached_items: u32,
+}
+
+/// Comparison of a synced table between the local state and the remote backup
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct BackupTableDiff {
+    pub table: String,
+    pub local_rows: u64,
+    pub remote_rows: u64,
+    /// Whether the table content is the same, compared by hashing all the rows
+    pub in_sync: bool,
+}
+
+/// Represents a request to set, change or remove (when `passphrase` is `None`) the backup passphrase
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChangeBackupPassphraseRequest {
+    pub passphrase: Option<String>,
+}
+
 /// The node state of a Greenlight LN node running in the cloud
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct NodeState {
@@ -654,6 +799,22 @@ pub struct Payment {
     pub status: PaymentStatus,
     pub description: Option<String>,
     pub details: PaymentDetails,
+    /// The note and tags set by the app, see [crate::BreezServices::set_payment_metadata]
+    pub metadata: Option<PaymentMetadata>,
+    /// The rates at the time the payment was completed of the currencies in
+    /// [Config::recorded_fiat_currencies] and, for payments made with
+    /// [crate::BreezServices::send_fiat_payment] or [crate::BreezServices::receive_fiat_payment],
+    /// of the quoted currency. Empty for payments made before rates were recorded, or if they
+    /// couldn't be fetched at that time.
+    #[serde(default)]
+    pub fiat_rates: Vec<PaymentFiatRate>,
+}
+
+/// App-level metadata attached to a [Payment], which is synced with the backup
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMetadata {
+    pub note: Option<String>,
+    pub tags: Vec<String>,
 }
 
 /// Represents a list payments request.
@@ -664,6 +825,41 @@ pub struct ListPaymentsRequest {
     pub include_failures: Option<bool>,
     pub offset: Option<u32>,
     pub limit: Option<u32>,
+    /// Only list the payments that come after this one, see [PaymentsCursor]
+    pub cursor: Option<PaymentsCursor>,
+    /// Text matched against the description, LNURL metadata, Lightning address and payment hash
+    pub search: Option<String>,
+    pub min_amount_msat: Option<u64>,
+    pub max_amount_msat: Option<u64>,
+    /// Only list the payments that have at least one of these tags
+    pub tags: Option<Vec<String>>,
+}
+
+/// Represents a payment history export request, see [crate::BreezServices::export_payments]
+#[derive(Clone, Debug)]
+pub struct ExportPaymentsRequest {
+    pub format: PaymentExportFormat,
+    pub from_timestamp: Option<i64>,
+    pub to_timestamp: Option<i64>,
+    /// The id of the fiat currency, e.g. "USD", in which the payment amounts are also given
+    pub fiat_currency: Option<String>,
+}
+
+/// The file format of a payment history export
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentExportFormat {
+    Csv,
+    Json,
+}
+
+/// Position in the payments list, which is ordered by payment time and id, newest first.
+///
+/// To fetch the next page, use the `payment_time` and `id` of the last payment of the current
+/// one. Unlike an offset, the cursor stays valid when new payments are added.
+#[derive(Clone, Debug)]
+pub struct PaymentsCursor {
+    pub payment_time: i64,
+    pub id: String,
 }
 
 /// Represents a payment response.
@@ -758,6 +954,74 @@ pub struct ReceivePaymentResponse {
     pub opening_fee_msat: Option<u64>,
 }
 
+/// Represents a receive payment request where the amount is given in a fiat currency.
+///
+/// See [crate::BreezServices::receive_fiat_payment]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReceiveFiatPaymentRequest {
+    /// The id of the fiat currency, as in [crate::FiatCurrency::id]
+    pub fiat_currency: String,
+    /// The amount in the fiat currency
+    pub fiat_amount: f64,
+    /// The description for this payment request. The fiat amount and rate are appended to it.
+    pub description: String,
+    /// If set and valid, these fess options are used when a new channels is needed.
+    /// Otherwise the default fee options will be used.
+    pub opening_fee_params: Option<OpeningFeeParams>,
+    /// If set to true, then the bolt11 invoice returned includes the description hash.
+    pub use_description_hash: Option<bool>,
+    /// if specified, set the time the invoice is valid for, in seconds.
+    pub expiry: Option<u32>,
+    /// if specified, sets the min_final_cltv_expiry for the invoice
+    pub cltv: Option<u32>,
+}
+
+/// Represents a receive fiat payment response.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReceiveFiatPaymentResponse {
+    pub ln_invoice: LNInvoice,
+    pub opening_fee_params: Option<OpeningFeeParams>,
+    pub opening_fee_msat: Option<u64>,
+    pub quote: FiatQuote,
+}
+
+/// The conversion of a fiat amount to millisatoshis, locked in for a payment
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FiatQuote {
+    pub fiat_currency: String,
+    pub fiat_amount: f64,
+    /// The price of one bitcoin in the fiat currency
+    pub rate: f64,
+    pub amount_msat: u64,
+    /// The time until which the quote holds, which is the expiry of the invoice, in seconds
+    /// since epoch
+    pub expires_at: u64,
+}
+
+/// Represents a request to process a notification received on the registered webhook.
+///
+/// See [crate::BreezServices::process_notification]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProcessNotificationRequest {
+    /// The JSON payload posted to the webhook
+    pub payload: String,
+    /// The time by which the processing has to finish, in seconds since epoch. It should leave
+    /// some margin before the deadline of the OS to run in the background.
+    pub deadline: u64,
+}
+
+/// The outcome of processing a webhook notification
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ProcessNotificationResult {
+    /// The payment of the notification was received
+    PaymentReceived { payment: Payment },
+    /// The swap of the notified address was updated and, if possible, redeemed
+    SwapUpdated { swap_info: SwapInfo },
+    /// The processing didn't finish before the deadline
+    DeadlineReached,
+}
+
 /// Represents a send payment request.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SendPaymentRequest {
@@ -782,9 +1046,35 @@ pub struct SendPaymentResponse {
     pub payment: Payment,
 }
 
+/// Represents a send payment request where the amount is given in a fiat currency.
+///
+/// See [crate::BreezServices::send_fiat_payment]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SendFiatPaymentRequest {
+    /// The bolt11 invoice, which must not have an amount
+    pub bolt11: String,
+    /// The id of the fiat currency, as in [crate::FiatCurrency::id]
+    pub fiat_currency: String,
+    /// The amount to pay in the fiat currency
+    pub fiat_amount: f64,
+}
+
+/// Represents a send fiat payment response.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SendFiatPaymentResponse {
+    pub payment: Payment,
+    pub quote: FiatQuote,
+}
+
 #[derive(Clone)]
 pub struct StaticBackupRequest {
     pub working_dir: String,
+    /// The seed used to connect, needed to read the storage as it is encrypted with a key
+    /// derived from it
+    pub seed: Option<Vec<u8>>,
+    /// The key the storage is encrypted with, when set with [Config::storage_key]. Takes
+    /// precedence over the seed.
+    pub storage_key: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -803,6 +1093,83 @@ pub struct OpenChannelFeeResponse {
     pub used_fee_params: Option<OpeningFeeParams>,
 }
 
+/// Request to order a channel with inbound liquidity from the LSP, see
+/// [crate::BreezServices::prepare_buy_inbound_liquidity]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrepareBuyInboundLiquidityRequest {
+    /// The inbound liquidity, i.e. the balance on the LSP side of the channel
+    pub amount_sat: u64,
+    /// The number of blocks the LSP commits to keep the channel open
+    pub lease_blocks: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrepareBuyInboundLiquidityResponse {
+    /// The created order, whose [LiquidityOrder::fee_sat] is paid when buying it
+    pub order: LiquidityOrder,
+}
+
+/// Request to pay an order prepared with [crate::BreezServices::prepare_buy_inbound_liquidity],
+/// see [crate::BreezServices::buy_inbound_liquidity]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BuyInboundLiquidityRequest {
+    pub order_id: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BuyInboundLiquidityResponse {
+    pub order: LiquidityOrder,
+}
+
+/// The state of a [LiquidityOrder]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiquidityOrderState {
+    /// The order is waiting for its invoice to be paid
+    Created,
+    /// The invoice is paid and the LSP is opening the channel
+    Paid,
+    /// The channel is open
+    Completed,
+    /// The channel could not be opened. A paid invoice is refunded by the LSP.
+    Failed,
+}
+
+/// An order for a channel with inbound liquidity, bought from the LSP with LSPS1
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LiquidityOrder {
+    pub order_id: String,
+    pub state: LiquidityOrderState,
+    pub lsp_balance_sat: u64,
+    pub lease_blocks: u32,
+    pub fee_sat: u64,
+    pub bolt11: String,
+    /// The funding outpoint of the channel, once it's opened
+    pub funding_outpoint: Option<String>,
+}
+
+impl From<lsps1::client::Order> for LiquidityOrder {
+    fn from(order: lsps1::client::Order) -> Self {
+        let state = match (order.order_state, order.payment.state) {
+            (lsps1::client::OrderState::Completed, _) => LiquidityOrderState::Completed,
+            (lsps1::client::OrderState::Failed, _) => LiquidityOrderState::Failed,
+            (
+                lsps1::client::OrderState::Created,
+                lsps1::client::PaymentState::Hold | lsps1::client::PaymentState::Paid,
+            ) => LiquidityOrderState::Paid,
+            (lsps1::client::OrderState::Created, _) => LiquidityOrderState::Created,
+        };
+        LiquidityOrder {
+            order_id: order.order_id,
+            state,
+            lsp_balance_sat: order.lsp_balance_sat,
+            lease_blocks: order.channel_expiry_blocks,
+            fee_sat: order.payment.fee_total_sat,
+            bolt11: order.payment.bolt11_invoice,
+            funding_outpoint: order.channel.map(|c| c.funding_outpoint),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ReceiveOnchainRequest {
     pub opening_fee_params: Option<OpeningFeeParams>,
@@ -926,6 +1293,32 @@ impl From<grpc::OpeningFeeParams> for OpeningFeeParams {
     }
 }
 
+impl From<lsps2::client::OpeningFeeParams> for OpeningFeeParams {
+    fn from(ofp: lsps2::client::OpeningFeeParams) -> Self {
+        Self {
+            min_msat: ofp.min_fee_msat,
+            proportional: ofp.proportional,
+            valid_until: ofp.valid_until,
+            max_idle_time: ofp.min_lifetime,
+            max_client_to_self_delay: ofp.max_client_to_self_delay,
+            promise: ofp.promise,
+        }
+    }
+}
+
+impl From<OpeningFeeParams> for lsps2::client::OpeningFeeParams {
+    fn from(ofp: OpeningFeeParams) -> Self {
+        Self {
+            min_fee_msat: ofp.min_msat,
+            proportional: ofp.proportional,
+            valid_until: ofp.valid_until,
+            min_lifetime: ofp.max_idle_time,
+            max_client_to_self_delay: ofp.max_client_to_self_delay,
+            promise: ofp.promise,
+        }
+    }
+}
+
 impl FromSql for OpeningFeeParams {
     fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
         serde_json::from_str(value.as_str()?).map_err(|_| FromSqlError::InvalidType)
@@ -995,6 +1388,28 @@ impl OpeningFeeParamsMenu {
         Ok(())
     }
 
+    /// Returns the cheapest opening fee params that are valid for at least `expiry` seconds, or
+    /// the longest valid ones if none are.
+    pub(crate) fn cheapest_open_channel_fee(&self, expiry: u32) -> SdkResult<&OpeningFeeParams> {
+        for fee in &self.values {
+            match fee.valid_for(expiry) {
+                Ok(valid) => {
+                    if valid {
+                        return Ok(fee);
+                    }
+                }
+                Err(e) => {
+                    return Err(SdkError::CalculateOpenChannelFeesFailed { err: e.to_string() })
+                }
+            }
+        }
+        self.values
+            .last()
+            .ok_or_else(|| SdkError::LspOpenChannelNotSupported {
+                err: "Dynamic fees menu contains no values".to_string(),
+            })
+    }
+
     pub fn get_cheapest_opening_fee_params(&self) -> SdkResult<OpeningFeeParams> {
         self.values
             .first()
@@ -1282,10 +1697,14 @@ mod tests {
     use rand::random;
 
     use crate::grpc::PaymentInformation;
+    use crate::lsps1::{
+        self,
+        client::{OrderState, PaymentState},
+    };
     use crate::test_utils::{get_test_ofp, rand_vec_u8};
     use crate::OpeningFeeParams;
 
-    use super::OpeningFeeParamsMenu;
+    use super::{LiquidityOrder, LiquidityOrderState, OpeningFeeParamsMenu};
 
     #[test]
     fn test_ofp_menu_validation() -> Result<()> {
@@ -1383,4 +1802,60 @@ mod tests {
         ofp.valid_until = "2023-08-03T00:30:35.117Z".to_string();
         ofp.valid_until_date().map(|_| ())
     }
+
+    #[test]
+    fn test_liquidity_order_state() {
+        let order = |order_state, payment_state| lsps1::client::Order {
+            order_id: "order".to_string(),
+            lsp_balance_sat: 1_000_000,
+            client_balance_sat: 0,
+            required_channel_confirmations: 0,
+            funding_confirms_within_blocks: 6,
+            channel_expiry_blocks: 13140,
+            created_at: "2023-01-01T00:00:00.000Z".to_string(),
+            expires_at: "2023-01-02T00:00:00.000Z".to_string(),
+            order_state,
+            payment: lsps1::client::Payment {
+                state: payment_state,
+                fee_total_sat: 8888,
+                order_total_sat: 8888,
+                bolt11_invoice: "bolt11".to_string(),
+            },
+            channel: None,
+        };
+
+        let cases = [
+            (
+                OrderState::Created,
+                PaymentState::ExpectPayment,
+                LiquidityOrderState::Created,
+            ),
+            (
+                OrderState::Created,
+                PaymentState::Hold,
+                LiquidityOrderState::Paid,
+            ),
+            (
+                OrderState::Created,
+                PaymentState::Paid,
+                LiquidityOrderState::Paid,
+            ),
+            (
+                OrderState::Completed,
+                PaymentState::Paid,
+                LiquidityOrderState::Completed,
+            ),
+            (
+                OrderState::Failed,
+                PaymentState::Refunded,
+                LiquidityOrderState::Failed,
+            ),
+        ];
+        for (order_state, payment_state, expected) in cases {
+            let liquidity_order = LiquidityOrder::from(order(order_state, payment_state));
+            assert_eq!(liquidity_order.state, expected);
+            assert_eq!(liquidity_order.fee_sat, 8888);
+            assert_eq!(liquidity_order.lease_blocks, 13140);
+        }
+    }
 }
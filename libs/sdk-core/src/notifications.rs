@@ -0,0 +1,89 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use tonic::Request;
+
+use crate::breez_services::BreezServer;
+use crate::grpc::{AddRegistrationRequest, SubscribeAddressTransactionsRequest};
+use crate::models::ChainNotifierAPI;
+
+/// A notification posted to the registered webhook, see `doc/SDK_NOTIFICATIONS.md`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub(crate) enum Notification {
+    /// The LSP holds an incoming payment for the node
+    PaymentReceived { payment_hash: String },
+    /// A transaction to a subscribed swap address was confirmed
+    AddressTxsConfirmed { address: String },
+}
+
+impl Notification {
+    pub(crate) fn parse(payload: &str) -> Result<Self> {
+        serde_json::from_str(payload).map_err(|e| anyhow!("Invalid notification payload: {e}"))
+    }
+}
+
+#[tonic::async_trait]
+impl ChainNotifierAPI for BreezServer {
+    async fn add_registration(
+        &self,
+        current_registration_id: Option<String>,
+        webhook_url: String,
+    ) -> Result<String> {
+        let mut client = self.get_chain_notifier_client().await?;
+
+        let request = Request::new(AddRegistrationRequest {
+            current_registration_id: current_registration_id.unwrap_or_default(),
+            url: webhook_url,
+        });
+        let response = client.add_registration(request).await?;
+
+        Ok(response.into_inner().registration_id)
+    }
+
+    async fn subscribe_address_transactions(
+        &self,
+        registration_id: String,
+        webhook_url: String,
+        address: String,
+    ) -> Result<()> {
+        let mut client = self.get_chain_notifier_client().await?;
+
+        let request = Request::new(SubscribeAddressTransactionsRequest {
+            registration_id,
+            url: webhook_url,
+            address,
+        });
+        client.subscribe_address_transactions(request).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notifications::Notification;
+
+    #[test]
+    fn test_parse_notification() {
+        assert_eq!(
+            Notification::parse(
+                r#"{"type": "payment_received", "data": {"payment_hash": "1234"}}"#
+            )
+            .unwrap(),
+            Notification::PaymentReceived {
+                payment_hash: "1234".to_string()
+            }
+        );
+        assert_eq!(
+            Notification::parse(
+                r#"{"type": "address_txs_confirmed", "data": {"address": "bc1qaddress"}}"#
+            )
+            .unwrap(),
+            Notification::AddressTxsConfirmed {
+                address: "bc1qaddress".to_string()
+            }
+        );
+        assert!(Notification::parse(r#"{"type": "unknown", "data": {}}"#).is_err());
+        assert!(Notification::parse("not json").is_err());
+    }
+}
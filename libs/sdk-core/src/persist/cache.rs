@@ -1,9 +1,13 @@
+use crate::fiat::FiatRates;
 use crate::models::NodeState;
 
 use super::db::SqliteStorage;
 use crate::error::{SdkError, SdkResult};
 use anyhow::Result;
 
+/// The keys of the cached items older versions used and that are no longer read
+pub(crate) const OBSOLETE_CACHED_ITEM_KEYS: [&str; 1] = ["backup_passphrase_key"];
+
 impl SqliteStorage {
     pub fn get_cached_item(&self, key: String) -> Result<Option<String>> {
         let res = self.get_connection()?.query_row(
@@ -22,7 +26,6 @@ impl SqliteStorage {
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub fn delete_cached_item(&self, key: String) -> Result<()> {
         self.get_connection()?
             .execute("DELETE FROM cached_items WHERE key = ?1", [key])?;
@@ -71,6 +74,25 @@ impl SqliteStorage {
         }
     }
 
+    /// Stores the keys of the replaced backup passphrases, encrypted with the key of the
+    /// current passphrase. The passphrase keys themselves are never stored in plain.
+    pub fn set_backup_passphrase_keyring(&self, keyring: Option<Vec<u8>>) -> Result<()> {
+        match keyring {
+            Some(keyring) => self.update_cached_item(
+                "backup_passphrase_keyring".to_string(),
+                hex::encode(keyring),
+            ),
+            None => self.delete_cached_item("backup_passphrase_keyring".to_string()),
+        }
+    }
+
+    pub fn get_backup_passphrase_keyring(&self) -> Result<Option<Vec<u8>>> {
+        match self.get_cached_item("backup_passphrase_keyring".to_string())? {
+            Some(str) => Ok(Some(hex::decode(str)?)),
+            None => Ok(None),
+        }
+    }
+
     pub fn set_static_backup(&self, backup: Vec<String>) -> Result<()> {
         let serialized_state = serde_json::to_string(&backup)?;
         self.update_cached_item("static_backup".to_string(), serialized_state)?;
@@ -88,6 +110,43 @@ impl SqliteStorage {
             None => None,
         })
     }
+
+    pub fn set_fiat_rates(&self, rates: &FiatRates) -> Result<()> {
+        let serialized_rates = serde_json::to_string(rates)?;
+        self.update_cached_item("fiat_rates".to_string(), serialized_rates)?;
+        Ok(())
+    }
+
+    pub fn get_fiat_rates(&self) -> Result<Option<FiatRates>> {
+        let rates_str = self.get_cached_item("fiat_rates".to_string())?;
+        Ok(match rates_str {
+            Some(str) => {
+                serde_json::from_str(str.as_str()).map_err(|e| SdkError::PersistenceFailure {
+                    err: format!("Failed to deserialize fiat rates: {e}"),
+                })?
+            }
+            None => None,
+        })
+    }
+
+    pub fn set_webhook_url(&self, webhook_url: String) -> Result<()> {
+        self.update_cached_item("webhook_url".to_string(), webhook_url)
+    }
+
+    pub fn get_webhook_url(&self) -> Result<Option<String>> {
+        self.get_cached_item("webhook_url".to_string())
+    }
+
+    pub fn set_chain_notifier_registration_id(&self, registration_id: String) -> Result<()> {
+        self.update_cached_item(
+            "chain_notifier_registration_id".to_string(),
+            registration_id,
+        )
+    }
+
+    pub fn get_chain_notifier_registration_id(&self) -> Result<Option<String>> {
+        self.get_cached_item("chain_notifier_registration_id".to_string())
+    }
 }
 
 #[test]
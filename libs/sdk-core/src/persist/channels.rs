@@ -138,6 +138,25 @@ impl SqliteStorage {
         )?;
         Ok(())
     }
+
+    /// Records the LSP a channel was opened with
+    pub(crate) fn set_channel_lsp(&self, funding_txid: &str, lsp_id: &str) -> Result<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO channel_lsps (funding_txid, lsp_id) VALUES (?1,?2)",
+            (funding_txid, lsp_id),
+        )?;
+        Ok(())
+    }
+
+    /// Lists the LSPs of the channels, by funding txid
+    pub(crate) fn list_channel_lsps(&self) -> Result<HashMap<String, String>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT funding_txid, lsp_id FROM channel_lsps")?;
+        let channel_lsps = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<HashMap<String, String>, _>>()?;
+        Ok(channel_lsps)
+    }
 }
 
 #[test]
@@ -273,3 +292,21 @@ fn test_sync_closed_channels() {
     let queried_channels = storage.list_channels().unwrap();
     assert_eq!(channels.len(), queried_channels.len());
 }
+
+#[test]
+fn test_channel_lsps() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+
+    storage.init().unwrap();
+    assert!(storage.list_channel_lsps().unwrap().is_empty());
+
+    storage.set_channel_lsp("123", "lsp1").unwrap();
+    storage.set_channel_lsp("456", "lsp1").unwrap();
+    storage.set_channel_lsp("456", "lsp2").unwrap();
+    let channel_lsps = storage.list_channel_lsps().unwrap();
+    assert_eq!(channel_lsps.len(), 2);
+    assert_eq!(channel_lsps.get("123"), Some(&"lsp1".to_string()));
+    assert_eq!(channel_lsps.get("456"), Some(&"lsp2".to_string()));
+}
@@ -1,19 +1,27 @@
 use super::migrations::{current_migrations, current_sync_migrations};
-use crate::error::SdkResult;
+use crate::error::{SdkError, SdkResult};
 use anyhow::Result;
 use rusqlite::{
     hooks::Action,
     types::{FromSql, FromSqlError, ToSqlOutput},
-    Connection, ToSql,
+    Connection, DatabaseName, ToSql,
 };
 use rusqlite_migration::{Migrations, M};
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::Path,
+};
 use tokio::sync::broadcast;
 
+/// The header of unencrypted SQLite database files
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
 /// HookEvent is used to notify listeners about DB changes.
 /// A listener can register to be notified about specific events that occurs as part of
 /// modifications in the persistent storage.
 #[derive(Debug, Clone)]
-pub(crate) enum HookEvent {
+pub enum HookEvent {
     Insert { table: String },
 }
 
@@ -24,6 +32,9 @@ pub(crate) struct SqliteStorage {
     sync_db_file: String,
     /// Dispatch DB hook events.
     events_publisher: broadcast::Sender<HookEvent>,
+    /// SQLCipher raw key used to encrypt both DBs, in the `x'<hex>'` format. Not set for
+    /// unencrypted storages.
+    encryption_key: Option<String>,
 }
 
 impl SqliteStorage {
@@ -36,6 +47,17 @@ impl SqliteStorage {
             main_db_file,
             sync_db_file,
             events_publisher,
+            encryption_key: None,
+        }
+    }
+
+    /// Creates a storage whose DBs are encrypted with the given 32 bytes key.
+    ///
+    /// Existing unencrypted DBs are encrypted when the storage is initialized.
+    pub fn new_encrypted(working_dir: String, encryption_key: Vec<u8>) -> SqliteStorage {
+        SqliteStorage {
+            encryption_key: Some(format!("x'{}'", hex::encode(encryption_key))),
+            ..SqliteStorage::new(working_dir)
         }
     }
 
@@ -44,13 +66,26 @@ impl SqliteStorage {
     }
 
     pub(crate) fn init(&self) -> SdkResult<()> {
+        if let Some(key) = &self.encryption_key {
+            for db_file in [&self.main_db_file, &self.sync_db_file] {
+                Self::encrypt_db(db_file, key).map_err(|e| SdkError::PersistenceFailure {
+                    err: format!("Failed to encrypt {db_file}: {e}"),
+                })?;
+            }
+        }
         self.migrate_main_db()?;
-        Self::migrate_sync_db(self.sync_db_file.clone())?;
+        Self::migrate_sync_db(self.sync_db_file.clone(), self.encryption_key.as_deref())?;
         Ok(())
     }
 
-    pub(crate) fn migrate_sync_db(sync_db_path: String) -> SdkResult<()> {
+    pub(crate) fn migrate_sync_db(
+        sync_db_path: String,
+        encryption_key: Option<&str>,
+    ) -> SdkResult<()> {
         let mut sync_con = Connection::open(sync_db_path)?;
+        if let Some(key) = encryption_key {
+            sync_con.pragma_update(None, "key", key)?;
+        }
         let sync_migrations =
             Migrations::new(current_sync_migrations().into_iter().map(M::up).collect());
         sync_migrations.to_latest(&mut sync_con)?;
@@ -64,10 +99,54 @@ impl SqliteStorage {
         Ok(())
     }
 
+    /// Encrypts the given DB in place, in case it exists and isn't encrypted yet
+    fn encrypt_db(db_file: &str, key: &str) -> Result<()> {
+        let mut header = [0; SQLITE_HEADER.len()];
+        let is_plaintext = match File::open(db_file) {
+            Ok(mut f) => f.read_exact(&mut header).is_ok() && &header == SQLITE_HEADER,
+            Err(_) => false,
+        };
+        if !is_plaintext {
+            return Ok(());
+        }
+
+        info!("Encrypting {db_file}");
+        let encrypted_db_file = format!("{db_file}.encrypted");
+        if Path::new(&encrypted_db_file).exists() {
+            fs::remove_file(&encrypted_db_file)?;
+        }
+        let con = Connection::open(db_file)?;
+        con.execute(
+            "ATTACH DATABASE ?1 AS encrypted KEY ?2;",
+            [encrypted_db_file.as_str(), key],
+        )?;
+        con.query_row("SELECT sqlcipher_export('encrypted');", [], |_| Ok(()))?;
+        // The export doesn't copy the schema version the migrations are tracked with
+        let user_version: i64 = con.query_row("PRAGMA user_version;", [], |row| row.get(0))?;
+        con.pragma_update(
+            Some(DatabaseName::Attached("encrypted")),
+            "user_version",
+            user_version,
+        )?;
+        con.execute("DETACH DATABASE encrypted;", [])?;
+        con.close().map_err(|(_, e)| e)?;
+        fs::rename(&encrypted_db_file, db_file)?;
+        Ok(())
+    }
+
     pub(crate) fn get_connection(&self) -> SdkResult<Connection> {
         let con = Connection::open(self.main_db_file.clone())?;
-        let sql = "ATTACH DATABASE ? AS sync;";
-        con.execute(sql, [self.sync_db_file.clone()])?;
+        match &self.encryption_key {
+            Some(key) => {
+                con.pragma_update(None, "key", key)?;
+                let sql = "ATTACH DATABASE ?1 AS sync KEY ?2;";
+                con.execute(sql, [self.sync_db_file.as_str(), key])?;
+            }
+            None => {
+                let sql = "ATTACH DATABASE ? AS sync;";
+                con.execute(sql, [self.sync_db_file.clone()])?;
+            }
+        }
         // We want to notify any subscribers with hook events.
         let events_publisher = self.events_publisher.clone();
         con.update_hook(Some(move |action, db: &str, t: &str, _| {
@@ -79,6 +158,23 @@ impl SqliteStorage {
         Ok(con)
     }
 
+    /// Opens a connection to a single DB file of this storage, without attaching the sync DB
+    pub(crate) fn open_db_file(&self, db_file: &str) -> SdkResult<Connection> {
+        let con = Connection::open(db_file)?;
+        if let Some(key) = &self.encryption_key {
+            con.pragma_update(None, "key", key)?;
+        }
+        Ok(con)
+    }
+
+    pub(crate) fn is_encrypted(&self) -> bool {
+        self.encryption_key.is_some()
+    }
+
+    pub(crate) fn main_db_path(&self) -> String {
+        self.main_db_file.clone()
+    }
+
     pub(crate) fn sync_db_path(&self) -> String {
         self.sync_db_file.clone()
     }
@@ -100,3 +196,55 @@ impl ToSql for StringArray {
         Ok(ToSqlOutput::from(res?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use std::fs::File;
+    use std::io::Read;
+
+    use super::{SqliteStorage, SQLITE_HEADER};
+    use crate::persist::test_utils;
+
+    fn has_plaintext_header(db_file: &str) -> Result<bool> {
+        let mut header = [0; SQLITE_HEADER.len()];
+        File::open(db_file)?.read_exact(&mut header)?;
+        Ok(&header == SQLITE_HEADER)
+    }
+
+    #[test]
+    fn test_encrypt_existing_storage() -> Result<()> {
+        let working_dir = test_utils::create_test_sql_dir();
+        let storage = SqliteStorage::new(working_dir.clone());
+        storage.init()?;
+        storage.set_lsp_id("lsp".into())?;
+        storage.set_static_backup(vec!["scb".into()])?;
+        assert!(has_plaintext_header(&storage.main_db_file)?);
+        assert!(has_plaintext_header(&storage.sync_db_file)?);
+
+        let encrypted_storage = SqliteStorage::new_encrypted(working_dir.clone(), vec![1; 32]);
+        encrypted_storage.init()?;
+        assert!(!has_plaintext_header(&encrypted_storage.main_db_file)?);
+        assert!(!has_plaintext_header(&encrypted_storage.sync_db_file)?);
+        assert_eq!(encrypted_storage.get_lsp_id()?, Some("lsp".into()));
+        assert_eq!(
+            encrypted_storage.get_static_backup()?,
+            Some(vec!["scb".into()])
+        );
+
+        // The DBs can't be read without the key, or with another one
+        assert!(storage.get_lsp_id().is_err());
+        assert!(
+            SqliteStorage::new_encrypted(working_dir.clone(), vec![2; 32])
+                .get_lsp_id()
+                .is_err()
+        );
+
+        // The sync DB is backed up unencrypted
+        let backup_file = format!("{working_dir}/backup.sql");
+        encrypted_storage.backup(&backup_file)?;
+        assert!(has_plaintext_header(&backup_file)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use rusqlite::{params, types::Type, Row};
+
+use super::db::SqliteStorage;
+use crate::breez_services::{BreezEvent, PersistedEvent};
+
+impl SqliteStorage {
+    /// Appends the event to the event log, keeping only the last `retention` events.
+    ///
+    /// Returns the sequence number of the event.
+    pub(crate) fn insert_event(
+        &self,
+        event: &BreezEvent,
+        created_at: u64,
+        retention: u32,
+    ) -> Result<u64> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        tx.execute(
+            "INSERT INTO events (created_at, data) VALUES (?1,?2)",
+            params![created_at, serde_json::to_string(event)?],
+        )?;
+        let seq = tx.last_insert_rowid();
+        tx.execute(
+            "DELETE FROM events WHERE seq <= ?1",
+            params![seq - retention as i64],
+        )?;
+        tx.commit()?;
+
+        Ok(seq as u64)
+    }
+
+    /// Lists the logged events with a sequence number greater than `seq`, oldest first
+    pub(crate) fn list_events_since(&self, seq: u64) -> Result<Vec<PersistedEvent>> {
+        let con = self.get_connection()?;
+        let mut stmt =
+            con.prepare("SELECT seq, created_at, data FROM events WHERE seq > ?1 ORDER BY seq")?;
+        let events = stmt
+            .query_map(params![seq], |row| self.sql_row_to_event(row))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(events)
+    }
+
+    fn sql_row_to_event(&self, row: &Row) -> rusqlite::Result<PersistedEvent> {
+        let data: String = row.get(2)?;
+        Ok(PersistedEvent {
+            seq: row.get(0)?,
+            created_at: row.get(1)?,
+            event: serde_json::from_str(&data).map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(2, Type::Text, Box::new(e))
+            })?,
+        })
+    }
+}
+
+#[test]
+fn test_event_log() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert!(storage.list_events_since(0).unwrap().is_empty());
+
+    for block in 1..=5 {
+        let seq = storage
+            .insert_event(&BreezEvent::NewBlock { block }, 1000 + block as u64, 3)
+            .unwrap();
+        assert_eq!(seq, block as u64);
+    }
+
+    // Only the last 3 events are retained
+    let events = storage.list_events_since(0).unwrap();
+    assert_eq!(
+        events.iter().map(|e| e.seq).collect::<Vec<_>>(),
+        vec![3, 4, 5]
+    );
+    assert_eq!(events[0].event, BreezEvent::NewBlock { block: 3 });
+    assert_eq!(events[0].created_at, 1003);
+
+    let events = storage.list_events_since(4).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, BreezEvent::NewBlock { block: 5 });
+}
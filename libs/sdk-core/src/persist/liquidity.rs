@@ -0,0 +1,75 @@
+use anyhow::Result;
+use rusqlite::{params, types::Type, OptionalExtension, Row};
+
+use super::db::SqliteStorage;
+use crate::models::LiquidityOrder;
+
+impl SqliteStorage {
+    pub(crate) fn insert_or_update_liquidity_order(&self, order: &LiquidityOrder) -> Result<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO liquidity_orders (order_id, data) VALUES (?1,?2)",
+            params![order.order_id, serde_json::to_string(order)?],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn get_liquidity_order(&self, order_id: &str) -> Result<Option<LiquidityOrder>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                "SELECT data FROM liquidity_orders WHERE order_id = ?1",
+                params![order_id],
+                |row| self.sql_row_to_liquidity_order(row),
+            )
+            .optional()?)
+    }
+
+    pub(crate) fn list_liquidity_orders(&self) -> Result<Vec<LiquidityOrder>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT data FROM liquidity_orders")?;
+        let orders = stmt
+            .query_map([], |row| self.sql_row_to_liquidity_order(row))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(orders)
+    }
+
+    fn sql_row_to_liquidity_order(&self, row: &Row) -> rusqlite::Result<LiquidityOrder> {
+        let data: String = row.get(0)?;
+        serde_json::from_str(&data)
+            .map_err(|e| rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(e)))
+    }
+}
+
+#[test]
+fn test_liquidity_orders() {
+    use crate::models::LiquidityOrderState;
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert!(storage.list_liquidity_orders().unwrap().is_empty());
+
+    let order = LiquidityOrder {
+        order_id: "order".to_string(),
+        state: LiquidityOrderState::Created,
+        lsp_balance_sat: 1_000_000,
+        lease_blocks: 13140,
+        fee_sat: 8888,
+        bolt11: "bolt11".to_string(),
+        funding_outpoint: None,
+    };
+    storage.insert_or_update_liquidity_order(&order).unwrap();
+    assert_eq!(
+        storage.get_liquidity_order("order").unwrap(),
+        Some(order.clone())
+    );
+
+    let paid = LiquidityOrder {
+        state: LiquidityOrderState::Paid,
+        ..order
+    };
+    storage.insert_or_update_liquidity_order(&paid).unwrap();
+    assert_eq!(storage.list_liquidity_orders().unwrap(), vec![paid]);
+    assert_eq!(storage.get_liquidity_order("unknown").unwrap(), None);
+}
@@ -0,0 +1,299 @@
+use super::cache::OBSOLETE_CACHED_ITEM_KEYS;
+use super::db::SqliteStorage;
+use crate::models::PaymentStatus;
+use crate::ReverseSwapStatus;
+use anyhow::Result;
+use rusqlite::{named_params, params, Connection};
+use std::fs;
+
+/// Result of [crate::Storage::check_integrity]
+pub struct DbIntegrity {
+    pub main_ok: bool,
+    pub sync_ok: bool,
+}
+
+impl SqliteStorage {
+    /// Runs the SQLite integrity check on the main and sync DBs
+    pub(crate) fn check_integrity(&self) -> DbIntegrity {
+        DbIntegrity {
+            main_ok: self.db_file_ok(&self.main_db_path()),
+            sync_ok: self.db_file_ok(&self.sync_db_path()),
+        }
+    }
+
+    fn db_file_ok(&self, db_file: &str) -> bool {
+        let res = self.open_db_file(db_file).and_then(|con| {
+            Ok(con.query_row("PRAGMA integrity_check;", [], |row| {
+                row.get::<usize, String>(0)
+            })?)
+        });
+        match res {
+            Ok(check) if check == "ok" => true,
+            Ok(check) => {
+                warn!("Integrity check of {db_file} failed: {check}");
+                false
+            }
+            Err(e) => {
+                warn!("Failed to check the integrity of {db_file}: {e}");
+                false
+            }
+        }
+    }
+
+    /// Deletes the failed payments made before the given time, returns the number of deleted payments
+    pub(crate) fn delete_failed_payments_before(&self, timestamp: i64) -> Result<u32> {
+        let deleted = self.get_connection()?.execute(
+            "DELETE FROM payments WHERE status = ?1 AND payment_time < ?2",
+            params![PaymentStatus::Failed, timestamp],
+        )?;
+        Ok(deleted as u32)
+    }
+
+    /// Keeps only the given number of most recent backup versions, returns the number of deleted versions
+    pub(crate) fn prune_sync_versions(&self, keep: u32) -> Result<u32> {
+        let deleted = self.get_connection()?.execute(
+            "
+            DELETE FROM sync_versions WHERE last_version NOT IN (
+             SELECT last_version FROM sync_versions ORDER BY created_at DESC, last_version DESC LIMIT ?1
+            )",
+            [keep],
+        )?;
+        Ok(deleted as u32)
+    }
+
+    /// Deletes the cached items left over by older versions, returns the number of deleted items
+    pub(crate) fn delete_unused_cached_items(&self) -> Result<u32> {
+        let con = self.get_connection()?;
+        let mut deleted = 0;
+        for key in OBSOLETE_CACHED_ITEM_KEYS {
+            deleted += con.execute("DELETE FROM cached_items WHERE key = ?1", [key])?;
+        }
+        Ok(deleted as u32)
+    }
+
+    /// Rebuilds both DBs, releasing the space of deleted rows
+    pub(crate) fn vacuum(&self) -> Result<()> {
+        self.get_connection()?
+            .execute_batch("VACUUM main; VACUUM sync;")?;
+        Ok(())
+    }
+
+    /// Replaces the main DB with a new one, keeping the settings, cached items and logged events
+    /// that can still be read. The payments and channels have to be pulled again from the node.
+    ///
+    /// The replaced DB is kept next to the new one, with a `.corrupted` suffix.
+    pub(crate) fn reset_main_db(&self) -> Result<()> {
+        let main_db_file = self.main_db_path();
+        let (settings, cached_items, events) = match self.open_db_file(&main_db_file) {
+            Ok(con) => (
+                Self::read_key_values(&con, "settings"),
+                Self::read_key_values(&con, "cached_items"),
+                Self::read_events(&con),
+            ),
+            Err(_) => (vec![], vec![], vec![]),
+        };
+        info!(
+            "Resetting the main DB, keeping {} settings, {} cached items and {} events",
+            settings.len(),
+            cached_items.len(),
+            events.len()
+        );
+        fs::rename(&main_db_file, format!("{main_db_file}.corrupted"))?;
+
+        // The migrations of a new main DB move its tables to a new sync DB, so the synced
+        // state is set aside while they run
+        let sync_db_file = self.sync_db_path();
+        let kept_sync_db_file = format!("{sync_db_file}.kept");
+        fs::rename(&sync_db_file, &kept_sync_db_file)?;
+        let migrated = self.init();
+        fs::rename(&kept_sync_db_file, &sync_db_file)?;
+        migrated?;
+        self.init()?;
+
+        let con = self.get_connection()?;
+        for (key, value) in settings {
+            con.execute(
+                "INSERT OR REPLACE INTO settings (key, value) VALUES (?1,?2)",
+                (key, value),
+            )?;
+        }
+        for (key, value) in cached_items {
+            con.execute(
+                "INSERT OR REPLACE INTO cached_items (key, value) VALUES (?1,?2)",
+                (key, value),
+            )?;
+        }
+
+        // The sequence numbers are kept, as apps use them to list the events they missed
+        for (seq, created_at, data) in events {
+            con.execute(
+                "INSERT OR REPLACE INTO events (seq, created_at, data) VALUES (?1,?2,?3)",
+                params![seq, created_at, data],
+            )?;
+        }
+
+        // The local info of the swaps is refreshed by the swaps monitoring
+        con.execute(
+            "
+            INSERT OR IGNORE INTO swaps_info (bitcoin_address, unconfirmed_tx_ids, confirmed_tx_ids)
+             SELECT bitcoin_address, '[]', '[]' FROM sync.swaps;",
+            [],
+        )?;
+        con.execute(
+            "
+            INSERT OR IGNORE INTO reverse_swaps_info (id, status)
+             SELECT id, :status FROM sync.reverse_swaps;",
+            named_params! {
+                ":status": serde_json::to_value(ReverseSwapStatus::Initial)?
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the sync DB with a new, empty one, to be restored from the remote backup.
+    ///
+    /// The replaced DB is kept next to the new one, with a `.corrupted` suffix.
+    pub(crate) fn reset_sync_db(&self) -> Result<()> {
+        let sync_db_file = self.sync_db_path();
+        info!("Resetting the sync DB");
+        fs::rename(&sync_db_file, format!("{sync_db_file}.corrupted"))?;
+        self.init()?;
+        // The versions history belongs to the replaced DB, without it the next sync merges
+        // the remote backup instead of overwriting it
+        self.get_connection()?
+            .execute("DELETE FROM sync_versions", [])?;
+        Ok(())
+    }
+
+    /// Reads the rows of a key-value table, skipping the ones that can't be read
+    fn read_key_values(con: &Connection, table: &str) -> Vec<(String, String)> {
+        let mut stmt = match con.prepare(&format!("SELECT key, value FROM {table}")) {
+            Ok(stmt) => stmt,
+            Err(e) => {
+                warn!("Failed to read {table}: {e}");
+                return vec![];
+            }
+        };
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)));
+        match rows {
+            Ok(rows) => rows.filter_map(|row| row.ok()).collect(),
+            Err(e) => {
+                warn!("Failed to read {table}: {e}");
+                vec![]
+            }
+        }
+    }
+
+    fn read_events(con: &Connection) -> Vec<(i64, i64, String)> {
+        let mut stmt = match con.prepare("SELECT seq, created_at, data FROM events") {
+            Ok(stmt) => stmt,
+            Err(e) => {
+                warn!("Failed to read events: {e}");
+                return vec![];
+            }
+        };
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)));
+        match rows {
+            Ok(rows) => rows.filter_map(|row| row.ok()).collect(),
+            Err(e) => {
+                warn!("Failed to read events: {e}");
+                vec![]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use std::fs::OpenOptions;
+    use std::io::{Seek, SeekFrom, Write};
+
+    use crate::breez_services::BreezEvent;
+    use crate::persist::db::SqliteStorage;
+    use crate::persist::test_utils;
+
+    #[test]
+    fn test_prune() -> Result<()> {
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        for version in 0..5 {
+            storage.set_last_sync_version(version, &[])?;
+        }
+        storage.update_cached_item("backup_passphrase_key".into(), "value".into())?;
+        storage.update_cached_item("unknown".into(), "value".into())?;
+        storage.set_last_backup_time(10)?;
+
+        assert_eq!(storage.prune_sync_versions(2)?, 3);
+        let versions: Vec<u64> = storage
+            .sync_versions_history()?
+            .into_iter()
+            .map(|v| v.last_version)
+            .collect();
+        assert_eq!(versions.len(), 2);
+        assert!(versions.contains(&4));
+
+        assert_eq!(storage.delete_unused_cached_items()?, 1);
+        assert_eq!(
+            storage.get_cached_item("backup_passphrase_key".into())?,
+            None
+        );
+        assert_eq!(
+            storage.get_cached_item("unknown".into())?,
+            Some("value".into())
+        );
+        assert_eq!(storage.get_last_backup_time()?, Some(10));
+
+        storage.vacuum()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_corrupted_main_db() -> Result<()> {
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        storage.set_lsp_id("lsp".into())?;
+        storage.insert_open_channel_payment_info("123", 1000)?;
+        let sync_tables = SqliteStorage::sync_db_summary(storage.sync_db_path())?.tables;
+        let integrity = storage.check_integrity();
+        assert!(integrity.main_ok && integrity.sync_ok);
+
+        // Overwrite the DB header
+        let mut f = OpenOptions::new()
+            .write(true)
+            .open(storage.main_db_path())?;
+        f.seek(SeekFrom::Start(0))?;
+        f.write_all(&[0; 100])?;
+        f.flush()?;
+        let integrity = storage.check_integrity();
+        assert!(!integrity.main_ok && integrity.sync_ok);
+
+        storage.reset_main_db()?;
+        let integrity = storage.check_integrity();
+        assert!(integrity.main_ok && integrity.sync_ok);
+        assert_eq!(storage.get_lsp_id()?, None);
+        assert_eq!(
+            SqliteStorage::sync_db_summary(storage.sync_db_path())?.tables,
+            sync_tables
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_main_db_keeps_events() -> Result<()> {
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        storage.set_lsp_id("lsp".into())?;
+        storage.insert_event(&BreezEvent::Synced, 10, 100)?;
+        let seq = storage.insert_event(&BreezEvent::BackupStarted, 20, 100)?;
+        let events = storage.list_events_since(0)?;
+
+        storage.reset_main_db()?;
+        assert_eq!(storage.get_lsp_id()?, Some("lsp".into()));
+        assert_eq!(storage.list_events_since(0)?, events);
+        assert!(storage.insert_event(&BreezEvent::Synced, 30, 100)? > seq);
+
+        Ok(())
+    }
+}
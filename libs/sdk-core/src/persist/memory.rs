@@ -0,0 +1,1303 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use log::error;
+use rusqlite::{named_params, Connection};
+use tempfile::NamedTempFile;
+use tokio::sync::broadcast;
+
+use super::cache::OBSOLETE_CACHED_ITEM_KEYS;
+use super::db::{HookEvent, SqliteStorage};
+use super::maintenance::DbIntegrity;
+use super::storage::Storage;
+use super::sync::SyncVersion;
+use crate::breez_services::{BreezEvent, PersistedEvent};
+use crate::error::SdkResult;
+use crate::fiat::{merge_payment_fiat_rates, FiatRates, PaymentFiatRate};
+use crate::lnurl::pay::model::SuccessActionProcessed;
+use crate::lsp::LspInformation;
+use crate::models::*;
+
+#[derive(Clone, Default)]
+struct ExternalInfo {
+    lnurl_success_action: Option<SuccessActionProcessed>,
+    lnurl_metadata: Option<String>,
+    ln_address: Option<String>,
+    lnurl_withdraw_endpoint: Option<String>,
+}
+
+/// A synced row with the time it was last written, which settles merge conflicts
+#[derive(Clone)]
+struct Timestamped<T> {
+    value: T,
+    /// UTC time, in the format of the SQLite `CURRENT_TIMESTAMP`
+    updated_at: String,
+}
+
+impl<T> Timestamped<T> {
+    fn now(value: T) -> Self {
+        Timestamped {
+            value,
+            updated_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+/// The content of the tables of the sync DB
+#[derive(Default)]
+struct SyncedState {
+    swaps: HashMap<String, SwapInfo>,
+    swap_refunds: HashMap<String, Vec<String>>,
+    external_info: HashMap<String, ExternalInfo>,
+    reverse_swaps: HashMap<String, FullReverseSwapInfo>,
+    open_channel_payer_amounts: HashMap<String, u64>,
+    swaps_fees: HashMap<String, Timestamped<OpeningFeeParams>>,
+    payments_metadata: HashMap<String, Timestamped<PaymentMetadata>>,
+    payments_fiat_rates: HashMap<String, Vec<PaymentFiatRate>>,
+}
+
+#[derive(Default)]
+struct MemoryState {
+    // Local state
+    payments: HashMap<String, Payment>,
+    channels: HashMap<String, Channel>,
+    channel_lsps: HashMap<String, String>,
+    swaps_info: HashMap<String, SwapInfo>,
+    reverse_swaps_status: HashMap<String, ReverseSwapStatus>,
+    settings: HashMap<String, String>,
+    cached_items: HashMap<String, String>,
+    events: Vec<PersistedEvent>,
+    last_event_seq: u64,
+    liquidity_orders: HashMap<String, LiquidityOrder>,
+    sync_versions: Vec<(String, u64, Vec<u8>)>,
+
+    synced: SyncedState,
+    last_sync_request: u64,
+}
+
+/// A [Storage] that keeps everything in memory, for apps that don't need to keep the SDK state
+/// across restarts, e.g. because it is restored from the node and the remote backup.
+///
+/// The synced state is exchanged with the remote backup as an SQLite sync DB and merged with the
+/// same rules as the SQLite storage, so both storages can sync with each other.
+pub struct MemoryStorage {
+    state: Mutex<MemoryState>,
+    events_publisher: broadcast::Sender<HookEvent>,
+}
+
+impl Default for MemoryStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        let (events_publisher, _) = broadcast::channel::<HookEvent>(100);
+        Self {
+            state: Mutex::new(MemoryState::default()),
+            events_publisher,
+        }
+    }
+
+    fn state(&self) -> Result<MutexGuard<'_, MemoryState>> {
+        self.state
+            .lock()
+            .map_err(|_| anyhow!("Failed to lock the memory storage"))
+    }
+
+    /// Locks the state even if a thread panicked while holding the lock, which left it in an
+    /// unknown state. Used to reset the state, after which it can be locked again.
+    fn state_for_reset(&self) -> MutexGuard<'_, MemoryState> {
+        let state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.state.clear_poison();
+        state
+    }
+
+    /// Records a change of the synced state, like the triggers of the SQLite sync DB
+    fn add_sync_request(&self, state: &mut MemoryState) {
+        state.last_sync_request += 1;
+        _ = self.events_publisher.send(HookEvent::Insert {
+            table: "sync_requests".into(),
+        });
+    }
+
+    fn set_cached_item<T: serde::Serialize>(&self, key: &str, value: Option<&T>) -> Result<()> {
+        let mut state = self.state()?;
+        match value {
+            Some(value) => {
+                state
+                    .cached_items
+                    .insert(key.into(), serde_json::to_string(value)?);
+            }
+            None => {
+                state.cached_items.remove(key);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_cached_item<T: serde::de::DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        match self.state()?.cached_items.get(key) {
+            Some(value) => Ok(Some(serde_json::from_str(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn full_payment(state: &MemoryState, payment: &Payment) -> Payment {
+        let synced = &state.synced;
+        let mut payment = payment.clone();
+        payment.metadata = synced
+            .payments_metadata
+            .get(&payment.id)
+            .map(|m| m.value.clone());
+        payment.fiat_rates = synced
+            .payments_fiat_rates
+            .get(&payment.id)
+            .cloned()
+            .unwrap_or_default();
+        if let Some(payer_amount) = synced.open_channel_payer_amounts.get(&payment.id) {
+            payment.fee_msat = payer_amount - payment.amount_msat;
+        }
+        if let PaymentDetails::Ln { data } = &mut payment.details {
+            if let Some(info) = synced.external_info.get(&payment.id) {
+                data.lnurl_success_action = info.lnurl_success_action.clone();
+                data.lnurl_metadata = info.lnurl_metadata.clone();
+                data.ln_address = info.ln_address.clone();
+                data.lnurl_withdraw_endpoint = info.lnurl_withdraw_endpoint.clone();
+            }
+        }
+        payment
+    }
+
+    fn full_swap(state: &MemoryState, swap: &SwapInfo) -> SwapInfo {
+        let mut swap = match state.swaps_info.get(&swap.bitcoin_address) {
+            Some(info) => SwapInfo {
+                bitcoin_address: swap.bitcoin_address.clone(),
+                created_at: swap.created_at,
+                lock_height: swap.lock_height,
+                payment_hash: swap.payment_hash.clone(),
+                preimage: swap.preimage.clone(),
+                private_key: swap.private_key.clone(),
+                public_key: swap.public_key.clone(),
+                swapper_public_key: swap.swapper_public_key.clone(),
+                script: swap.script.clone(),
+                min_allowed_deposit: swap.min_allowed_deposit,
+                max_allowed_deposit: swap.max_allowed_deposit,
+                ..info.clone()
+            },
+            None => swap.clone(),
+        };
+        swap.refund_tx_ids = state
+            .synced
+            .swap_refunds
+            .get(&swap.bitcoin_address)
+            .cloned()
+            .unwrap_or_default();
+        swap.channel_opening_fees = state
+            .synced
+            .swaps_fees
+            .get(&swap.bitcoin_address)
+            .map(|fees| fees.value.clone());
+        swap
+    }
+
+    fn update_swap_info(&self, bitcoin_address: &str, f: impl FnOnce(&mut SwapInfo)) -> Result<()> {
+        let mut state = self.state()?;
+        let info = state
+            .swaps_info
+            .get_mut(bitcoin_address)
+            .ok_or(anyhow!("Swap {bitcoin_address} not found"))?;
+        f(info);
+        Ok(())
+    }
+
+    /// Writes the synced state to a new sync DB at the given path
+    fn write_sync_db(synced: &SyncedState, sync_db_path: &Path) -> Result<()> {
+        let path = sync_db_path
+            .to_str()
+            .ok_or(anyhow!("Invalid sync DB path"))?;
+        SqliteStorage::migrate_sync_db(path.to_string(), None)?;
+        let mut con = Connection::open(sync_db_path)?;
+        let tx = con.transaction()?;
+        for swap in synced.swaps.values() {
+            tx.execute(
+                "INSERT INTO swaps (bitcoin_address, created_at, lock_height, payment_hash,
+                  preimage, private_key, public_key, swapper_public_key, script,
+                  min_allowed_deposit, max_allowed_deposit)
+                 VALUES (:bitcoin_address, :created_at, :lock_height, :payment_hash, :preimage,
+                  :private_key, :public_key, :swapper_public_key, :script, :min_allowed_deposit,
+                  :max_allowed_deposit)",
+                named_params! {
+                    ":bitcoin_address": swap.bitcoin_address,
+                    ":created_at": swap.created_at,
+                    ":lock_height": swap.lock_height,
+                    ":payment_hash": swap.payment_hash,
+                    ":preimage": swap.preimage,
+                    ":private_key": swap.private_key,
+                    ":public_key": swap.public_key,
+                    ":swapper_public_key": swap.swapper_public_key,
+                    ":script": swap.script,
+                    ":min_allowed_deposit": swap.min_allowed_deposit,
+                    ":max_allowed_deposit": swap.max_allowed_deposit,
+                },
+            )?;
+        }
+        for (bitcoin_address, tx_ids) in &synced.swap_refunds {
+            for tx_id in tx_ids {
+                tx.execute(
+                    "INSERT INTO swap_refunds (bitcoin_address, refund_tx_id) VALUES (?1, ?2)",
+                    (bitcoin_address, tx_id),
+                )?;
+            }
+        }
+        for (payment_id, info) in &synced.external_info {
+            tx.execute(
+                "INSERT INTO payments_external_info (payment_id, lnurl_success_action,
+                  lnurl_metadata, ln_address, lnurl_withdraw_endpoint)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                (
+                    payment_id,
+                    &info.lnurl_success_action,
+                    &info.lnurl_metadata,
+                    &info.ln_address,
+                    &info.lnurl_withdraw_endpoint,
+                ),
+            )?;
+        }
+        for rsi in synced.reverse_swaps.values() {
+            tx.execute(
+                "INSERT INTO reverse_swaps (id, created_at_block_height, preimage, private_key,
+                  claim_pubkey, timeout_block_height, invoice, onchain_amount_sat, sat_per_vbyte,
+                  redeem_script)
+                 VALUES (:id, :created_at_block_height, :preimage, :private_key, :claim_pubkey,
+                  :timeout_block_height, :invoice, :onchain_amount_sat, :sat_per_vbyte,
+                  :redeem_script)",
+                named_params! {
+                    ":id": rsi.id,
+                    ":created_at_block_height": rsi.created_at_block_height,
+                    ":preimage": rsi.preimage,
+                    ":private_key": rsi.private_key,
+                    ":claim_pubkey": rsi.claim_pubkey,
+                    ":timeout_block_height": rsi.timeout_block_height,
+                    ":invoice": rsi.invoice,
+                    ":onchain_amount_sat": rsi.onchain_amount_sat,
+                    ":sat_per_vbyte": rsi.sat_per_vbyte,
+                    ":redeem_script": rsi.redeem_script,
+                },
+            )?;
+        }
+        for (payment_hash, payer_amount_msat) in &synced.open_channel_payer_amounts {
+            tx.execute(
+                "INSERT INTO open_channel_payment_info (payment_hash, payer_amount_msat)
+                 VALUES (?1, ?2)",
+                (payment_hash, payer_amount_msat),
+            )?;
+        }
+        for (bitcoin_address, fees) in &synced.swaps_fees {
+            tx.execute(
+                "INSERT INTO swaps_fees (bitcoin_address, created_at, channel_opening_fees)
+                 VALUES (?1, ?2, ?3)",
+                (bitcoin_address, &fees.updated_at, &fees.value),
+            )?;
+        }
+        for (payment_id, metadata) in &synced.payments_metadata {
+            tx.execute(
+                "INSERT INTO payments_metadata (payment_id, note, tags, updated_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                (
+                    payment_id,
+                    &metadata.value.note,
+                    serde_json::to_string(&metadata.value.tags)?,
+                    &metadata.updated_at,
+                ),
+            )?;
+        }
+        for (payment_id, rates) in &synced.payments_fiat_rates {
+            tx.execute(
+                "INSERT INTO payments_fiat_rates (payment_id, rates) VALUES (?1, ?2)",
+                (payment_id, serde_json::to_string(rates)?),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Reads the synced state from the sync DB at the given path
+    fn read_sync_db(sync_db_path: &Path) -> Result<SyncedState> {
+        let con = Connection::open(sync_db_path)?;
+        let mut synced = SyncedState::default();
+
+        let mut stmt = con.prepare(
+            "SELECT bitcoin_address, created_at, lock_height, payment_hash, preimage, private_key,
+              public_key, swapper_public_key, script, min_allowed_deposit, max_allowed_deposit
+             FROM swaps",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let swap = SwapInfo {
+                bitcoin_address: row.get(0)?,
+                created_at: row.get(1)?,
+                lock_height: row.get(2)?,
+                payment_hash: row.get(3)?,
+                preimage: row.get(4)?,
+                private_key: row.get(5)?,
+                public_key: row.get(6)?,
+                swapper_public_key: row.get(7)?,
+                script: row.get(8)?,
+                bolt11: None,
+                paid_sats: 0,
+                unconfirmed_sats: 0,
+                confirmed_sats: 0,
+                status: SwapStatus::Initial,
+                refund_tx_ids: vec![],
+                unconfirmed_tx_ids: vec![],
+                confirmed_tx_ids: vec![],
+                min_allowed_deposit: row.get(9)?,
+                max_allowed_deposit: row.get(10)?,
+                last_redeem_error: None,
+                channel_opening_fees: None,
+            };
+            synced.swaps.insert(swap.bitcoin_address.clone(), swap);
+        }
+
+        let mut stmt = con.prepare("SELECT bitcoin_address, refund_tx_id FROM swap_refunds")?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            synced
+                .swap_refunds
+                .entry(row.get(0)?)
+                .or_default()
+                .push(row.get(1)?);
+        }
+
+        let mut stmt = con.prepare(
+            "SELECT payment_id, lnurl_success_action, lnurl_metadata, ln_address,
+              lnurl_withdraw_endpoint
+             FROM payments_external_info",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            synced.external_info.insert(
+                row.get(0)?,
+                ExternalInfo {
+                    lnurl_success_action: row.get(1)?,
+                    lnurl_metadata: row.get(2)?,
+                    ln_address: row.get(3)?,
+                    lnurl_withdraw_endpoint: row.get(4)?,
+                },
+            );
+        }
+
+        let mut stmt = con.prepare(
+            "SELECT id, created_at_block_height, preimage, private_key, claim_pubkey,
+              timeout_block_height, invoice, onchain_amount_sat, sat_per_vbyte, redeem_script
+             FROM reverse_swaps",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let rsi = FullReverseSwapInfo {
+                id: row.get(0)?,
+                created_at_block_height: row.get(1)?,
+                preimage: row.get(2)?,
+                private_key: row.get(3)?,
+                claim_pubkey: row.get(4)?,
+                timeout_block_height: row.get(5)?,
+                invoice: row.get(6)?,
+                onchain_amount_sat: row.get(7)?,
+                sat_per_vbyte: row.get(8)?,
+                redeem_script: row.get(9)?,
+                cache: ReverseSwapInfoCached {
+                    status: ReverseSwapStatus::Initial,
+                },
+            };
+            synced.reverse_swaps.insert(rsi.id.clone(), rsi);
+        }
+
+        let mut stmt =
+            con.prepare("SELECT payment_hash, payer_amount_msat FROM open_channel_payment_info")?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            synced
+                .open_channel_payer_amounts
+                .insert(row.get(0)?, row.get(1)?);
+        }
+
+        let mut stmt = con
+            .prepare("SELECT bitcoin_address, created_at, channel_opening_fees FROM swaps_fees")?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            synced.swaps_fees.insert(
+                row.get(0)?,
+                Timestamped {
+                    value: row.get(2)?,
+                    updated_at: row.get(1)?,
+                },
+            );
+        }
+
+        let mut stmt =
+            con.prepare("SELECT payment_id, note, tags, updated_at FROM payments_metadata")?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let tags: String = row.get(2)?;
+            synced.payments_metadata.insert(
+                row.get(0)?,
+                Timestamped {
+                    value: PaymentMetadata {
+                        note: row.get(1)?,
+                        tags: serde_json::from_str(&tags)?,
+                    },
+                    updated_at: row.get(3)?,
+                },
+            );
+        }
+
+        let mut stmt = con.prepare("SELECT payment_id, rates FROM payments_fiat_rates")?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let rates: String = row.get(1)?;
+            synced
+                .payments_fiat_rates
+                .insert(row.get(0)?, serde_json::from_str(&rates)?);
+        }
+
+        Ok(synced)
+    }
+
+    /// Replaces the synced state. With `to_local`, the local state of the swaps and reverse
+    /// swaps that are new to it is added, as they become ones of this app.
+    fn set_synced_state(state: &mut MemoryState, synced: SyncedState, to_local: bool) {
+        if to_local {
+            for swap in synced.swaps.values() {
+                state
+                    .swaps_info
+                    .entry(swap.bitcoin_address.clone())
+                    .or_insert_with(|| swap.clone());
+            }
+            for id in synced.reverse_swaps.keys() {
+                state
+                    .reverse_swaps_status
+                    .entry(id.clone())
+                    .or_insert(ReverseSwapStatus::Initial);
+            }
+        }
+        state.synced = synced;
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn init(&self) -> SdkResult<()> {
+        Ok(())
+    }
+
+    fn subscribe_hooks(&self) -> broadcast::Receiver<HookEvent> {
+        self.events_publisher.subscribe()
+    }
+
+    fn insert_or_update_payments(&self, transactions: &[Payment]) -> SdkResult<()> {
+        let mut state = self.state()?;
+        state.payments.retain(|_, p| {
+            p.payment_type != PaymentType::Sent || p.status != PaymentStatus::Pending
+        });
+        for payment in transactions {
+            state.payments.insert(payment.id.clone(), payment.clone());
+        }
+        Ok(())
+    }
+
+    fn insert_lnurl_payment_external_info(
+        &self,
+        payment_hash: &str,
+        lnurl_pay_success_action: Option<&SuccessActionProcessed>,
+        lnurl_metadata: Option<String>,
+        ln_address: Option<String>,
+        lnurl_withdraw_endpoint: Option<String>,
+    ) -> SdkResult<()> {
+        let mut state = self.state()?;
+        state.synced.external_info.insert(
+            payment_hash.into(),
+            ExternalInfo {
+                lnurl_success_action: lnurl_pay_success_action.cloned(),
+                lnurl_metadata,
+                ln_address,
+                lnurl_withdraw_endpoint,
+            },
+        );
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn insert_open_channel_payment_info(
+        &self,
+        payment_hash: &str,
+        payer_amount_msat: u64,
+    ) -> Result<()> {
+        let mut state = self.state()?;
+        state
+            .synced
+            .open_channel_payer_amounts
+            .insert(payment_hash.into(), payer_amount_msat);
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn set_payment_metadata(
+        &self,
+        payment_hash: &str,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<()> {
+        let mut state = self.state()?;
+        state.synced.payments_metadata.insert(
+            payment_hash.into(),
+            Timestamped::now(PaymentMetadata { note, tags }),
+        );
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn insert_payment_fiat_rates(
+        &self,
+        payment_hash: &str,
+        rates: &[PaymentFiatRate],
+        replace: bool,
+    ) -> Result<()> {
+        let mut state = self.state()?;
+        let recorded = state
+            .synced
+            .payments_fiat_rates
+            .entry(payment_hash.into())
+            .or_default();
+        merge_payment_fiat_rates(recorded, rates, replace);
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn last_payment_timestamp(&self) -> Result<u64> {
+        Ok(self
+            .state()?
+            .payments
+            .values()
+            .filter(|p| p.status != PaymentStatus::Pending)
+            .map(|p| p.payment_time as u64)
+            .max()
+            .unwrap_or_default())
+    }
+
+    fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
+        let state = self.state()?;
+        let search = req.search.map(|s| s.to_lowercase());
+        let mut payments: Vec<Payment> = state
+            .payments
+            .values()
+            .map(|p| Self::full_payment(&state, p))
+            .filter(|p| {
+                let type_ok = req.filters.as_ref().is_none_or(|filters| {
+                    filters.iter().any(|f| {
+                        matches!(
+                            (f, &p.payment_type),
+                            (PaymentTypeFilter::Sent, PaymentType::Sent)
+                                | (PaymentTypeFilter::Received, PaymentType::Received)
+                                | (
+                                    PaymentTypeFilter::ClosedChannels,
+                                    PaymentType::ClosedChannel
+                                )
+                        )
+                    })
+                });
+                let search_ok = search.as_ref().is_none_or(|s| {
+                    let mut texts = vec![p.id.clone()];
+                    texts.extend(p.description.clone());
+                    if let PaymentDetails::Ln { data } = &p.details {
+                        texts.extend(data.lnurl_metadata.clone());
+                        texts.extend(data.ln_address.clone());
+                    }
+                    texts.iter().any(|t| t.to_lowercase().contains(s))
+                });
+                let tags_ok = req.tags.as_ref().is_none_or(|tags| {
+                    p.metadata
+                        .as_ref()
+                        .is_some_and(|m| m.tags.iter().any(|t| tags.contains(t)))
+                });
+                type_ok
+                    && search_ok
+                    && tags_ok
+                    && (req.include_failures.unwrap_or_default()
+                        || p.status != PaymentStatus::Failed)
+                    && req.from_timestamp.is_none_or(|t| p.payment_time >= t)
+                    && req.to_timestamp.is_none_or(|t| p.payment_time <= t)
+                    && req.min_amount_msat.is_none_or(|a| p.amount_msat >= a)
+                    && req.max_amount_msat.is_none_or(|a| p.amount_msat <= a)
+                    && req
+                        .cursor
+                        .as_ref()
+                        .is_none_or(|c| (p.payment_time, &p.id) < (c.payment_time, &c.id))
+            })
+            .collect();
+        payments.sort_by(|p1, p2| (p2.payment_time, &p2.id).cmp(&(p1.payment_time, &p1.id)));
+        Ok(payments
+            .into_iter()
+            .skip(req.offset.unwrap_or_default() as usize)
+            .take(req.limit.map_or(usize::MAX, |l| l as usize))
+            .collect())
+    }
+
+    fn get_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>> {
+        let state = self.state()?;
+        Ok(state
+            .payments
+            .get(hash)
+            .map(|p| Self::full_payment(&state, p)))
+    }
+
+    fn get_completed_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>> {
+        Ok(self
+            .get_payment_by_hash(hash)?
+            .filter(|p| p.status == PaymentStatus::Complete))
+    }
+
+    fn update_channels(&self, fetched_channels: &[Channel]) -> Result<()> {
+        let mut state = self.state()?;
+        for channel in fetched_channels {
+            let mut channel = channel.clone();
+            if let Some(persisted) = state.channels.get(&channel.funding_txid) {
+                channel.closed_at = persisted.closed_at;
+                channel.closing_txid = persisted.closing_txid.clone();
+            }
+            state.channels.insert(channel.funding_txid.clone(), channel);
+        }
+        for channel in state.channels.values_mut() {
+            if !fetched_channels
+                .iter()
+                .any(|c| c.funding_txid == channel.funding_txid)
+            {
+                channel.state = ChannelState::Closed;
+            }
+        }
+        Ok(())
+    }
+
+    fn list_channels(&self) -> Result<Vec<Channel>> {
+        Ok(self.state()?.channels.values().cloned().collect())
+    }
+
+    fn insert_or_update_channel(&self, c: Channel) -> Result<()> {
+        self.state()?.channels.insert(c.funding_txid.clone(), c);
+        Ok(())
+    }
+
+    fn set_channel_lsp(&self, funding_txid: &str, lsp_id: &str) -> Result<()> {
+        self.state()?
+            .channel_lsps
+            .insert(funding_txid.into(), lsp_id.into());
+        Ok(())
+    }
+
+    fn list_channel_lsps(&self) -> Result<HashMap<String, String>> {
+        Ok(self.state()?.channel_lsps.clone())
+    }
+
+    fn insert_swap(&self, swap_info: SwapInfo) -> Result<()> {
+        let mut state = self.state()?;
+        if let Some(fees) = swap_info.channel_opening_fees.clone() {
+            state
+                .synced
+                .swaps_fees
+                .insert(swap_info.bitcoin_address.clone(), Timestamped::now(fees));
+        }
+        state
+            .swaps_info
+            .insert(swap_info.bitcoin_address.clone(), swap_info.clone());
+        state
+            .synced
+            .swaps
+            .insert(swap_info.bitcoin_address.clone(), swap_info);
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn update_swap_paid_amount(&self, bitcoin_address: String, paid_sats: u32) -> Result<()> {
+        self.update_swap_info(&bitcoin_address, |s| s.paid_sats = paid_sats as u64)
+    }
+
+    fn update_swap_redeem_error(&self, bitcoin_address: String, redeem_err: String) -> Result<()> {
+        self.update_swap_info(&bitcoin_address, |s| s.last_redeem_error = Some(redeem_err))
+    }
+
+    fn update_swap_bolt11(&self, bitcoin_address: String, bolt11: String) -> Result<()> {
+        self.update_swap_info(&bitcoin_address, |s| s.bolt11 = Some(bolt11))
+    }
+
+    fn update_swap_fees(
+        &self,
+        bitcoin_address: String,
+        channel_opening_fees: OpeningFeeParams,
+    ) -> Result<()> {
+        let mut state = self.state()?;
+        state
+            .synced
+            .swaps_fees
+            .insert(bitcoin_address, Timestamped::now(channel_opening_fees));
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn insert_swap_refund_tx_ids(
+        &self,
+        bitcoin_address: String,
+        refund_tx_id: String,
+    ) -> Result<()> {
+        let mut state = self.state()?;
+        state
+            .synced
+            .swap_refunds
+            .entry(bitcoin_address)
+            .or_default()
+            .push(refund_tx_id);
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn update_swap_chain_info(
+        &self,
+        bitcoin_address: String,
+        unconfirmed_sats: u64,
+        unconfirmed_tx_ids: Vec<String>,
+        confirmed_sats: u64,
+        confirmed_tx_ids: Vec<String>,
+        status: SwapStatus,
+    ) -> Result<SwapInfo> {
+        self.update_swap_info(&bitcoin_address, |s| {
+            s.unconfirmed_sats = unconfirmed_sats;
+            s.unconfirmed_tx_ids = unconfirmed_tx_ids;
+            s.confirmed_sats = confirmed_sats;
+            s.confirmed_tx_ids = confirmed_tx_ids;
+            s.status = status;
+        })?;
+        self.get_swap_info_by_address(bitcoin_address.clone())?
+            .ok_or(anyhow!("Swap {bitcoin_address} not found"))
+    }
+
+    fn get_swap_info_by_hash(&self, hash: &[u8]) -> Result<Option<SwapInfo>> {
+        let state = self.state()?;
+        Ok(state
+            .synced
+            .swaps
+            .values()
+            .find(|s| s.payment_hash == hash)
+            .map(|s| Self::full_swap(&state, s)))
+    }
+
+    fn get_swap_info_by_address(&self, address: String) -> Result<Option<SwapInfo>> {
+        let state = self.state()?;
+        Ok(state
+            .synced
+            .swaps
+            .get(&address)
+            .map(|s| Self::full_swap(&state, s)))
+    }
+
+    fn list_swaps_with_status(&self, status: SwapStatus) -> Result<Vec<SwapInfo>> {
+        Ok(self
+            .list_swaps()?
+            .into_iter()
+            .filter(|s| s.status == status)
+            .collect())
+    }
+
+    fn list_swaps(&self) -> Result<Vec<SwapInfo>> {
+        let state = self.state()?;
+        Ok(state
+            .synced
+            .swaps
+            .values()
+            .map(|s| Self::full_swap(&state, s))
+            .collect())
+    }
+
+    fn insert_reverse_swap(&self, rsi: &FullReverseSwapInfo) -> Result<()> {
+        let mut state = self.state()?;
+        state
+            .reverse_swaps_status
+            .insert(rsi.id.clone(), rsi.cache.status);
+        state
+            .synced
+            .reverse_swaps
+            .insert(rsi.id.clone(), rsi.clone());
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    fn update_reverse_swap_status(&self, id: &str, status: &ReverseSwapStatus) -> Result<()> {
+        self.state()?
+            .reverse_swaps_status
+            .insert(id.into(), *status);
+        Ok(())
+    }
+
+    fn list_reverse_swaps(&self) -> Result<Vec<FullReverseSwapInfo>> {
+        let state = self.state()?;
+        Ok(state
+            .synced
+            .reverse_swaps
+            .values()
+            .map(|rsi| {
+                let mut rsi = rsi.clone();
+                if let Some(status) = state.reverse_swaps_status.get(&rsi.id) {
+                    rsi.cache.status = *status;
+                }
+                rsi
+            })
+            .collect())
+    }
+
+    fn set_lsp_id(&self, lsp_id: String) -> SdkResult<()> {
+        self.state()?.settings.insert("lsp".into(), lsp_id);
+        Ok(())
+    }
+
+    fn get_lsp_id(&self) -> SdkResult<Option<String>> {
+        Ok(self.state()?.settings.get("lsp").cloned())
+    }
+
+    fn set_custom_lsps(&self, lsps: &[LspInformation]) -> SdkResult<()> {
+        let lsps = serde_json::to_string(lsps).map_err(anyhow::Error::new)?;
+        self.state()?.settings.insert("custom_lsps".into(), lsps);
+        Ok(())
+    }
+
+    fn get_custom_lsps(&self) -> SdkResult<Vec<LspInformation>> {
+        match self.state()?.settings.get("custom_lsps") {
+            Some(lsps) => Ok(serde_json::from_str(lsps).map_err(anyhow::Error::new)?),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn set_node_state(&self, state: &NodeState) -> Result<()> {
+        self.set_cached_item("node_state", Some(state))
+    }
+
+    fn get_node_state(&self) -> SdkResult<Option<NodeState>> {
+        Ok(self.get_cached_item("node_state")?)
+    }
+
+    fn set_last_backup_time(&self, t: u64) -> Result<()> {
+        self.set_cached_item("last_backup_time", Some(&t))
+    }
+
+    fn get_last_backup_time(&self) -> Result<Option<u64>> {
+        self.get_cached_item("last_backup_time")
+    }
+
+    fn set_gl_credentials(&self, creds: Vec<u8>) -> Result<()> {
+        self.set_cached_item("gl_credentials", Some(&creds))
+    }
+
+    fn get_gl_credentials(&self) -> Result<Option<Vec<u8>>> {
+        self.get_cached_item("gl_credentials")
+    }
+
+    fn set_backup_passphrase_keyring(&self, keyring: Option<Vec<u8>>) -> Result<()> {
+        self.set_cached_item("backup_passphrase_keyring", keyring.as_ref())
+    }
+
+    fn get_backup_passphrase_keyring(&self) -> Result<Option<Vec<u8>>> {
+        self.get_cached_item("backup_passphrase_keyring")
+    }
+
+    fn set_static_backup(&self, backup: Vec<String>) -> Result<()> {
+        self.set_cached_item("static_backup", Some(&backup))
+    }
+
+    fn get_static_backup(&self) -> Result<Option<Vec<String>>> {
+        self.get_cached_item("static_backup")
+    }
+
+    fn set_fiat_rates(&self, rates: &FiatRates) -> Result<()> {
+        self.set_cached_item("fiat_rates", Some(rates))
+    }
+
+    fn get_fiat_rates(&self) -> Result<Option<FiatRates>> {
+        self.get_cached_item("fiat_rates")
+    }
+
+    fn set_webhook_url(&self, webhook_url: String) -> Result<()> {
+        self.set_cached_item("webhook_url", Some(&webhook_url))
+    }
+
+    fn get_webhook_url(&self) -> Result<Option<String>> {
+        self.get_cached_item("webhook_url")
+    }
+
+    fn set_chain_notifier_registration_id(&self, registration_id: String) -> Result<()> {
+        self.set_cached_item("chain_notifier_registration_id", Some(&registration_id))
+    }
+
+    fn get_chain_notifier_registration_id(&self) -> Result<Option<String>> {
+        self.get_cached_item("chain_notifier_registration_id")
+    }
+
+    fn insert_event(&self, event: &BreezEvent, created_at: u64, retention: u32) -> Result<u64> {
+        let mut state = self.state()?;
+        state.last_event_seq += 1;
+        let seq = state.last_event_seq;
+        state.events.push(PersistedEvent {
+            seq,
+            created_at,
+            event: event.clone(),
+        });
+        state.events.retain(|e| e.seq + retention as u64 > seq);
+        Ok(seq)
+    }
+
+    fn list_events_since(&self, seq: u64) -> Result<Vec<PersistedEvent>> {
+        Ok(self
+            .state()?
+            .events
+            .iter()
+            .filter(|e| e.seq > seq)
+            .cloned()
+            .collect())
+    }
+
+    fn insert_or_update_liquidity_order(&self, order: &LiquidityOrder) -> Result<()> {
+        self.state()?
+            .liquidity_orders
+            .insert(order.order_id.clone(), order.clone());
+        Ok(())
+    }
+
+    fn get_liquidity_order(&self, order_id: &str) -> Result<Option<LiquidityOrder>> {
+        Ok(self.state()?.liquidity_orders.get(order_id).cloned())
+    }
+
+    fn list_liquidity_orders(&self) -> Result<Vec<LiquidityOrder>> {
+        Ok(self.state()?.liquidity_orders.values().cloned().collect())
+    }
+
+    fn backup(&self, dst_path: &Path) -> Result<()> {
+        Self::write_sync_db(&self.state()?.synced, dst_path)
+    }
+
+    fn get_last_sync_version(&self) -> Result<Option<u64>> {
+        Ok(self.state()?.sync_versions.iter().map(|(_, v, _)| *v).max())
+    }
+
+    fn set_last_sync_version(&self, last_version: u64, data: &[u8]) -> Result<()> {
+        let mut state = self.state()?;
+        state.sync_versions.retain(|(_, v, _)| *v != last_version);
+        state
+            .sync_versions
+            .push((Utc::now().to_rfc3339(), last_version, data.to_vec()));
+        Ok(())
+    }
+
+    fn sync_versions_history(&self) -> Result<Vec<SyncVersion>> {
+        Ok(self
+            .state()?
+            .sync_versions
+            .iter()
+            .rev()
+            .map(|(created_at, last_version, data)| SyncVersion {
+                created_at: created_at.clone(),
+                last_version: *last_version,
+                data: data.clone(),
+            })
+            .collect())
+    }
+
+    fn get_sync_version(&self, version: u64) -> Result<Option<SyncVersion>> {
+        Ok(self
+            .sync_versions_history()?
+            .into_iter()
+            .find(|v| v.last_version == version))
+    }
+
+    fn get_last_sync_request(&self) -> Result<Option<u64>> {
+        let last = self.state()?.last_sync_request;
+        Ok((last > 0).then_some(last))
+    }
+
+    fn delete_sync_requests_up_to(&self, _request_id: u64) -> Result<()> {
+        // The requests are only counted, the last id is kept so that new ones are still higher
+        Ok(())
+    }
+
+    fn import_remote_changes(&self, sync_db_path: &Path, to_local: bool) -> Result<()> {
+        let remote_path = sync_db_path
+            .to_str()
+            .ok_or(anyhow!("Invalid sync DB path"))?;
+        if let Err(e) = SqliteStorage::migrate_sync_db(remote_path.to_string(), None) {
+            error!("Failed to migrate sync db, probably local db is older than remote, skipping migration: {e}");
+        }
+
+        // The local synced state is written to a sync DB, to merge the remote one into it with
+        // the same rules as the SQLite storage
+        let mut state = self.state()?;
+        let local_file = NamedTempFile::new_in(sync_db_path.parent().unwrap_or(Path::new(".")))?;
+        Self::write_sync_db(&state.synced, local_file.path())?;
+        let local_path = local_file
+            .path()
+            .to_str()
+            .ok_or(anyhow!("Invalid sync DB path"))?;
+        let before = SqliteStorage::sync_db_summary(local_path.to_string())?.tables;
+
+        let mut con = Connection::open_in_memory()?;
+        con.execute("ATTACH DATABASE ?1 AS sync", [local_path])?;
+        con.execute("ATTACH DATABASE ?1 AS remote_sync", [remote_path])?;
+        let tx = con.transaction()?;
+        SqliteStorage::import_remote_tables(&tx, false)?;
+        tx.commit()?;
+        drop(con);
+
+        if SqliteStorage::sync_db_summary(local_path.to_string())?.tables != before {
+            let synced = Self::read_sync_db(local_file.path())?;
+            Self::set_synced_state(&mut state, synced, to_local);
+            self.add_sync_request(&mut state);
+        }
+        Ok(())
+    }
+
+    fn restore_remote_changes(&self, sync_db_path: &Path) -> Result<()> {
+        let path = sync_db_path
+            .to_str()
+            .ok_or(anyhow!("Invalid sync DB path"))?;
+        if let Err(e) = SqliteStorage::migrate_sync_db(path.to_string(), None) {
+            error!("Failed to migrate sync db, probably local db is older than remote, skipping migration: {e}");
+        }
+        let synced = Self::read_sync_db(sync_db_path)?;
+        let mut state = self.state()?;
+        Self::set_synced_state(&mut state, synced, true);
+        self.add_sync_request(&mut state);
+        Ok(())
+    }
+
+    /// The state can only be inconsistent if a thread panicked while changing it, which
+    /// poisons its lock. A reset clears it.
+    fn check_integrity(&self) -> DbIntegrity {
+        let ok = !self.state.is_poisoned();
+        DbIntegrity {
+            main_ok: ok,
+            sync_ok: ok,
+        }
+    }
+
+    fn delete_failed_payments_before(&self, timestamp: i64) -> Result<u32> {
+        let mut state = self.state()?;
+        let before = state.payments.len();
+        state
+            .payments
+            .retain(|_, p| p.status != PaymentStatus::Failed || p.payment_time >= timestamp);
+        Ok((before - state.payments.len()) as u32)
+    }
+
+    fn prune_sync_versions(&self, keep: u32) -> Result<u32> {
+        let mut state = self.state()?;
+        let pruned = state.sync_versions.len().saturating_sub(keep as usize);
+        state.sync_versions.drain(..pruned);
+        Ok(pruned as u32)
+    }
+
+    fn delete_unused_cached_items(&self) -> Result<u32> {
+        let mut state = self.state()?;
+        let deleted = OBSOLETE_CACHED_ITEM_KEYS
+            .iter()
+            .filter(|key| state.cached_items.remove(**key).is_some())
+            .count();
+        Ok(deleted as u32)
+    }
+
+    fn vacuum(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn reset_main_db(&self) -> Result<()> {
+        let mut state = self.state_for_reset();
+        state.payments.clear();
+        state.channels.clear();
+        state.channel_lsps.clear();
+        state.swaps_info.clear();
+        state.reverse_swaps_status.clear();
+        state.settings.clear();
+        state.cached_items.clear();
+        state.liquidity_orders.clear();
+        state.sync_versions.clear();
+        Ok(())
+    }
+
+    fn reset_sync_db(&self) -> Result<()> {
+        self.state_for_reset().synced = SyncedState::default();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use super::MemoryStorage;
+    use crate::fiat::PaymentFiatRate;
+    use crate::persist::{db::SqliteStorage, storage::Storage, test_utils};
+    use crate::test_utils::{create_test_config, create_test_persister, get_test_ofp_48h};
+    use crate::{FullReverseSwapInfo, ReverseSwapInfoCached, ReverseSwapStatus};
+    use crate::{SwapInfo, SwapStatus};
+
+    fn test_swap(bitcoin_address: &str) -> SwapInfo {
+        // The swap keys and script are unique per swap
+        let unique_bytes = |prefix: u8| [&[prefix], bitcoin_address.as_bytes()].concat();
+        SwapInfo {
+            bitcoin_address: bitcoin_address.into(),
+            created_at: 0,
+            lock_height: 100,
+            payment_hash: unique_bytes(1),
+            preimage: unique_bytes(2),
+            private_key: unique_bytes(3),
+            public_key: unique_bytes(4),
+            swapper_public_key: unique_bytes(5),
+            script: unique_bytes(6),
+            bolt11: None,
+            paid_sats: 0,
+            unconfirmed_sats: 0,
+            confirmed_sats: 0,
+            status: SwapStatus::Initial,
+            refund_tx_ids: Vec::new(),
+            unconfirmed_tx_ids: Vec::new(),
+            confirmed_tx_ids: Vec::new(),
+            min_allowed_deposit: 0,
+            max_allowed_deposit: 100,
+            last_redeem_error: None,
+            channel_opening_fees: Some(get_test_ofp_48h(1, 1).into()),
+        }
+    }
+
+    /// Summarizes the synced tables of the storage, as written to the backup
+    fn sync_summary(storage: &dyn Storage) -> Result<Vec<(String, u64, String)>> {
+        let path = format!("{}/sync.sql", test_utils::create_test_sql_dir());
+        storage.backup(Path::new(&path))?;
+        Ok(SqliteStorage::sync_db_summary(path)?
+            .tables
+            .into_iter()
+            .map(|t| (t.table, t.rows, t.hash))
+            .collect())
+    }
+
+    // Test that the memory storage and the SQLite storage exchange all their synced tables
+    #[test]
+    fn test_sync_with_sqlite_storage() -> Result<()> {
+        let memory_storage = MemoryStorage::new();
+        memory_storage.insert_swap(test_swap("1"))?;
+        memory_storage.insert_swap_refund_tx_ids("1".into(), "refund".into())?;
+        memory_storage.insert_lnurl_payment_external_info(
+            "123",
+            None,
+            Some("metadata".into()),
+            Some("user@domain.com".into()),
+            None,
+        )?;
+        memory_storage.insert_reverse_swap(&FullReverseSwapInfo {
+            id: "rev".into(),
+            created_at_block_height: 10,
+            preimage: vec![6],
+            private_key: vec![7],
+            claim_pubkey: "claim".into(),
+            timeout_block_height: 20,
+            invoice: "invoice".into(),
+            redeem_script: "script".into(),
+            onchain_amount_sat: 1000,
+            sat_per_vbyte: 5,
+            cache: ReverseSwapInfoCached {
+                status: ReverseSwapStatus::Initial,
+            },
+        })?;
+        memory_storage.insert_open_channel_payment_info("123", 2000)?;
+        memory_storage.set_payment_metadata("123", Some("note".into()), vec!["tag".into()])?;
+        memory_storage.insert_payment_fiat_rates(
+            "123",
+            &[PaymentFiatRate {
+                coin: "USD".into(),
+                value_micros: 20_000_000_000,
+            }],
+            false,
+        )?;
+        assert_eq!(memory_storage.get_last_sync_request()?, Some(7));
+
+        // No synced table is dropped on the way to the SQLite storage
+        let sqlite_storage = create_test_persister(create_test_config());
+        sqlite_storage.init()?;
+        let memory_sync_db = format!("{}/memory_sync.sql", test_utils::create_test_sql_dir());
+        memory_storage.backup(Path::new(&memory_sync_db))?;
+        Storage::import_remote_changes(&sqlite_storage, Path::new(&memory_sync_db), true)?;
+        let memory_summary = sync_summary(&memory_storage)?;
+        assert!(memory_summary.iter().all(|(_, rows, _)| *rows > 0));
+        assert_eq!(sync_summary(&sqlite_storage)?, memory_summary);
+        let swap = sqlite_storage
+            .get_swap_info_by_address("1".into())?
+            .unwrap();
+        assert_eq!(swap.refund_tx_ids, vec!["refund".to_string()]);
+        assert_eq!(Storage::list_reverse_swaps(&sqlite_storage)?.len(), 1);
+
+        // Nor on the way back, with the rows changed on the SQLite storage merged
+        sqlite_storage.insert_swap(test_swap("2"))?;
+        sqlite_storage.set_payment_metadata("123", Some("newer note".into()), vec![])?;
+        sqlite_storage.get_connection()?.execute(
+            "UPDATE sync.payments_metadata SET updated_at = '2999-01-01 00:00:00'",
+            [],
+        )?;
+        let sqlite_sync_db = format!("{}/sqlite_sync.sql", test_utils::create_test_sql_dir());
+        Storage::backup(&sqlite_storage, Path::new(&sqlite_sync_db))?;
+        memory_storage.import_remote_changes(Path::new(&sqlite_sync_db), true)?;
+        assert_eq!(
+            sync_summary(&memory_storage)?,
+            sync_summary(&sqlite_storage)?
+        );
+        let mut addresses: Vec<String> = memory_storage
+            .list_swaps()?
+            .into_iter()
+            .map(|s| s.bitcoin_address)
+            .collect();
+        addresses.sort();
+        assert_eq!(addresses, vec!["1".to_string(), "2".to_string()]);
+        // The merge is a change of the synced state to push, unlike a merge without changes
+        assert_eq!(memory_storage.get_last_sync_request()?, Some(8));
+        memory_storage.import_remote_changes(Path::new(&sqlite_sync_db), true)?;
+        assert_eq!(memory_storage.get_last_sync_request()?, Some(8));
+
+        memory_storage.restore_remote_changes(Path::new(&memory_sync_db))?;
+        assert_eq!(memory_storage.list_swaps()?.len(), 1);
+        assert_eq!(sync_summary(&memory_storage)?, memory_summary);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_integrity() -> Result<()> {
+        let storage = MemoryStorage::new();
+        storage.insert_swap(test_swap("1"))?;
+        assert!(storage.check_integrity().main_ok);
+
+        // A panic while changing the state leaves it inconsistent
+        _ = catch_unwind(AssertUnwindSafe(|| {
+            let _state = storage.state.lock().unwrap();
+            panic!("Failed while changing the state");
+        }));
+        let integrity = storage.check_integrity();
+        assert!(!integrity.main_ok && !integrity.sync_ok);
+        assert!(storage.list_swaps().is_err());
+
+        storage.reset_main_db()?;
+        storage.reset_sync_db()?;
+        let integrity = storage.check_integrity();
+        assert!(integrity.main_ok && integrity.sync_ok);
+        assert!(storage.list_swaps()?.is_empty());
+        Ok(())
+    }
+}
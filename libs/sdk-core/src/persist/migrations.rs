@@ -415,7 +415,52 @@ pub(crate) fn current_migrations() -> Vec<&'static str> {
        "SELECT 1;", // Placeholder statement, to avoid that column is added twice (from sync fn below and here)
        "ALTER TABLE channels ADD COLUMN alias_local TEXT;",
        "ALTER TABLE channels ADD COLUMN alias_remote TEXT;",
-       "ALTER TABLE channels ADD COLUMN closing_txid TEXT;"
+       "ALTER TABLE channels ADD COLUMN closing_txid TEXT;",
+       "
+       CREATE VIRTUAL TABLE IF NOT EXISTS payments_fts USING fts5(
+        id UNINDEXED,
+        payment_hash,
+        description,
+        lnurl_metadata,
+        ln_address
+       );
+
+       INSERT INTO payments_fts (id, payment_hash, description, lnurl_metadata, ln_address)
+        SELECT
+         p.id,
+         json_extract(p.details, '$.payment_hash'),
+         p.description,
+         e.lnurl_metadata,
+         e.ln_address
+        FROM payments p
+        LEFT JOIN sync.payments_external_info e
+         ON p.id = e.payment_id;
+
+       CREATE TRIGGER IF NOT EXISTS payments_fts_delete
+        AFTER DELETE ON payments
+       BEGIN
+        DELETE FROM payments_fts WHERE id = old.id;
+       END;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS events (
+        seq INTEGER PRIMARY KEY AUTOINCREMENT,
+        created_at INTEGER NOT NULL,
+        data TEXT NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS channel_lsps (
+        funding_txid TEXT NOT NULL PRIMARY KEY,
+        lsp_id TEXT NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS liquidity_orders (
+        order_id TEXT NOT NULL PRIMARY KEY,
+        data TEXT NOT NULL
+       ) STRICT;
+       ",
     ]
 }
 
@@ -530,5 +575,31 @@ pub(crate) fn current_sync_migrations() -> Vec<&'static str> {
          INSERT INTO sync_requests(changed_table) VALUES('payments_external_info');
         END;
         ",
+        "
+        CREATE TABLE IF NOT EXISTS payments_metadata (
+         payment_id TEXT NOT NULL PRIMARY KEY,
+         note TEXT,
+         tags TEXT NOT NULL,
+         updated_at TEXT NOT NULL
+        ) STRICT;
+
+        CREATE TRIGGER IF NOT EXISTS sync_requests_payments_metadata
+         AFTER INSERT ON payments_metadata
+        BEGIN
+         INSERT INTO sync_requests(changed_table) VALUES('payments_metadata');
+        END;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS payments_fiat_rates (
+         payment_id TEXT NOT NULL PRIMARY KEY,
+         rates TEXT NOT NULL
+        ) STRICT;
+
+        CREATE TRIGGER IF NOT EXISTS sync_requests_payments_fiat_rates
+         AFTER INSERT ON payments_fiat_rates
+        BEGIN
+         INSERT INTO sync_requests(changed_table) VALUES('payments_fiat_rates');
+        END;
+        ",
     ]
 }
@@ -1,9 +1,14 @@
 pub(crate) mod cache;
 pub(crate) mod channels;
 pub(crate) mod db;
+pub(crate) mod events;
+pub(crate) mod liquidity;
+pub(crate) mod maintenance;
+pub(crate) mod memory;
 pub(crate) mod migrations;
 pub(crate) mod reverseswap;
 pub(crate) mod settings;
+pub(crate) mod storage;
 pub(crate) mod swap;
 pub(crate) mod sync;
 pub(crate) mod transactions;
@@ -1,5 +1,6 @@
 use super::db::SqliteStorage;
-use crate::error::SdkResult;
+use crate::error::{SdkError, SdkResult};
+use crate::lsp::LspInformation;
 use anyhow::Result;
 
 #[allow(dead_code)]
@@ -57,6 +58,24 @@ impl SqliteStorage {
     pub fn get_lsp_id(&self) -> SdkResult<Option<String>> {
         self.get_setting("lsp".to_string())
     }
+
+    pub fn set_custom_lsps(&self, lsps: &[LspInformation]) -> SdkResult<()> {
+        let serialized = serde_json::to_string(lsps).map_err(|e| SdkError::PersistenceFailure {
+            err: format!("Failed to serialize custom LSPs: {e}"),
+        })?;
+        self.update_setting("custom_lsps".to_string(), serialized)
+    }
+
+    pub fn get_custom_lsps(&self) -> SdkResult<Vec<LspInformation>> {
+        match self.get_setting("custom_lsps".to_string())? {
+            Some(str) => {
+                serde_json::from_str(str.as_str()).map_err(|e| SdkError::PersistenceFailure {
+                    err: format!("Failed to deserialize custom LSPs: {e}"),
+                })
+            }
+            None => Ok(vec![]),
+        }
+    }
 }
 
 #[test]
@@ -88,3 +107,36 @@ fn test_settings() {
     assert_eq!(settings[1].key, "key2");
     assert_eq!(settings[1].value, "val3");
 }
+
+#[test]
+fn test_custom_lsps() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert!(storage.get_custom_lsps().unwrap().is_empty());
+
+    use crate::models::OpeningFeeParamsMenu;
+
+    let lsp = LspInformation {
+        id: "lsp-id".to_string(),
+        name: "lsp.example.com".to_string(),
+        widget_url: String::new(),
+        pubkey: "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".to_string(),
+        host: "lsp.example.com:9735".to_string(),
+        channel_capacity: 0,
+        target_conf: 0,
+        base_fee_msat: 1000,
+        fee_rate: 0.000001,
+        time_lock_delta: 144,
+        min_htlc_msat: 1000,
+        lsp_pubkey: vec![],
+        opening_fee_params_list: OpeningFeeParamsMenu { values: vec![] },
+    };
+    storage.set_custom_lsps(std::slice::from_ref(&lsp)).unwrap();
+    let lsps = storage.get_custom_lsps().unwrap();
+    assert_eq!(lsps.len(), 1);
+    assert_eq!(lsps[0].id, lsp.id);
+    assert_eq!(lsps[0].host, lsp.host);
+    assert_eq!(lsps[0].time_lock_delta, lsp.time_lock_delta);
+}
@@ -0,0 +1,498 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::db::{HookEvent, SqliteStorage};
+use super::maintenance::DbIntegrity;
+use super::sync::SyncVersion;
+use crate::breez_services::{BreezEvent, PersistedEvent};
+use crate::error::SdkResult;
+use crate::fiat::{FiatRates, PaymentFiatRate};
+use crate::lnurl::pay::model::SuccessActionProcessed;
+use crate::lsp::LspInformation;
+use crate::models::*;
+use anyhow::Result;
+use tokio::sync::broadcast;
+
+/// Storage is the interface of the persistent storage used by the SDK, an implementation can be
+/// set with [crate::BreezServicesBuilder::persister].
+///
+/// The SQLite storage is the default implementation. Other implementations still exchange the
+/// synced state with the backup as an unencrypted SQLite sync DB file (see [Storage::backup] and
+/// [Storage::import_remote_changes]), so different implementations can sync with each other.
+pub trait Storage: Send + Sync {
+    /// Creates or migrates the underlying storage, called once before it is used
+    fn init(&self) -> SdkResult<()>;
+
+    /// Subscribes to the changes of the synced state, see [HookEvent]
+    fn subscribe_hooks(&self) -> broadcast::Receiver<HookEvent>;
+
+    // Payments
+    fn insert_or_update_payments(&self, transactions: &[Payment]) -> SdkResult<()>;
+    fn insert_lnurl_payment_external_info(
+        &self,
+        payment_hash: &str,
+        lnurl_pay_success_action: Option<&SuccessActionProcessed>,
+        lnurl_metadata: Option<String>,
+        ln_address: Option<String>,
+        lnurl_withdraw_endpoint: Option<String>,
+    ) -> SdkResult<()>;
+    fn insert_open_channel_payment_info(
+        &self,
+        payment_hash: &str,
+        payer_amount_msat: u64,
+    ) -> Result<()>;
+    fn set_payment_metadata(
+        &self,
+        payment_hash: &str,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<()>;
+    fn insert_payment_fiat_rates(
+        &self,
+        payment_hash: &str,
+        rates: &[PaymentFiatRate],
+        replace: bool,
+    ) -> Result<()>;
+    fn last_payment_timestamp(&self) -> Result<u64>;
+    fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>>;
+    fn get_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>>;
+    fn get_completed_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>>;
+
+    // Channels
+    fn update_channels(&self, fetched_channels: &[Channel]) -> Result<()>;
+    fn list_channels(&self) -> Result<Vec<Channel>>;
+    fn insert_or_update_channel(&self, c: Channel) -> Result<()>;
+    fn set_channel_lsp(&self, funding_txid: &str, lsp_id: &str) -> Result<()>;
+    fn list_channel_lsps(&self) -> Result<HashMap<String, String>>;
+
+    // Swaps
+    fn insert_swap(&self, swap_info: SwapInfo) -> Result<()>;
+    fn update_swap_paid_amount(&self, bitcoin_address: String, paid_sats: u32) -> Result<()>;
+    fn update_swap_redeem_error(&self, bitcoin_address: String, redeem_err: String) -> Result<()>;
+    fn update_swap_bolt11(&self, bitcoin_address: String, bolt11: String) -> Result<()>;
+    fn update_swap_fees(
+        &self,
+        bitcoin_address: String,
+        channel_opening_fees: OpeningFeeParams,
+    ) -> Result<()>;
+    fn insert_swap_refund_tx_ids(
+        &self,
+        bitcoin_address: String,
+        refund_tx_id: String,
+    ) -> Result<()>;
+    fn update_swap_chain_info(
+        &self,
+        bitcoin_address: String,
+        unconfirmed_sats: u64,
+        unconfirmed_tx_ids: Vec<String>,
+        confirmed_sats: u64,
+        confirmed_tx_ids: Vec<String>,
+        status: SwapStatus,
+    ) -> Result<SwapInfo>;
+    fn get_swap_info_by_hash(&self, hash: &[u8]) -> Result<Option<SwapInfo>>;
+    fn get_swap_info_by_address(&self, address: String) -> Result<Option<SwapInfo>>;
+    fn list_swaps_with_status(&self, status: SwapStatus) -> Result<Vec<SwapInfo>>;
+    fn list_swaps(&self) -> Result<Vec<SwapInfo>>;
+
+    // Reverse swaps
+    fn insert_reverse_swap(&self, rsi: &FullReverseSwapInfo) -> Result<()>;
+    fn update_reverse_swap_status(&self, id: &str, status: &ReverseSwapStatus) -> Result<()>;
+    fn list_reverse_swaps(&self) -> Result<Vec<FullReverseSwapInfo>>;
+
+    // Settings
+    fn set_lsp_id(&self, lsp_id: String) -> SdkResult<()>;
+    fn get_lsp_id(&self) -> SdkResult<Option<String>>;
+    fn set_custom_lsps(&self, lsps: &[LspInformation]) -> SdkResult<()>;
+    fn get_custom_lsps(&self) -> SdkResult<Vec<LspInformation>>;
+
+    // Cache
+    fn set_node_state(&self, state: &NodeState) -> Result<()>;
+    fn get_node_state(&self) -> SdkResult<Option<NodeState>>;
+    fn set_last_backup_time(&self, t: u64) -> Result<()>;
+    fn get_last_backup_time(&self) -> Result<Option<u64>>;
+    fn set_gl_credentials(&self, creds: Vec<u8>) -> Result<()>;
+    fn get_gl_credentials(&self) -> Result<Option<Vec<u8>>>;
+    fn set_backup_passphrase_keyring(&self, keyring: Option<Vec<u8>>) -> Result<()>;
+    fn get_backup_passphrase_keyring(&self) -> Result<Option<Vec<u8>>>;
+    fn set_static_backup(&self, backup: Vec<String>) -> Result<()>;
+    fn get_static_backup(&self) -> Result<Option<Vec<String>>>;
+    fn set_fiat_rates(&self, rates: &FiatRates) -> Result<()>;
+    fn get_fiat_rates(&self) -> Result<Option<FiatRates>>;
+    fn set_webhook_url(&self, webhook_url: String) -> Result<()>;
+    fn get_webhook_url(&self) -> Result<Option<String>>;
+    fn set_chain_notifier_registration_id(&self, registration_id: String) -> Result<()>;
+    fn get_chain_notifier_registration_id(&self) -> Result<Option<String>>;
+
+    // Events
+    /// Appends the event to the event log, keeping only the last `retention` events
+    fn insert_event(&self, event: &BreezEvent, created_at: u64, retention: u32) -> Result<u64>;
+    fn list_events_since(&self, seq: u64) -> Result<Vec<PersistedEvent>>;
+
+    // Liquidity orders
+    fn insert_or_update_liquidity_order(&self, order: &LiquidityOrder) -> Result<()>;
+    fn get_liquidity_order(&self, order_id: &str) -> Result<Option<LiquidityOrder>>;
+    fn list_liquidity_orders(&self) -> Result<Vec<LiquidityOrder>>;
+
+    // Sync
+    /// Writes the synced state to the given path as an SQLite sync DB
+    fn backup(&self, dst_path: &Path) -> Result<()>;
+    fn get_last_sync_version(&self) -> Result<Option<u64>>;
+    fn set_last_sync_version(&self, last_version: u64, data: &[u8]) -> Result<()>;
+    fn sync_versions_history(&self) -> Result<Vec<SyncVersion>>;
+    fn get_sync_version(&self, version: u64) -> Result<Option<SyncVersion>>;
+    fn get_last_sync_request(&self) -> Result<Option<u64>>;
+    fn delete_sync_requests_up_to(&self, request_id: u64) -> Result<()>;
+    /// Merges the synced state of the sync DB file at the given path into the local one
+    fn import_remote_changes(&self, sync_db_path: &Path, to_local: bool) -> Result<()>;
+    /// Replaces the local synced state with the one of the sync DB file at the given path
+    fn restore_remote_changes(&self, sync_db_path: &Path) -> Result<()>;
+
+    // Maintenance
+    fn check_integrity(&self) -> DbIntegrity;
+    fn delete_failed_payments_before(&self, timestamp: i64) -> Result<u32>;
+    fn prune_sync_versions(&self, keep: u32) -> Result<u32>;
+    fn delete_unused_cached_items(&self) -> Result<u32>;
+    fn vacuum(&self) -> Result<()>;
+    /// Replaces a corrupted main DB with a new one, to be rebuilt from the node
+    fn reset_main_db(&self) -> Result<()>;
+    /// Replaces a corrupted sync DB with a new one, to be restored from the remote backup
+    fn reset_sync_db(&self) -> Result<()>;
+}
+
+impl Storage for SqliteStorage {
+    fn init(&self) -> SdkResult<()> {
+        SqliteStorage::init(self)
+    }
+
+    fn subscribe_hooks(&self) -> broadcast::Receiver<HookEvent> {
+        SqliteStorage::subscribe_hooks(self)
+    }
+
+    fn insert_or_update_payments(&self, transactions: &[Payment]) -> SdkResult<()> {
+        SqliteStorage::insert_or_update_payments(self, transactions)
+    }
+
+    fn insert_lnurl_payment_external_info(
+        &self,
+        payment_hash: &str,
+        lnurl_pay_success_action: Option<&SuccessActionProcessed>,
+        lnurl_metadata: Option<String>,
+        ln_address: Option<String>,
+        lnurl_withdraw_endpoint: Option<String>,
+    ) -> SdkResult<()> {
+        SqliteStorage::insert_lnurl_payment_external_info(
+            self,
+            payment_hash,
+            lnurl_pay_success_action,
+            lnurl_metadata,
+            ln_address,
+            lnurl_withdraw_endpoint,
+        )
+    }
+
+    fn insert_open_channel_payment_info(
+        &self,
+        payment_hash: &str,
+        payer_amount_msat: u64,
+    ) -> Result<()> {
+        SqliteStorage::insert_open_channel_payment_info(self, payment_hash, payer_amount_msat)
+    }
+
+    fn set_payment_metadata(
+        &self,
+        payment_hash: &str,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<()> {
+        SqliteStorage::set_payment_metadata(self, payment_hash, note, tags)
+    }
+
+    fn insert_payment_fiat_rates(
+        &self,
+        payment_hash: &str,
+        rates: &[PaymentFiatRate],
+        replace: bool,
+    ) -> Result<()> {
+        SqliteStorage::insert_payment_fiat_rates(self, payment_hash, rates, replace)
+    }
+
+    fn last_payment_timestamp(&self) -> Result<u64> {
+        SqliteStorage::last_payment_timestamp(self)
+    }
+
+    fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
+        SqliteStorage::list_payments(self, req)
+    }
+
+    fn get_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>> {
+        SqliteStorage::get_payment_by_hash(self, hash)
+    }
+
+    fn get_completed_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>> {
+        SqliteStorage::get_completed_payment_by_hash(self, hash)
+    }
+
+    fn update_channels(&self, fetched_channels: &[Channel]) -> Result<()> {
+        SqliteStorage::update_channels(self, fetched_channels)
+    }
+
+    fn list_channels(&self) -> Result<Vec<Channel>> {
+        SqliteStorage::list_channels(self)
+    }
+
+    fn insert_or_update_channel(&self, c: Channel) -> Result<()> {
+        SqliteStorage::insert_or_update_channel(self, c)
+    }
+
+    fn set_channel_lsp(&self, funding_txid: &str, lsp_id: &str) -> Result<()> {
+        SqliteStorage::set_channel_lsp(self, funding_txid, lsp_id)
+    }
+
+    fn list_channel_lsps(&self) -> Result<HashMap<String, String>> {
+        SqliteStorage::list_channel_lsps(self)
+    }
+
+    fn insert_swap(&self, swap_info: SwapInfo) -> Result<()> {
+        SqliteStorage::insert_swap(self, swap_info)
+    }
+
+    fn update_swap_paid_amount(&self, bitcoin_address: String, paid_sats: u32) -> Result<()> {
+        SqliteStorage::update_swap_paid_amount(self, bitcoin_address, paid_sats)
+    }
+
+    fn update_swap_redeem_error(&self, bitcoin_address: String, redeem_err: String) -> Result<()> {
+        SqliteStorage::update_swap_redeem_error(self, bitcoin_address, redeem_err)
+    }
+
+    fn update_swap_bolt11(&self, bitcoin_address: String, bolt11: String) -> Result<()> {
+        SqliteStorage::update_swap_bolt11(self, bitcoin_address, bolt11)
+    }
+
+    fn update_swap_fees(
+        &self,
+        bitcoin_address: String,
+        channel_opening_fees: OpeningFeeParams,
+    ) -> Result<()> {
+        SqliteStorage::update_swap_fees(self, bitcoin_address, channel_opening_fees)
+    }
+
+    fn insert_swap_refund_tx_ids(
+        &self,
+        bitcoin_address: String,
+        refund_tx_id: String,
+    ) -> Result<()> {
+        SqliteStorage::insert_swap_refund_tx_ids(self, bitcoin_address, refund_tx_id)
+    }
+
+    fn update_swap_chain_info(
+        &self,
+        bitcoin_address: String,
+        unconfirmed_sats: u64,
+        unconfirmed_tx_ids: Vec<String>,
+        confirmed_sats: u64,
+        confirmed_tx_ids: Vec<String>,
+        status: SwapStatus,
+    ) -> Result<SwapInfo> {
+        SqliteStorage::update_swap_chain_info(
+            self,
+            bitcoin_address,
+            unconfirmed_sats,
+            unconfirmed_tx_ids,
+            confirmed_sats,
+            confirmed_tx_ids,
+            status,
+        )
+    }
+
+    fn get_swap_info_by_hash(&self, hash: &[u8]) -> Result<Option<SwapInfo>> {
+        SqliteStorage::get_swap_info_by_hash(self, hash)
+    }
+
+    fn get_swap_info_by_address(&self, address: String) -> Result<Option<SwapInfo>> {
+        SqliteStorage::get_swap_info_by_address(self, address)
+    }
+
+    fn list_swaps_with_status(&self, status: SwapStatus) -> Result<Vec<SwapInfo>> {
+        SqliteStorage::list_swaps_with_status(self, status)
+    }
+
+    fn list_swaps(&self) -> Result<Vec<SwapInfo>> {
+        SqliteStorage::list_swaps(self)
+    }
+
+    fn insert_reverse_swap(&self, rsi: &FullReverseSwapInfo) -> Result<()> {
+        SqliteStorage::insert_reverse_swap(self, rsi)
+    }
+
+    fn update_reverse_swap_status(&self, id: &str, status: &ReverseSwapStatus) -> Result<()> {
+        SqliteStorage::update_reverse_swap_status(self, id, status)
+    }
+
+    fn list_reverse_swaps(&self) -> Result<Vec<FullReverseSwapInfo>> {
+        SqliteStorage::list_reverse_swaps(self)
+    }
+
+    fn set_lsp_id(&self, lsp_id: String) -> SdkResult<()> {
+        SqliteStorage::set_lsp_id(self, lsp_id)
+    }
+
+    fn get_lsp_id(&self) -> SdkResult<Option<String>> {
+        SqliteStorage::get_lsp_id(self)
+    }
+
+    fn set_custom_lsps(&self, lsps: &[LspInformation]) -> SdkResult<()> {
+        SqliteStorage::set_custom_lsps(self, lsps)
+    }
+
+    fn get_custom_lsps(&self) -> SdkResult<Vec<LspInformation>> {
+        SqliteStorage::get_custom_lsps(self)
+    }
+
+    fn set_node_state(&self, state: &NodeState) -> Result<()> {
+        SqliteStorage::set_node_state(self, state)
+    }
+
+    fn get_node_state(&self) -> SdkResult<Option<NodeState>> {
+        SqliteStorage::get_node_state(self)
+    }
+
+    fn set_last_backup_time(&self, t: u64) -> Result<()> {
+        SqliteStorage::set_last_backup_time(self, t)
+    }
+
+    fn get_last_backup_time(&self) -> Result<Option<u64>> {
+        SqliteStorage::get_last_backup_time(self)
+    }
+
+    fn set_gl_credentials(&self, creds: Vec<u8>) -> Result<()> {
+        SqliteStorage::set_gl_credentials(self, creds)
+    }
+
+    fn get_gl_credentials(&self) -> Result<Option<Vec<u8>>> {
+        SqliteStorage::get_gl_credentials(self)
+    }
+
+    fn set_backup_passphrase_keyring(&self, keyring: Option<Vec<u8>>) -> Result<()> {
+        SqliteStorage::set_backup_passphrase_keyring(self, keyring)
+    }
+
+    fn get_backup_passphrase_keyring(&self) -> Result<Option<Vec<u8>>> {
+        SqliteStorage::get_backup_passphrase_keyring(self)
+    }
+
+    fn set_static_backup(&self, backup: Vec<String>) -> Result<()> {
+        SqliteStorage::set_static_backup(self, backup)
+    }
+
+    fn get_static_backup(&self) -> Result<Option<Vec<String>>> {
+        SqliteStorage::get_static_backup(self)
+    }
+
+    fn set_fiat_rates(&self, rates: &FiatRates) -> Result<()> {
+        SqliteStorage::set_fiat_rates(self, rates)
+    }
+
+    fn get_fiat_rates(&self) -> Result<Option<FiatRates>> {
+        SqliteStorage::get_fiat_rates(self)
+    }
+
+    fn set_webhook_url(&self, webhook_url: String) -> Result<()> {
+        SqliteStorage::set_webhook_url(self, webhook_url)
+    }
+
+    fn get_webhook_url(&self) -> Result<Option<String>> {
+        SqliteStorage::get_webhook_url(self)
+    }
+
+    fn set_chain_notifier_registration_id(&self, registration_id: String) -> Result<()> {
+        SqliteStorage::set_chain_notifier_registration_id(self, registration_id)
+    }
+
+    fn get_chain_notifier_registration_id(&self) -> Result<Option<String>> {
+        SqliteStorage::get_chain_notifier_registration_id(self)
+    }
+
+    fn insert_event(&self, event: &BreezEvent, created_at: u64, retention: u32) -> Result<u64> {
+        SqliteStorage::insert_event(self, event, created_at, retention)
+    }
+
+    fn list_events_since(&self, seq: u64) -> Result<Vec<PersistedEvent>> {
+        SqliteStorage::list_events_since(self, seq)
+    }
+
+    fn insert_or_update_liquidity_order(&self, order: &LiquidityOrder) -> Result<()> {
+        SqliteStorage::insert_or_update_liquidity_order(self, order)
+    }
+
+    fn get_liquidity_order(&self, order_id: &str) -> Result<Option<LiquidityOrder>> {
+        SqliteStorage::get_liquidity_order(self, order_id)
+    }
+
+    fn list_liquidity_orders(&self) -> Result<Vec<LiquidityOrder>> {
+        SqliteStorage::list_liquidity_orders(self)
+    }
+
+    fn backup(&self, dst_path: &Path) -> Result<()> {
+        SqliteStorage::backup(self, dst_path)
+    }
+
+    fn get_last_sync_version(&self) -> Result<Option<u64>> {
+        SqliteStorage::get_last_sync_version(self)
+    }
+
+    fn set_last_sync_version(&self, last_version: u64, data: &[u8]) -> Result<()> {
+        SqliteStorage::set_last_sync_version(self, last_version, data)
+    }
+
+    fn sync_versions_history(&self) -> Result<Vec<SyncVersion>> {
+        SqliteStorage::sync_versions_history(self)
+    }
+
+    fn get_sync_version(&self, version: u64) -> Result<Option<SyncVersion>> {
+        SqliteStorage::get_sync_version(self, version)
+    }
+
+    fn get_last_sync_request(&self) -> Result<Option<u64>> {
+        SqliteStorage::get_last_sync_request(self)
+    }
+
+    fn delete_sync_requests_up_to(&self, request_id: u64) -> Result<()> {
+        SqliteStorage::delete_sync_requests_up_to(self, request_id)
+    }
+
+    fn import_remote_changes(&self, sync_db_path: &Path, to_local: bool) -> Result<()> {
+        SqliteStorage::import_remote_changes(self, sync_db_path, to_local)
+    }
+
+    fn restore_remote_changes(&self, sync_db_path: &Path) -> Result<()> {
+        SqliteStorage::restore_remote_changes(self, sync_db_path)
+    }
+
+    fn check_integrity(&self) -> DbIntegrity {
+        SqliteStorage::check_integrity(self)
+    }
+
+    fn delete_failed_payments_before(&self, timestamp: i64) -> Result<u32> {
+        SqliteStorage::delete_failed_payments_before(self, timestamp)
+    }
+
+    fn prune_sync_versions(&self, keep: u32) -> Result<u32> {
+        SqliteStorage::prune_sync_versions(self, keep)
+    }
+
+    fn delete_unused_cached_items(&self) -> Result<u32> {
+        SqliteStorage::delete_unused_cached_items(self)
+    }
+
+    fn vacuum(&self) -> Result<()> {
+        SqliteStorage::vacuum(self)
+    }
+
+    fn reset_main_db(&self) -> Result<()> {
+        SqliteStorage::reset_main_db(self)
+    }
+
+    fn reset_sync_db(&self) -> Result<()> {
+        SqliteStorage::reset_sync_db(self)
+    }
+}
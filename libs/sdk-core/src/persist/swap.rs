@@ -237,7 +237,7 @@ impl SqliteStorage {
             .map_err(|e| anyhow!(e))
     }
 
-    pub(crate) fn get_swap_info_by_hash(&self, hash: &Vec<u8>) -> Result<Option<SwapInfo>> {
+    pub(crate) fn get_swap_info_by_hash(&self, hash: &[u8]) -> Result<Option<SwapInfo>> {
         self.select_single_swap("payment_hash = ?1", [hash])
     }
 
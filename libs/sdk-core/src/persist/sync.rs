@@ -1,22 +1,65 @@
 use crate::ReverseSwapStatus;
 
 use super::db::SqliteStorage;
-use anyhow::Result;
-use rusqlite::{named_params, Row, Transaction};
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use rusqlite::{named_params, types::ValueRef, Connection, Row, Transaction};
 use std::path::Path;
 
-#[allow(dead_code)]
-pub(crate) struct SyncVersion {
+/// The synced tables, with the columns that uniquely identify their rows
+const SYNC_TABLES: [(&str, &str); 8] = [
+    ("swaps", "bitcoin_address"),
+    ("swap_refunds", "bitcoin_address, refund_tx_id"),
+    ("payments_external_info", "payment_id"),
+    ("reverse_swaps", "id"),
+    ("open_channel_payment_info", "payment_hash"),
+    ("swaps_fees", "bitcoin_address"),
+    ("payments_metadata", "payment_id"),
+    ("payments_fiat_rates", "payment_id"),
+];
+
+/// A version of the synced state that was pushed to the remote backup, see
+/// [crate::Storage::sync_versions_history]
+pub struct SyncVersion {
     pub created_at: String,
     pub last_version: u64,
     pub data: Vec<u8>,
 }
 
+/// Number of rows and hash of the content of a synced table
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct SyncTableSummary {
+    pub table: String,
+    pub rows: u64,
+    pub hash: String,
+}
+
+/// Result of [SqliteStorage::sync_db_summary]
+pub(crate) struct SyncDbSummary {
+    pub integrity_ok: bool,
+    pub tables: Vec<SyncTableSummary>,
+}
+
 impl SqliteStorage {
+    /// Writes an unencrypted copy of the sync DB to the given path
     pub(crate) fn backup<P: AsRef<Path>>(&self, dst_path: P) -> Result<()> {
-        self.get_connection()?
-            .backup(rusqlite::DatabaseName::Attached("sync"), dst_path, None)
-            .map_err(anyhow::Error::msg)
+        if !self.is_encrypted() {
+            return self
+                .get_connection()?
+                .backup(rusqlite::DatabaseName::Attached("sync"), dst_path, None)
+                .map_err(anyhow::Error::msg);
+        }
+
+        // The backup API can't copy an encrypted DB into an unencrypted one
+        let dst_path = dst_path
+            .as_ref()
+            .to_str()
+            .ok_or(anyhow!("Invalid backup path"))?;
+        let con = self.get_connection()?;
+        con.execute("ATTACH DATABASE ?1 AS backup KEY '';", [dst_path])?;
+        con.query_row("SELECT sqlcipher_export('backup', 'sync');", [], |_| Ok(()))?;
+        con.execute("DETACH DATABASE backup;", [])?;
+        Ok(())
     }
 
     pub(crate) fn get_last_sync_version(&self) -> Result<Option<u64>> {
@@ -28,7 +71,7 @@ impl SqliteStorage {
         res.map_err(anyhow::Error::msg)
     }
 
-    pub(crate) fn set_last_sync_version(&self, last_version: u64, data: &Vec<u8>) -> Result<()> {
+    pub(crate) fn set_last_sync_version(&self, last_version: u64, data: &[u8]) -> Result<()> {
         let con = self.get_connection()?;
 
         // make sure we have no more than 20 history entries
@@ -41,7 +84,6 @@ impl SqliteStorage {
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub(crate) fn sync_versions_history(&self) -> Result<Vec<SyncVersion>> {
         let con = self.get_connection()?;
         let mut stmt = con.prepare(
@@ -56,6 +98,15 @@ impl SqliteStorage {
         Ok(vec)
     }
 
+    pub(crate) fn get_sync_version(&self, version: u64) -> Result<Option<SyncVersion>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT created_at, last_version, data FROM sync_versions WHERE last_version = ?1;",
+        )?;
+        let mut rows = stmt.query_map([version], |row| self.sql_row_to_sync_version(row))?;
+        Ok(rows.next().transpose()?)
+    }
+
     fn sql_row_to_sync_version(&self, row: &Row) -> Result<SyncVersion, rusqlite::Error> {
         let version = SyncVersion {
             created_at: row.get(0)?,
@@ -83,13 +134,56 @@ impl SqliteStorage {
         Ok(())
     }
 
-    pub(crate) fn import_remote_changes(
-        &self,
-        remote_storage: &SqliteStorage,
-        to_local: bool,
-    ) -> Result<()> {
-        let sync_data_file = remote_storage.sync_db_path();
-        match SqliteStorage::migrate_sync_db(sync_data_file.clone()) {
+    /// Runs an integrity check on the given sync DB and summarizes the content of its synced tables.
+    ///
+    /// The rows are hashed in the order of their unique columns, so two DBs with the same content
+    /// have the same hashes regardless of the order the rows were inserted.
+    pub(crate) fn sync_db_summary(sync_db_path: String) -> Result<SyncDbSummary> {
+        let con = Connection::open(sync_db_path)?;
+        let integrity_check: String =
+            con.query_row("PRAGMA integrity_check;", [], |row| row.get(0))?;
+
+        let mut tables = vec![];
+        for (table, order_by) in SYNC_TABLES {
+            let mut stmt = con.prepare(&format!("SELECT * FROM {table} ORDER BY {order_by}"))?;
+            let column_count = stmt.column_count();
+            let mut rows = stmt.query([])?;
+            let mut engine = sha256::Hash::engine();
+            let mut count = 0;
+            while let Some(row) = rows.next()? {
+                for i in 0..column_count {
+                    let value = match row.get_ref(i)? {
+                        ValueRef::Null => String::new(),
+                        ValueRef::Integer(i) => i.to_string(),
+                        ValueRef::Real(f) => f.to_string(),
+                        ValueRef::Text(t) => String::from_utf8_lossy(t).to_string(),
+                        ValueRef::Blob(b) => hex::encode(b),
+                    };
+                    engine.input(value.as_bytes());
+                    engine.input(&[0x1f]);
+                }
+                engine.input(&[0x1e]);
+                count += 1;
+            }
+            tables.push(SyncTableSummary {
+                table: table.to_string(),
+                rows: count,
+                hash: sha256::Hash::from_engine(engine).to_string(),
+            });
+        }
+
+        Ok(SyncDbSummary {
+            integrity_ok: integrity_check == "ok",
+            tables,
+        })
+    }
+
+    pub(crate) fn import_remote_changes(&self, sync_db_path: &Path, to_local: bool) -> Result<()> {
+        let sync_data_file = sync_db_path
+            .to_str()
+            .ok_or(anyhow!("Invalid sync DB path"))?
+            .to_string();
+        match SqliteStorage::migrate_sync_db(sync_data_file.clone(), None) {
             Ok(_) => {}
             Err(e) => {
                 log::error!("Failed to migrate sync db, probably local db is older than remote, skipping migration: {}", e);
@@ -98,8 +192,53 @@ impl SqliteStorage {
 
         let mut con = self.get_connection()?;
         let tx = con.transaction()?;
-        tx.execute("ATTACH DATABASE ? AS remote_sync;", [sync_data_file])?;
+        tx.execute("ATTACH DATABASE ? AS remote_sync KEY '';", [sync_data_file])?;
+        Self::import_remote_tables(&tx, to_local)?;
+        tx.commit()?;
+        con.execute("DETACH DATABASE remote_sync", [])?;
+
+        Ok(())
+    }
 
+    /// Replaces the content of the local sync tables with the content of the given sync DB.
+    ///
+    /// Unlike [SqliteStorage::import_remote_changes], rows that only exist locally are dropped,
+    /// which makes it suitable for rolling back to an older backup version.
+    pub(crate) fn restore_remote_changes(&self, sync_db_path: &Path) -> Result<()> {
+        let sync_data_file = sync_db_path
+            .to_str()
+            .ok_or(anyhow!("Invalid sync DB path"))?
+            .to_string();
+        match SqliteStorage::migrate_sync_db(sync_data_file.clone(), None) {
+            Ok(_) => {}
+            Err(e) => {
+                log::error!("Failed to migrate sync db, probably local db is older than remote, skipping migration: {}", e);
+            }
+        }
+
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        tx.execute("ATTACH DATABASE ? AS remote_sync KEY '';", [sync_data_file])?;
+        tx.execute_batch(
+            "
+        DELETE FROM sync.swaps;
+        DELETE FROM sync.swap_refunds;
+        DELETE FROM sync.payments_external_info;
+        DELETE FROM sync.reverse_swaps;
+        DELETE FROM sync.open_channel_payment_info;
+        DELETE FROM sync.swaps_fees;
+        DELETE FROM sync.payments_metadata;
+        DELETE FROM sync.payments_fiat_rates;",
+        )?;
+        Self::import_remote_tables(&tx, true)?;
+        tx.commit()?;
+        con.execute("DETACH DATABASE remote_sync", [])?;
+
+        Ok(())
+    }
+
+    /// Merges the rows of the attached `remote_sync` database into the local sync tables
+    pub(crate) fn import_remote_tables(tx: &Transaction, to_local: bool) -> Result<()> {
         if to_local {
             tx.execute(
                 "
@@ -151,11 +290,17 @@ impl SqliteStorage {
           bitcoin_address,
           refund_tx_id
          FROM remote_sync.swap_refunds
-         WHERE bitcoin_address NOT IN (SELECT bitcoin_address FROM sync.swap_refunds);",
+         WHERE NOT EXISTS (
+          SELECT 1 FROM sync.swap_refunds
+          WHERE sync.swap_refunds.bitcoin_address = remote_sync.swap_refunds.bitcoin_address
+           AND sync.swap_refunds.refund_tx_id = remote_sync.swap_refunds.refund_tx_id
+         );",
             [],
         )?;
 
         // sync remote payments_external_info table
+        // Rows of the same payment are merged column by column, so info added on different
+        // devices (e.g. the success action on one and the metadata on another) is kept.
         tx.execute(
             "
          INSERT into sync.payments_external_info
@@ -166,7 +311,12 @@ impl SqliteStorage {
           lnurl_metadata,
           lnurl_withdraw_endpoint
          FROM remote_sync.payments_external_info
-         WHERE payment_id NOT IN (SELECT payment_id FROM sync.payments_external_info);",
+         WHERE true
+         ON CONFLICT(payment_id) DO UPDATE SET
+          lnurl_success_action = COALESCE(lnurl_success_action, excluded.lnurl_success_action),
+          ln_address = COALESCE(ln_address, excluded.ln_address),
+          lnurl_metadata = COALESCE(lnurl_metadata, excluded.lnurl_metadata),
+          lnurl_withdraw_endpoint = COALESCE(lnurl_withdraw_endpoint, excluded.lnurl_withdraw_endpoint);",
             [],
         )?;
 
@@ -204,10 +354,40 @@ impl SqliteStorage {
 
         // Sync remote swaps_fees table, which contains dynamic fees used in swaps
         // created_at is used to settle conflicts, since we assume small variations in the client local times
-        Self::sync_swaps_fees_local(&tx)?;
+        Self::sync_swaps_fees_local(tx)?;
 
-        tx.commit()?;
-        con.execute("DETACH DATABASE remote_sync", [])?;
+        // Sync remote payments_metadata table, the most recently updated row wins
+        tx.execute(
+            "
+        INSERT OR REPLACE INTO sync.payments_metadata
+         SELECT
+          remote_sync.payments_metadata.payment_id,
+          remote_sync.payments_metadata.note,
+          remote_sync.payments_metadata.tags,
+          remote_sync.payments_metadata.updated_at
+         FROM remote_sync.payments_metadata
+          LEFT JOIN sync.payments_metadata ON sync.payments_metadata.payment_id = remote_sync.payments_metadata.payment_id
+         WHERE
+          sync.payments_metadata.updated_at IS NULL OR remote_sync.payments_metadata.updated_at > sync.payments_metadata.updated_at
+         ;",
+            [],
+        )?;
+
+        // Sync remote payments_fiat_rates table, the rates recorded first are kept
+        tx.execute(
+            "
+        INSERT OR IGNORE INTO sync.payments_fiat_rates
+         SELECT
+          payment_id,
+          rates
+         FROM remote_sync.payments_fiat_rates;",
+            [],
+        )?;
+
+        // The LNURL info of payments may have changed
+        if to_local {
+            Self::rebuild_payments_search_index(tx)?;
+        }
 
         Ok(())
     }
@@ -242,7 +422,7 @@ impl SqliteStorage {
 mod tests {
     use anyhow::{anyhow, Result};
     use rand::random;
-    use std::time::Duration;
+    use std::path::Path;
 
     use crate::persist::db::SqliteStorage;
     use crate::persist::test_utils;
@@ -272,8 +452,8 @@ mod tests {
 
         remote_storage.insert_open_channel_payment_info("123", 100000)?;
 
-        remote_storage.import_remote_changes(&local_storage, false)?;
-        local_storage.import_remote_changes(&remote_storage, true)?;
+        remote_storage.import_remote_changes(Path::new(&local_storage.sync_db_path()), false)?;
+        local_storage.import_remote_changes(Path::new(&remote_storage.sync_db_path()), true)?;
 
         let mut local_swaps = local_storage.list_swaps()?;
         local_swaps.sort_by(|s1, s2| s1.bitcoin_address.cmp(&s2.bitcoin_address));
@@ -291,17 +471,19 @@ mod tests {
         Ok(())
     }
 
-    #[tokio::test]
-    async fn test_sync_swaps_update_swap_fees() -> Result<()> {
+    #[test]
+    fn test_sync_swaps_update_swap_fees() -> Result<()> {
         let local_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
         local_storage.init()?;
 
         // Swap is created with initial dynamic fee
         let local_swap_info = create_test_swap_info();
         local_storage.insert_swap(local_swap_info.clone())?;
-
-        // Sleep to cause a change in created_at
-        tokio::time::sleep(Duration::from_secs(2)).await;
+        set_swap_fees_created_at(
+            &local_storage,
+            &local_swap_info.bitcoin_address,
+            "2024-01-01 00:00:00",
+        )?;
 
         // Swap address is re-used later with different (newer) dynamic fee
         let new_fees: crate::OpeningFeeParams = get_test_ofp_48h(10, 10).into();
@@ -320,8 +502,8 @@ mod tests {
         Ok(())
     }
 
-    #[tokio::test]
-    async fn test_sync_swaps_fees_local_vs_remote() -> Result<()> {
+    #[test]
+    fn test_sync_swaps_fees_local_vs_remote() -> Result<()> {
         let local_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
         local_storage.init()?;
 
@@ -339,22 +521,23 @@ mod tests {
         let mut pre_sync_r2 = pre_sync_l2.clone();
         pre_sync_r2.channel_opening_fees = Some(get_test_ofp_48h(15, 15).into());
 
-        // Swaps are inserted 1 second apart from each other, because the swaps_fees rows include a local timestamp on insertion
-        // Swaps are inserted in this order:
+        // The swaps_fees rows include a local timestamp on insertion, which is set explicitly
+        // to order the swaps:
         // - Remote swap R1
         // - Local swap L1  (created_at +1s)
         // - Local swap L2  (created_at +1s)
         // - Local swap L3  (created_at +1s)
         // - Remote swap R2 (created_at +1s)
-        remote_storage.insert_swap(pre_sync_r1.clone())?; // R1
-        tokio::time::sleep(Duration::from_secs(1)).await;
-        local_storage.insert_swap(pre_sync_l1.clone())?; // L1
-        tokio::time::sleep(Duration::from_secs(1)).await;
-        local_storage.insert_swap(pre_sync_l2.clone())?; // L2
-        tokio::time::sleep(Duration::from_secs(1)).await;
-        local_storage.insert_swap(pre_sync_l3.clone())?; // L3
-        tokio::time::sleep(Duration::from_secs(1)).await;
-        remote_storage.insert_swap(pre_sync_r2.clone())?; // R2
+        for (storage, swap, created_at) in [
+            (&remote_storage, &pre_sync_r1, "2024-01-01 00:00:01"), // R1
+            (&local_storage, &pre_sync_l1, "2024-01-01 00:00:02"),  // L1
+            (&local_storage, &pre_sync_l2, "2024-01-01 00:00:03"),  // L2
+            (&local_storage, &pre_sync_l3, "2024-01-01 00:00:04"),  // L3
+            (&remote_storage, &pre_sync_r2, "2024-01-01 00:00:05"), // R2
+        ] {
+            storage.insert_swap(swap.clone())?;
+            set_swap_fees_created_at(storage, &swap.bitcoin_address, created_at)?;
+        }
 
         // The swap fees created_at are in this order: R1 < L1 < L2 < L3 < R2
 
@@ -369,8 +552,8 @@ mod tests {
         assert_eq!(remote_swaps.len(), 2); // Before the sync, only 2 swaps in remote
 
         // Update local DB based on remote (sync)
-        remote_storage.import_remote_changes(&local_storage, false)?;
-        local_storage.import_remote_changes(&remote_storage, true)?;
+        remote_storage.import_remote_changes(Path::new(&local_storage.sync_db_path()), false)?;
+        local_storage.import_remote_changes(Path::new(&remote_storage.sync_db_path()), true)?;
 
         let local_swaps = local_storage.list_swaps()?;
         let remote_swaps = remote_storage.list_swaps()?;
@@ -421,6 +604,132 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sync_merge_rows() -> Result<()> {
+        let local_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        local_storage.init()?;
+        let remote_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        remote_storage.init()?;
+
+        let swap_info = create_test_swap_info();
+        local_storage.insert_swap(swap_info.clone())?;
+        remote_storage.import_remote_changes(Path::new(&local_storage.sync_db_path()), false)?;
+
+        // Both devices refund the same swap and add different info to the same payment
+        local_storage.insert_swap_refund_tx_ids(swap_info.bitcoin_address.clone(), "1".into())?;
+        remote_storage.insert_swap_refund_tx_ids(swap_info.bitcoin_address.clone(), "2".into())?;
+        local_storage.insert_lnurl_payment_external_info(
+            "123",
+            None,
+            Some("metadata".into()),
+            None,
+            None,
+        )?;
+        remote_storage.insert_lnurl_payment_external_info(
+            "123",
+            None,
+            None,
+            Some("user@domain.com".into()),
+            None,
+        )?;
+
+        local_storage.import_remote_changes(Path::new(&remote_storage.sync_db_path()), true)?;
+
+        let mut refund_tx_ids = local_storage
+            .get_swap_info_by_address(swap_info.bitcoin_address)?
+            .ok_or(anyhow!("Swap not found"))?
+            .refund_tx_ids;
+        refund_tx_ids.sort();
+        assert_eq!(refund_tx_ids, vec!["1".to_string(), "2".to_string()]);
+
+        let (lnurl_metadata, ln_address): (Option<String>, Option<String>) =
+            local_storage.get_connection()?.query_row(
+                "SELECT lnurl_metadata, ln_address FROM sync.payments_external_info WHERE payment_id = '123'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )?;
+        assert_eq!(lnurl_metadata, Some("metadata".into()));
+        assert_eq!(ln_address, Some("user@domain.com".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sync_payment_metadata() -> Result<()> {
+        let local_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        local_storage.init()?;
+        let remote_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        remote_storage.init()?;
+        let get_note = |storage: &SqliteStorage| -> Result<Option<String>> {
+            Ok(storage.get_connection()?.query_row(
+                "SELECT note FROM sync.payments_metadata WHERE payment_id = '123'",
+                [],
+                |row| row.get(0),
+            )?)
+        };
+
+        let set_updated_at = |storage: &SqliteStorage, updated_at: &str| -> Result<()> {
+            storage.get_connection()?.execute(
+                "UPDATE sync.payments_metadata SET updated_at = ?1 WHERE payment_id = '123'",
+                [updated_at],
+            )?;
+            Ok(())
+        };
+
+        // The metadata updated last wins, whichever side it was updated on
+        local_storage.set_payment_metadata("123", Some("local".into()), vec![])?;
+        set_updated_at(&local_storage, "2024-01-01 00:00:01")?;
+        remote_storage.set_payment_metadata("123", Some("remote".into()), vec![])?;
+        set_updated_at(&remote_storage, "2024-01-01 00:00:02")?;
+        local_storage.import_remote_changes(Path::new(&remote_storage.sync_db_path()), true)?;
+        assert_eq!(get_note(&local_storage)?, Some("remote".into()));
+
+        local_storage.set_payment_metadata("123", Some("local again".into()), vec![])?;
+        set_updated_at(&local_storage, "2024-01-01 00:00:03")?;
+        local_storage.import_remote_changes(Path::new(&remote_storage.sync_db_path()), true)?;
+        assert_eq!(get_note(&local_storage)?, Some("local again".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sync_db_summary() -> Result<()> {
+        let local_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        local_storage.init()?;
+        let remote_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        remote_storage.init()?;
+
+        let swap_info = create_test_swap_info();
+        local_storage.insert_swap(swap_info.clone())?;
+        remote_storage.insert_swap(create_test_swap_info())?;
+
+        let local_summary = SqliteStorage::sync_db_summary(local_storage.sync_db_path())?;
+        let remote_summary = SqliteStorage::sync_db_summary(remote_storage.sync_db_path())?;
+        assert!(local_summary.integrity_ok);
+        assert_eq!(local_summary.tables[0].rows, 1);
+        assert_eq!(remote_summary.tables[0].rows, 1);
+        assert_ne!(local_summary.tables[0].hash, remote_summary.tables[0].hash);
+
+        remote_storage.restore_remote_changes(Path::new(&local_storage.sync_db_path()))?;
+        let remote_summary = SqliteStorage::sync_db_summary(remote_storage.sync_db_path())?;
+        assert_eq!(local_summary.tables, remote_summary.tables);
+
+        Ok(())
+    }
+
+    /// Overrides the local timestamp of the swap fees, to order them without waiting
+    fn set_swap_fees_created_at(
+        storage: &SqliteStorage,
+        bitcoin_address: &str,
+        created_at: &str,
+    ) -> Result<()> {
+        storage.get_connection()?.execute(
+            "UPDATE sync.swaps_fees SET created_at = ?1 WHERE bitcoin_address = ?2",
+            [created_at, bitcoin_address],
+        )?;
+        Ok(())
+    }
+
     fn create_test_swap_info() -> SwapInfo {
         SwapInfo {
             bitcoin_address: rand_string(10),
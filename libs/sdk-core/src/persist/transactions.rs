@@ -1,11 +1,12 @@
 use super::db::SqliteStorage;
 use crate::error::SdkResult;
+use crate::fiat::{merge_payment_fiat_rates, PaymentFiatRate};
 use crate::lnurl::pay::model::SuccessActionProcessed;
 use crate::models::*;
 use anyhow::{anyhow, Result};
-use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Type, ValueRef};
 use rusqlite::Row;
-use rusqlite::{params, OptionalExtension};
+use rusqlite::{params, Connection, OptionalExtension};
 use std::collections::HashSet;
 
 use std::str::FromStr;
@@ -50,6 +51,8 @@ impl SqliteStorage {
                 &ln_tx.details,
             ))?;
         }
+        let ids: Vec<String> = transactions.iter().map(|p| p.id.clone()).collect();
+        Self::index_payments_for_search(&con, &ids)?;
         Ok(())
     }
 
@@ -92,6 +95,87 @@ impl SqliteStorage {
             ln_address,
             lnurl_withdraw_endpoint,
         ))?;
+        Self::index_payments_for_search(&con, &[payment_hash.to_string()])?;
+
+        Ok(())
+    }
+
+    /// Updates the full-text search entries of the given payments, see [ListPaymentsRequest::search]
+    fn index_payments_for_search(con: &Connection, ids: &[String]) -> Result<()> {
+        let ids = serde_json::to_string(ids)?;
+        con.execute(
+            "DELETE FROM payments_fts WHERE id IN (SELECT value FROM json_each(?1))",
+            [&ids],
+        )?;
+        con.execute(
+            &format!("{PAYMENTS_FTS_INSERT} WHERE p.id IN (SELECT value FROM json_each(?1))"),
+            [&ids],
+        )?;
+        Ok(())
+    }
+
+    /// Rebuilds the full-text search entries of all payments, e.g. after the LNURL info of
+    /// payments was changed by importing a backup
+    pub(crate) fn rebuild_payments_search_index(con: &Connection) -> Result<()> {
+        con.execute("DELETE FROM payments_fts", [])?;
+        con.execute(PAYMENTS_FTS_INSERT, [])?;
+        Ok(())
+    }
+
+    /// Sets the note and tags of a payment, replacing the previous ones.
+    ///
+    /// The payment doesn't need to exist yet, so the metadata can be set as soon as the payment
+    /// hash is known.
+    pub(crate) fn set_payment_metadata(
+        &self,
+        payment_hash: &str,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<()> {
+        self.get_connection()?.execute(
+            "
+         INSERT OR REPLACE INTO sync.payments_metadata (
+           payment_id,
+           note,
+           tags,
+           updated_at
+         )
+         VALUES (?1,?2,?3,CURRENT_TIMESTAMP)
+        ",
+            params![payment_hash, note, serde_json::to_string(&tags)?],
+        )?;
+
+        Ok(())
+    }
+
+    /// Records the fiat rates at the time the payment was completed.
+    ///
+    /// Rates that were already recorded for this payment are kept, unless `replace` is set.
+    pub(crate) fn insert_payment_fiat_rates(
+        &self,
+        payment_hash: &str,
+        rates: &[PaymentFiatRate],
+        replace: bool,
+    ) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        let mut recorded: Vec<PaymentFiatRate> = match tx
+            .query_row(
+                "SELECT rates FROM sync.payments_fiat_rates WHERE payment_id = ?1",
+                [payment_hash],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?
+        {
+            Some(recorded) => serde_json::from_str(&recorded)?,
+            None => vec![],
+        };
+        merge_payment_fiat_rates(&mut recorded, rates, replace);
+        tx.execute(
+            "INSERT OR REPLACE INTO sync.payments_fiat_rates (payment_id, rates) VALUES (?1,?2)",
+            params![payment_hash, serde_json::to_string(&recorded)?],
+        )?;
+        tx.commit()?;
 
         Ok(())
     }
@@ -133,12 +217,19 @@ impl SqliteStorage {
     /// This queries all payments. To query a single payment, see [Self::get_payment_by_hash]
     /// or [Self::get_completed_payment_by_hash]
     pub fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
-        let where_clause = filter_to_where_clause(
-            req.filters,
-            req.from_timestamp,
-            req.to_timestamp,
-            req.include_failures,
-        );
+        let search_query = req.search.as_deref().and_then(to_fts_query);
+        let where_clause = filter_to_where_clause(&req, &search_query);
+        let mut query_params: Vec<(&str, &dyn ToSql)> = Vec::new();
+        if let Some(cursor) = &req.cursor {
+            query_params.push((":cursor_id", &cursor.id as &dyn ToSql));
+        }
+        if let Some(search_query) = &search_query {
+            query_params.push((":search", search_query as &dyn ToSql));
+        }
+        let tags_filter = tags_filter(&req);
+        if let Some(tags_filter) = &tags_filter {
+            query_params.push((":tags", tags_filter as &dyn ToSql));
+        }
         let offset = req.offset.unwrap_or(0u32);
         let limit = req.limit.unwrap_or(u32::MAX);
         let con = self.get_connection()?;
@@ -158,7 +249,10 @@ impl SqliteStorage {
              e.lnurl_metadata,
              e.ln_address,
              e.lnurl_withdraw_endpoint,
-             o.payer_amount_msat
+             o.payer_amount_msat,
+             m.note,
+             m.tags,
+             r.rates
             FROM payments p
             LEFT JOIN sync.payments_external_info e
             ON
@@ -166,7 +260,13 @@ impl SqliteStorage {
             LEFT JOIN sync.open_channel_payment_info o
              ON
               p.id = o.payment_hash
-            {where_clause} ORDER BY payment_time DESC
+            LEFT JOIN sync.payments_metadata m
+             ON
+              p.id = m.payment_id
+            LEFT JOIN sync.payments_fiat_rates r
+             ON
+              p.id = r.payment_id
+            {where_clause} ORDER BY p.payment_time DESC, p.id DESC
             LIMIT {limit}
             OFFSET {offset}
           "
@@ -175,7 +275,7 @@ impl SqliteStorage {
         )?;
 
         let vec: Vec<Payment> = stmt
-            .query_map([], |row| self.sql_row_to_payment(row))?
+            .query_map(query_params.as_slice(), |row| self.sql_row_to_payment(row))?
             .map(|i| i.unwrap())
             .collect();
 
@@ -187,7 +287,7 @@ impl SqliteStorage {
     /// To lookup a completed payment by hash, use [Self::get_completed_payment_by_hash]
     ///
     /// To query all payments, see [Self::list_payments]
-    pub(crate) fn get_payment_by_hash(&self, hash: &String) -> Result<Option<Payment>> {
+    pub(crate) fn get_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>> {
         self.get_connection()?
             .query_row(
                 "
@@ -204,7 +304,10 @@ impl SqliteStorage {
                  e.lnurl_metadata,
                  e.ln_address,
                  e.lnurl_withdraw_endpoint,
-                 o.payer_amount_msat
+                 o.payer_amount_msat,
+                 m.note,
+                 m.tags,
+                 r.rates
                 FROM payments p
                 LEFT JOIN sync.payments_external_info e
                 ON
@@ -212,6 +315,12 @@ impl SqliteStorage {
                 LEFT JOIN sync.open_channel_payment_info o
                  ON
                   p.id = o.payment_hash
+                LEFT JOIN sync.payments_metadata m
+                 ON
+                  p.id = m.payment_id
+                LEFT JOIN sync.payments_fiat_rates r
+                 ON
+                  p.id = r.payment_id
                 WHERE
                  id = ?1",
                 [hash],
@@ -224,7 +333,7 @@ impl SqliteStorage {
     /// Looks up a completed payment by hash.
     ///
     /// To include pending or failed payments in the lookup as well, use [Self::get_payment_by_hash]
-    pub(crate) fn get_completed_payment_by_hash(&self, hash: &String) -> Result<Option<Payment>> {
+    pub(crate) fn get_completed_payment_by_hash(&self, hash: &str) -> Result<Option<Payment>> {
         let res = self
             .get_payment_by_hash(hash)?
             .filter(|p| p.status == PaymentStatus::Complete);
@@ -243,6 +352,8 @@ impl SqliteStorage {
             status: row.get(5)?,
             description: row.get(6)?,
             details: row.get(7)?,
+            metadata: None,
+            fiat_rates: vec![],
         };
 
         if let PaymentDetails::Ln { ref mut data } = payment.details {
@@ -258,30 +369,99 @@ impl SqliteStorage {
             payment.fee_msat = payer_amount - amount_msat;
         }
 
+        let tags: Option<String> = row.get(14)?;
+        if let Some(tags) = tags {
+            payment.metadata = Some(PaymentMetadata {
+                note: row.get(13)?,
+                tags: serde_json::from_str(&tags).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(14, Type::Text, Box::new(e))
+                })?,
+            });
+        }
+
+        let fiat_rates: Option<String> = row.get(15)?;
+        if let Some(fiat_rates) = fiat_rates {
+            payment.fiat_rates = serde_json::from_str(&fiat_rates).map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(15, Type::Text, Box::new(e))
+            })?;
+        }
+
         Ok(payment)
     }
 }
 
-fn filter_to_where_clause(
-    type_filters: Option<Vec<PaymentTypeFilter>>,
-    from_timestamp: Option<i64>,
-    to_timestamp: Option<i64>,
-    include_failures: Option<bool>,
-) -> String {
+/// Selects the full-text search entries of the payments, to be inserted in `payments_fts`
+const PAYMENTS_FTS_INSERT: &str = "
+    INSERT INTO payments_fts (id, payment_hash, description, lnurl_metadata, ln_address)
+     SELECT
+      p.id,
+      json_extract(p.details, '$.payment_hash'),
+      p.description,
+      e.lnurl_metadata,
+      e.ln_address
+     FROM payments p
+     LEFT JOIN sync.payments_external_info e
+      ON p.id = e.payment_id";
+
+/// Converts the user search text to an FTS5 query, where every word is matched as a prefix
+fn to_fts_query(search: &str) -> Option<String> {
+    let terms: Vec<String> = search
+        .split_whitespace()
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect();
+    match terms.is_empty() {
+        true => None,
+        false => Some(terms.join(" ")),
+    }
+}
+
+/// Serializes the tags of [ListPaymentsRequest::tags] as a JSON array, if any
+fn tags_filter(req: &ListPaymentsRequest) -> Option<String> {
+    req.tags
+        .as_ref()
+        .filter(|tags| !tags.is_empty())
+        .map(|tags| serde_json::Value::from(tags.clone()).to_string())
+}
+
+/// Builds the where clause of [SqliteStorage::list_payments]. The cursor id, search query and
+/// tags are bound as the `:cursor_id`, `:search` and `:tags` parameters.
+fn filter_to_where_clause(req: &ListPaymentsRequest, search_query: &Option<String>) -> String {
     let mut where_clause: Vec<String> = Vec::new();
-    let with_failures = include_failures.unwrap_or(false);
+    let with_failures = req.include_failures.unwrap_or(false);
 
-    if let Some(t) = from_timestamp {
+    if let Some(t) = req.from_timestamp {
         where_clause.push(format!("payment_time >= {t}"));
     };
-    if let Some(t) = to_timestamp {
+    if let Some(t) = req.to_timestamp {
         where_clause.push(format!("payment_time <= {t}"));
     };
     if !with_failures {
         where_clause.push(format!("status != {}", PaymentStatus::Failed as i64));
     };
+    if let Some(amount) = req.min_amount_msat {
+        where_clause.push(format!("amount_msat >= {amount}"));
+    };
+    if let Some(amount) = req.max_amount_msat {
+        where_clause.push(format!("amount_msat <= {amount}"));
+    };
+    if let Some(cursor) = &req.cursor {
+        let t = cursor.payment_time;
+        where_clause.push(format!(
+            "(p.payment_time < {t} OR (p.payment_time = {t} AND p.id < :cursor_id))"
+        ));
+    };
+    if search_query.is_some() {
+        where_clause
+            .push("p.id IN (SELECT id FROM payments_fts WHERE payments_fts MATCH :search)".into());
+    };
+    if tags_filter(req).is_some() {
+        where_clause.push(
+            "EXISTS (SELECT 1 FROM json_each(m.tags) WHERE value IN (SELECT value FROM json_each(:tags)))"
+                .into(),
+        );
+    };
 
-    if let Some(filters) = type_filters {
+    if let Some(filters) = &req.filters {
         if !filters.is_empty() {
             let mut type_filter_clause: HashSet<PaymentType> = HashSet::new();
             for type_filter in filters {
@@ -406,6 +586,8 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
                     lnurl_withdraw_endpoint: None,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         },
         Payment {
             id: payment_hash_with_lnurl_withdraw.to_string(),
@@ -429,6 +611,8 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
                     lnurl_withdraw_endpoint: Some(lnurl_withdraw_url.to_string()),
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         },
     ];
     let failed_txs = [Payment {
@@ -453,6 +637,8 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
                 lnurl_withdraw_endpoint: None,
             },
         },
+        metadata: None,
+        fiat_rates: vec![],
     }];
     let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
     storage.init()?;
@@ -481,6 +667,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: None,
         offset: None,
         limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 2);
     assert_eq!(retrieve_txs, txs);
@@ -496,6 +687,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: None,
         offset: None,
         limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 1);
     assert_eq!(retrieve_txs[0], txs[0]);
@@ -514,6 +710,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: None,
         offset: None,
         limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 1);
     assert_eq!(retrieve_txs[0], txs[1]);
@@ -529,6 +730,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: None,
         offset: None,
         limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 2);
     assert_eq!(retrieve_txs, txs);
@@ -541,6 +747,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: None,
         offset: None,
         limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs[0].fee_msat, 50);
 
@@ -552,6 +763,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: Some(true),
         offset: None,
         limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 3);
 
@@ -566,6 +782,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: Some(true),
         offset: None,
         limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 2);
 
@@ -577,6 +798,11 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: Some(false),
         offset: None,
         limit: Some(1),
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 1);
 
@@ -588,9 +814,165 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
         include_failures: Some(false),
         offset: Some(1),
         limit: Some(1),
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
+    })?;
+    assert_eq!(retrieve_txs.len(), 1);
+    assert_eq!(retrieve_txs[0].id, payment_hash_with_lnurl_withdraw);
+
+    // test cursor
+    let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+        filters: None,
+        from_timestamp: None,
+        to_timestamp: None,
+        include_failures: Some(true),
+        offset: None,
+        limit: None,
+        cursor: Some(PaymentsCursor {
+            payment_time: 1001,
+            id: payment_hash_with_lnurl_success_action.to_string(),
+        }),
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
     })?;
     assert_eq!(retrieve_txs.len(), 1);
     assert_eq!(retrieve_txs[0].id, payment_hash_with_lnurl_withdraw);
 
+    // test search by lightning address
+    let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+        filters: None,
+        from_timestamp: None,
+        to_timestamp: None,
+        include_failures: None,
+        offset: None,
+        limit: None,
+        cursor: None,
+        search: Some("ln.adddress".to_string()),
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
+    })?;
+    assert_eq!(retrieve_txs.len(), 1);
+    assert_eq!(retrieve_txs[0].id, payment_hash_with_lnurl_success_action);
+
+    // test search by description, with failures
+    let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+        filters: None,
+        from_timestamp: None,
+        to_timestamp: None,
+        include_failures: Some(true),
+        offset: None,
+        limit: None,
+        cursor: None,
+        search: Some("desc".to_string()),
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
+    })?;
+    assert_eq!(retrieve_txs.len(), 2);
+
+    // test search by payment hash prefix
+    let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+        filters: None,
+        from_timestamp: None,
+        to_timestamp: None,
+        include_failures: None,
+        offset: None,
+        limit: None,
+        cursor: None,
+        search: Some("12".to_string()),
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: None,
+    })?;
+    assert_eq!(retrieve_txs.len(), 2);
+
+    // test metadata and tags filter
+    storage.set_payment_metadata(
+        payment_hash_with_lnurl_withdraw,
+        Some("lunch".to_string()),
+        vec!["food".to_string(), "work".to_string()],
+    )?;
+    let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+        filters: None,
+        from_timestamp: None,
+        to_timestamp: None,
+        include_failures: Some(true),
+        offset: None,
+        limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: None,
+        max_amount_msat: None,
+        tags: Some(vec!["work".to_string(), "travel".to_string()]),
+    })?;
+    assert_eq!(retrieve_txs.len(), 1);
+    assert_eq!(
+        retrieve_txs[0].metadata,
+        Some(PaymentMetadata {
+            note: Some("lunch".to_string()),
+            tags: vec!["food".to_string(), "work".to_string()],
+        })
+    );
+    let payment = storage
+        .get_payment_by_hash(payment_hash_with_lnurl_withdraw)?
+        .unwrap();
+    assert_eq!(payment.metadata, retrieve_txs[0].metadata);
+
+    // test fiat rates, the recorded ones are kept unless replaced
+    let usd = |value_micros| PaymentFiatRate {
+        coin: "USD".to_string(),
+        value_micros,
+    };
+    let eur = PaymentFiatRate {
+        coin: "EUR".to_string(),
+        value_micros: 37_000_000_000,
+    };
+    storage.insert_payment_fiat_rates(
+        payment_hash_with_lnurl_withdraw,
+        &[usd(40_000_000_000)],
+        false,
+    )?;
+    storage.insert_payment_fiat_rates(
+        payment_hash_with_lnurl_withdraw,
+        &[usd(50_000_000_000), eur.clone()],
+        false,
+    )?;
+    let payment = storage
+        .get_payment_by_hash(payment_hash_with_lnurl_withdraw)?
+        .unwrap();
+    assert_eq!(payment.fiat_rates, vec![usd(40_000_000_000), eur.clone()]);
+    storage.insert_payment_fiat_rates(
+        payment_hash_with_lnurl_withdraw,
+        &[usd(41_000_000_000)],
+        true,
+    )?;
+    let payment = storage
+        .get_payment_by_hash(payment_hash_with_lnurl_withdraw)?
+        .unwrap();
+    assert_eq!(payment.fiat_rates, vec![usd(41_000_000_000), eur]);
+
+    // test amount range
+    let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+        filters: None,
+        from_timestamp: None,
+        to_timestamp: None,
+        include_failures: Some(true),
+        offset: None,
+        limit: None,
+        cursor: None,
+        search: None,
+        min_amount_msat: Some(500),
+        max_amount_msat: Some(1000),
+        tags: None,
+    })?;
+    assert_eq!(retrieve_txs.len(), 1);
+    assert_eq!(retrieve_txs[0].id, "125");
+
     Ok(())
 }
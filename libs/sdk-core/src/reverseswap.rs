@@ -2,8 +2,9 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::boltzswap::{BoltzApiCreateReverseSwapResponse, BoltzApiReverseSwapStatus::*};
-use crate::chain::{get_utxos, ChainService, MempoolSpace, OnchainTx};
+use crate::chain::{get_utxos, ChainService, OnchainTx};
 use crate::models::{ReverseSwapServiceAPI, ReverseSwapperRoutingAPI};
+use crate::persist::storage::Storage;
 use crate::{
     BreezEvent, Config, FullReverseSwapInfo, NodeAPI, PaymentStatus, ReverseSwapInfo,
     ReverseSwapInfoCached, ReverseSwapPairInfo, ReverseSwapStatus,
@@ -61,7 +62,7 @@ pub(crate) struct BTCSendSwap {
     config: Config,
     pub(crate) reverse_swapper_api: Arc<dyn ReverseSwapperRoutingAPI>,
     pub(crate) reverse_swap_service_api: Arc<dyn ReverseSwapServiceAPI>,
-    persister: Arc<crate::persist::db::SqliteStorage>,
+    persister: Arc<dyn Storage>,
     chain_service: Arc<dyn ChainService>,
     node_api: Arc<dyn NodeAPI>,
 }
@@ -71,8 +72,8 @@ impl BTCSendSwap {
         config: Config,
         reverse_swapper_api: Arc<dyn ReverseSwapperRoutingAPI>,
         reverse_swap_service_api: Arc<dyn ReverseSwapServiceAPI>,
-        persister: Arc<crate::persist::db::SqliteStorage>,
-        chain_service: Arc<MempoolSpace>,
+        persister: Arc<dyn Storage>,
+        chain_service: Arc<dyn ChainService>,
         node_api: Arc<dyn NodeAPI>,
     ) -> Self {
         Self {
@@ -2,7 +2,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::binding::parse_invoice;
-use crate::chain::{get_utxos, AddressUtxos, ChainService, MempoolSpace, OnchainTx};
+use crate::chain::{get_utxos, AddressUtxos, ChainService, OnchainTx};
 use crate::grpc::{AddFundInitRequest, GetSwapPaymentRequest};
 use crate::{
     OpeningFeeParams, PrepareRefundRequest, PrepareRefundResponse, ReceivePaymentRequest,
@@ -24,6 +24,7 @@ use ripemd::{Digest, Ripemd160};
 
 use crate::breez_services::{BreezEvent, BreezServer, PaymentReceiver, Receiver};
 use crate::models::{Swap, SwapInfo, SwapStatus, SwapperAPI};
+use crate::persist::storage::Storage;
 
 #[tonic::async_trait]
 impl SwapperAPI for BreezServer {
@@ -71,7 +72,7 @@ impl SwapperAPI for BreezServer {
 pub(crate) struct BTCReceiveSwap {
     network: bitcoin::Network,
     swapper_api: Arc<dyn SwapperAPI>,
-    persister: Arc<crate::persist::db::SqliteStorage>,
+    persister: Arc<dyn Storage>,
     chain_service: Arc<dyn ChainService>,
     payment_receiver: Arc<dyn Receiver>,
 }
@@ -80,8 +81,8 @@ impl BTCReceiveSwap {
     pub(crate) fn new(
         network: bitcoin::Network,
         swapper_api: Arc<dyn SwapperAPI>,
-        persister: Arc<crate::persist::db::SqliteStorage>,
-        chain_service: Arc<MempoolSpace>,
+        persister: Arc<dyn Storage>,
+        chain_service: Arc<dyn ChainService>,
         payment_receiver: Arc<PaymentReceiver>,
     ) -> Self {
         Self {
@@ -879,6 +880,8 @@ mod tests {
                     lnurl_withdraw_endpoint: None,
                 },
             },
+            metadata: None,
+            fiat_rates: vec![],
         };
         persister.insert_or_update_payments(&vec![payment.clone()])?;
 
@@ -29,9 +29,12 @@ use crate::breez_services::Receiver;
 use crate::chain::{ChainService, OnchainTx, Outspend, RecommendedFees, TxStatus};
 use crate::error::SdkResult;
 use crate::fiat::{FiatCurrency, Rate};
-use crate::grpc::{PaymentInformation, RegisterPaymentReply};
+use crate::grpc::{PaymentInformation, RegisterPaymentReply, SubscribeNotificationsReply};
 use crate::lsp::LspInformation;
-use crate::models::{FiatAPI, LspAPI, NodeAPI, NodeState, Payment, Swap, SwapperAPI, SyncResponse};
+use crate::models::{
+    ChainNotifierAPI, FiatAPI, LspAPI, NodeAPI, NodeChannelPolicy, NodeState, Payment, Swap,
+    SwapperAPI, SyncResponse,
+};
 use crate::moonpay::MoonPayApi;
 use crate::swap::create_submarine_swap_script;
 use crate::{
@@ -268,6 +271,7 @@ pub struct MockNodeAPI {
     node_state: NodeState,
     on_send_custom_message: Box<dyn Fn(CustomMessage) -> Result<()> + Sync + Send>,
     on_stream_custom_messages: Mutex<mpsc::Receiver<CustomMessage>>,
+    on_connect_peer: Box<dyn Fn(String, String) -> Result<()> + Sync + Send>,
 }
 
 #[tonic::async_trait]
@@ -340,8 +344,8 @@ impl NodeAPI for MockNodeAPI {
         Ok(vec![])
     }
 
-    async fn connect_peer(&self, _node_id: String, _addr: String) -> Result<()> {
-        Ok(())
+    async fn connect_peer(&self, node_id: String, addr: String) -> Result<()> {
+        (self.on_connect_peer)(node_id, addr)
     }
 
     async fn sign_message(&self, _message: &str) -> Result<String> {
@@ -359,6 +363,15 @@ impl NodeAPI for MockNodeAPI {
     async fn close_peer_channels(&self, _node_id: String) -> Result<Vec<String>> {
         Ok(vec![])
     }
+
+    async fn fetch_channel_policy(&self, _node_id: String) -> Result<Option<NodeChannelPolicy>> {
+        Ok(Some(NodeChannelPolicy {
+            base_fee_msat: 1000,
+            fee_rate_ppm: 100,
+            cltv_expiry_delta: 144,
+            htlc_minimum_msat: 1,
+        }))
+    }
     async fn stream_incoming_payments(&self) -> Result<Streaming<gl_client::pb::IncomingPayment>> {
         Err(anyhow!("Not implemented"))
     }
@@ -409,6 +422,7 @@ impl MockNodeAPI {
                 let (_, rx) = mpsc::channel(1);
                 Mutex::new(rx)
             },
+            on_connect_peer: Box::new(|_, _| Ok(())),
         }
     }
     /// Creates a (simulated) payment for the specified BOLT11 and adds it to a test-specific
@@ -510,6 +524,13 @@ impl MockNodeAPI {
     pub async fn set_on_stream_custom_messages(&mut self, f: mpsc::Receiver<CustomMessage>) {
         *self.on_stream_custom_messages.lock().await = f;
     }
+
+    pub fn set_on_connect_peer(
+        &mut self,
+        f: Box<dyn Fn(String, String) -> Result<()> + Sync + Send>,
+    ) {
+        self.on_connect_peer = f;
+    }
 }
 
 pub struct MockBreezServer {}
@@ -556,6 +577,67 @@ impl LspAPI for MockBreezServer {
     ) -> Result<RegisterPaymentReply> {
         Ok(RegisterPaymentReply {})
     }
+
+    async fn subscribe_notifications(
+        &self,
+        _lsp_id: String,
+        _lsp_pubkey: Vec<u8>,
+        _webhook_url: String,
+        _signature: String,
+    ) -> Result<SubscribeNotificationsReply> {
+        Ok(SubscribeNotificationsReply {})
+    }
+}
+
+#[tonic::async_trait]
+impl ChainNotifierAPI for MockBreezServer {
+    async fn add_registration(
+        &self,
+        current_registration_id: Option<String>,
+        _webhook_url: String,
+    ) -> Result<String> {
+        Ok(current_registration_id.unwrap_or("registration".to_string()))
+    }
+
+    async fn subscribe_address_transactions(
+        &self,
+        _registration_id: String,
+        _webhook_url: String,
+        _address: String,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A [ChainNotifierAPI] that records the subscribed addresses
+#[derive(Default)]
+pub struct MockChainNotifierAPI {
+    pub subscribed_addresses: std::sync::Mutex<Vec<String>>,
+    pub fail_subscriptions: bool,
+}
+
+#[tonic::async_trait]
+impl ChainNotifierAPI for MockChainNotifierAPI {
+    async fn add_registration(
+        &self,
+        current_registration_id: Option<String>,
+        _webhook_url: String,
+    ) -> Result<String> {
+        Ok(current_registration_id.unwrap_or("registration".to_string()))
+    }
+
+    async fn subscribe_address_transactions(
+        &self,
+        _registration_id: String,
+        _webhook_url: String,
+        address: String,
+    ) -> Result<()> {
+        if self.fail_subscriptions {
+            return Err(anyhow!("Unreachable"));
+        }
+        self.subscribed_addresses.lock().unwrap().push(address);
+        Ok(())
+    }
 }
 
 #[tonic::async_trait]
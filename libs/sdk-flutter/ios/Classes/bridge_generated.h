@@ -45,10 +45,16 @@ typedef struct wire_NodeConfig {
   union NodeConfigKind *kind;
 } wire_NodeConfig;
 
+typedef struct wire_StringList {
+  struct wire_uint_8_list **ptr;
+  int32_t len;
+} wire_StringList;
+
 typedef struct wire_Config {
   struct wire_uint_8_list *breezserver;
   struct wire_uint_8_list *mempoolspace_url;
   struct wire_uint_8_list *working_dir;
+  struct wire_uint_8_list *storage_key;
   int32_t network;
   uint32_t payment_timeout_sec;
   struct wire_uint_8_list *default_lsp_id;
@@ -56,6 +62,11 @@ typedef struct wire_Config {
   double maxfee_percent;
   uint64_t exemptfee_msat;
   struct wire_NodeConfig node_config;
+  struct wire_StringList *fiat_rates_urls;
+  struct wire_StringList *recorded_fiat_currencies;
+  uint32_t event_log_retention;
+  bool auto_select_lsp;
+  bool persist_lsp_failover;
 } wire_Config;
 
 typedef struct wire_SignMessageRequest {
@@ -68,15 +79,45 @@ typedef struct wire_CheckMessageRequest {
   struct wire_uint_8_list *signature;
 } wire_CheckMessageRequest;
 
+typedef struct wire_ProcessNotificationRequest {
+  struct wire_uint_8_list *payload;
+  uint64_t deadline;
+} wire_ProcessNotificationRequest;
+
 typedef struct wire_StaticBackupRequest {
   struct wire_uint_8_list *working_dir;
+  struct wire_uint_8_list *seed;
+  struct wire_uint_8_list *storage_key;
 } wire_StaticBackupRequest;
 
+typedef struct wire_PrepareBuyInboundLiquidityRequest {
+  uint64_t amount_sat;
+  uint32_t lease_blocks;
+} wire_PrepareBuyInboundLiquidityRequest;
+
+typedef struct wire_BuyInboundLiquidityRequest {
+  struct wire_uint_8_list *order_id;
+} wire_BuyInboundLiquidityRequest;
+
+typedef struct wire_ChangeBackupPassphraseRequest {
+  struct wire_uint_8_list *passphrase;
+} wire_ChangeBackupPassphraseRequest;
+
+typedef struct wire_DbMaintenanceRequest {
+  uint32_t *failed_payments_retention_days;
+  uint32_t *backup_versions_to_keep;
+} wire_DbMaintenanceRequest;
+
 typedef struct wire_list_payment_type_filter {
   int32_t *ptr;
   int32_t len;
 } wire_list_payment_type_filter;
 
+typedef struct wire_PaymentsCursor {
+  int64_t payment_time;
+  struct wire_uint_8_list *id;
+} wire_PaymentsCursor;
+
 typedef struct wire_ListPaymentsRequest {
   struct wire_list_payment_type_filter *filters;
   int64_t *from_timestamp;
@@ -84,8 +125,20 @@ typedef struct wire_ListPaymentsRequest {
   bool *include_failures;
   uint32_t *offset;
   uint32_t *limit;
+  struct wire_PaymentsCursor *cursor;
+  struct wire_uint_8_list *search;
+  uint64_t *min_amount_msat;
+  uint64_t *max_amount_msat;
+  struct wire_StringList *tags;
 } wire_ListPaymentsRequest;
 
+typedef struct wire_ExportPaymentsRequest {
+  int32_t format;
+  int64_t *from_timestamp;
+  int64_t *to_timestamp;
+  struct wire_uint_8_list *fiat_currency;
+} wire_ExportPaymentsRequest;
+
 typedef struct wire_SendPaymentRequest {
   struct wire_uint_8_list *bolt11;
   uint64_t *amount_msat;
@@ -115,6 +168,22 @@ typedef struct wire_ReceivePaymentRequest {
   uint32_t *cltv;
 } wire_ReceivePaymentRequest;
 
+typedef struct wire_SendFiatPaymentRequest {
+  struct wire_uint_8_list *bolt11;
+  struct wire_uint_8_list *fiat_currency;
+  double fiat_amount;
+} wire_SendFiatPaymentRequest;
+
+typedef struct wire_ReceiveFiatPaymentRequest {
+  struct wire_uint_8_list *fiat_currency;
+  double fiat_amount;
+  struct wire_uint_8_list *description;
+  struct wire_OpeningFeeParams *opening_fee_params;
+  bool *use_description_hash;
+  uint32_t *expiry;
+  uint32_t *cltv;
+} wire_ReceiveFiatPaymentRequest;
+
 typedef struct wire_LnUrlPayRequestData {
   struct wire_uint_8_list *callback;
   uint64_t min_sendable;
@@ -225,6 +294,10 @@ void wire_sign_message(int64_t port_, struct wire_SignMessageRequest *req);
 
 void wire_check_message(int64_t port_, struct wire_CheckMessageRequest *req);
 
+void wire_process_notification(int64_t port_, struct wire_ProcessNotificationRequest *req);
+
+void wire_register_webhook(int64_t port_, struct wire_uint_8_list *webhook_url);
+
 void wire_mnemonic_to_seed(int64_t port_, struct wire_uint_8_list *phrase);
 
 void wire_default_config(int64_t port_,
@@ -236,12 +309,20 @@ void wire_static_backup(int64_t port_, struct wire_StaticBackupRequest *req);
 
 void wire_breez_events_stream(int64_t port_);
 
+void wire_add_event_stream(int64_t port_);
+
+void wire_remove_event_stream(int64_t port_, struct wire_uint_8_list *id);
+
+void wire_events_since(int64_t port_, uint64_t seq);
+
 void wire_breez_log_stream(int64_t port_);
 
 void wire_list_lsps(int64_t port_);
 
 void wire_connect_lsp(int64_t port_, struct wire_uint_8_list *lsp_id);
 
+void wire_connect_lsp_by_uri(int64_t port_, struct wire_uint_8_list *uri);
+
 void wire_lsp_id(int64_t port_);
 
 void wire_fetch_lsp_info(int64_t port_, struct wire_uint_8_list *id);
@@ -250,10 +331,33 @@ void wire_lsp_info(int64_t port_);
 
 void wire_close_lsp_channels(int64_t port_);
 
+void wire_prepare_buy_inbound_liquidity(int64_t port_,
+                                        struct wire_PrepareBuyInboundLiquidityRequest *req);
+
+void wire_buy_inbound_liquidity(int64_t port_, struct wire_BuyInboundLiquidityRequest *req);
+
 void wire_backup(int64_t port_);
 
 void wire_backup_status(int64_t port_);
 
+void wire_verify_backup(int64_t port_);
+
+void wire_list_backup_versions(int64_t port_);
+
+void wire_restore_backup(int64_t port_, uint64_t version);
+
+void wire_change_backup_passphrase(int64_t port_, struct wire_ChangeBackupPassphraseRequest *req);
+
+void wire_export_backup(int64_t port_,
+                        struct wire_uint_8_list *path,
+                        struct wire_uint_8_list *passphrase);
+
+void wire_import_backup(int64_t port_,
+                        struct wire_uint_8_list *path,
+                        struct wire_uint_8_list *passphrase);
+
+void wire_db_maintenance(int64_t port_, struct wire_DbMaintenanceRequest *req);
+
 void wire_parse_invoice(int64_t port_, struct wire_uint_8_list *invoice);
 
 void wire_parse_input(int64_t port_, struct wire_uint_8_list *input);
@@ -262,12 +366,23 @@ void wire_list_payments(int64_t port_, struct wire_ListPaymentsRequest *req);
 
 void wire_payment_by_hash(int64_t port_, struct wire_uint_8_list *hash);
 
+void wire_export_payments(int64_t port_, struct wire_ExportPaymentsRequest *req);
+
+void wire_set_payment_metadata(int64_t port_,
+                               struct wire_uint_8_list *hash,
+                               struct wire_uint_8_list *note,
+                               struct wire_StringList *tags);
+
 void wire_send_payment(int64_t port_, struct wire_SendPaymentRequest *req);
 
 void wire_send_spontaneous_payment(int64_t port_, struct wire_SendSpontaneousPaymentRequest *req);
 
 void wire_receive_payment(int64_t port_, struct wire_ReceivePaymentRequest *req);
 
+void wire_send_fiat_payment(int64_t port_, struct wire_SendFiatPaymentRequest *req);
+
+void wire_receive_fiat_payment(int64_t port_, struct wire_ReceiveFiatPaymentRequest *req);
+
 void wire_lnurl_pay(int64_t port_, struct wire_LnUrlPayRequest *req);
 
 void wire_lnurl_withdraw(int64_t port_, struct wire_LnUrlWithdrawRequest *req);
@@ -276,6 +391,18 @@ void wire_lnurl_auth(int64_t port_, struct wire_LnUrlAuthRequestData *req_data);
 
 void wire_fetch_fiat_rates(int64_t port_);
 
+void wire_fiat_rates(int64_t port_);
+
+void wire_format_fiat(int64_t port_,
+                      double amount,
+                      struct wire_uint_8_list *currency_id,
+                      struct wire_uint_8_list *locale);
+
+void wire_format_sats(int64_t port_,
+                      uint64_t amount_msat,
+                      int32_t unit,
+                      struct wire_uint_8_list *locale);
+
 void wire_list_fiat_currencies(int64_t port_);
 
 void wire_send_onchain(int64_t port_, struct wire_SendOnchainRequest *req);
@@ -306,14 +433,24 @@ void wire_recommended_fees(int64_t port_);
 
 void wire_execute_command(int64_t port_, struct wire_uint_8_list *command);
 
+struct wire_StringList *new_StringList_0(int32_t len);
+
 bool *new_box_autoadd_bool_0(bool value);
 
 struct wire_BuyBitcoinRequest *new_box_autoadd_buy_bitcoin_request_0(void);
 
+struct wire_BuyInboundLiquidityRequest *new_box_autoadd_buy_inbound_liquidity_request_0(void);
+
+struct wire_ChangeBackupPassphraseRequest *new_box_autoadd_change_backup_passphrase_request_0(void);
+
 struct wire_CheckMessageRequest *new_box_autoadd_check_message_request_0(void);
 
 struct wire_Config *new_box_autoadd_config_0(void);
 
+struct wire_DbMaintenanceRequest *new_box_autoadd_db_maintenance_request_0(void);
+
+struct wire_ExportPaymentsRequest *new_box_autoadd_export_payments_request_0(void);
+
 struct wire_GreenlightCredentials *new_box_autoadd_greenlight_credentials_0(void);
 
 struct wire_GreenlightNodeConfig *new_box_autoadd_greenlight_node_config_0(void);
@@ -334,10 +471,18 @@ struct wire_OpenChannelFeeRequest *new_box_autoadd_open_channel_fee_request_0(vo
 
 struct wire_OpeningFeeParams *new_box_autoadd_opening_fee_params_0(void);
 
+struct wire_PaymentsCursor *new_box_autoadd_payments_cursor_0(void);
+
+struct wire_PrepareBuyInboundLiquidityRequest *new_box_autoadd_prepare_buy_inbound_liquidity_request_0(void);
+
 struct wire_PrepareRefundRequest *new_box_autoadd_prepare_refund_request_0(void);
 
 struct wire_PrepareSweepRequest *new_box_autoadd_prepare_sweep_request_0(void);
 
+struct wire_ProcessNotificationRequest *new_box_autoadd_process_notification_request_0(void);
+
+struct wire_ReceiveFiatPaymentRequest *new_box_autoadd_receive_fiat_payment_request_0(void);
+
 struct wire_ReceiveOnchainRequest *new_box_autoadd_receive_onchain_request_0(void);
 
 struct wire_ReceivePaymentRequest *new_box_autoadd_receive_payment_request_0(void);
@@ -346,6 +491,8 @@ struct wire_RefundRequest *new_box_autoadd_refund_request_0(void);
 
 struct wire_ReverseSwapFeesRequest *new_box_autoadd_reverse_swap_fees_request_0(void);
 
+struct wire_SendFiatPaymentRequest *new_box_autoadd_send_fiat_payment_request_0(void);
+
 struct wire_SendOnchainRequest *new_box_autoadd_send_onchain_request_0(void);
 
 struct wire_SendPaymentRequest *new_box_autoadd_send_payment_request_0(void);
@@ -379,30 +526,52 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_disconnect);
     dummy_var ^= ((int64_t) (void*) wire_sign_message);
     dummy_var ^= ((int64_t) (void*) wire_check_message);
+    dummy_var ^= ((int64_t) (void*) wire_process_notification);
+    dummy_var ^= ((int64_t) (void*) wire_register_webhook);
     dummy_var ^= ((int64_t) (void*) wire_mnemonic_to_seed);
     dummy_var ^= ((int64_t) (void*) wire_default_config);
     dummy_var ^= ((int64_t) (void*) wire_static_backup);
     dummy_var ^= ((int64_t) (void*) wire_breez_events_stream);
+    dummy_var ^= ((int64_t) (void*) wire_add_event_stream);
+    dummy_var ^= ((int64_t) (void*) wire_remove_event_stream);
+    dummy_var ^= ((int64_t) (void*) wire_events_since);
     dummy_var ^= ((int64_t) (void*) wire_breez_log_stream);
     dummy_var ^= ((int64_t) (void*) wire_list_lsps);
     dummy_var ^= ((int64_t) (void*) wire_connect_lsp);
+    dummy_var ^= ((int64_t) (void*) wire_connect_lsp_by_uri);
     dummy_var ^= ((int64_t) (void*) wire_lsp_id);
     dummy_var ^= ((int64_t) (void*) wire_fetch_lsp_info);
     dummy_var ^= ((int64_t) (void*) wire_lsp_info);
     dummy_var ^= ((int64_t) (void*) wire_close_lsp_channels);
+    dummy_var ^= ((int64_t) (void*) wire_prepare_buy_inbound_liquidity);
+    dummy_var ^= ((int64_t) (void*) wire_buy_inbound_liquidity);
     dummy_var ^= ((int64_t) (void*) wire_backup);
     dummy_var ^= ((int64_t) (void*) wire_backup_status);
+    dummy_var ^= ((int64_t) (void*) wire_verify_backup);
+    dummy_var ^= ((int64_t) (void*) wire_list_backup_versions);
+    dummy_var ^= ((int64_t) (void*) wire_restore_backup);
+    dummy_var ^= ((int64_t) (void*) wire_change_backup_passphrase);
+    dummy_var ^= ((int64_t) (void*) wire_export_backup);
+    dummy_var ^= ((int64_t) (void*) wire_import_backup);
+    dummy_var ^= ((int64_t) (void*) wire_db_maintenance);
     dummy_var ^= ((int64_t) (void*) wire_parse_invoice);
     dummy_var ^= ((int64_t) (void*) wire_parse_input);
     dummy_var ^= ((int64_t) (void*) wire_list_payments);
     dummy_var ^= ((int64_t) (void*) wire_payment_by_hash);
+    dummy_var ^= ((int64_t) (void*) wire_export_payments);
+    dummy_var ^= ((int64_t) (void*) wire_set_payment_metadata);
     dummy_var ^= ((int64_t) (void*) wire_send_payment);
     dummy_var ^= ((int64_t) (void*) wire_send_spontaneous_payment);
     dummy_var ^= ((int64_t) (void*) wire_receive_payment);
+    dummy_var ^= ((int64_t) (void*) wire_send_fiat_payment);
+    dummy_var ^= ((int64_t) (void*) wire_receive_fiat_payment);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_pay);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_withdraw);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_auth);
     dummy_var ^= ((int64_t) (void*) wire_fetch_fiat_rates);
+    dummy_var ^= ((int64_t) (void*) wire_fiat_rates);
+    dummy_var ^= ((int64_t) (void*) wire_format_fiat);
+    dummy_var ^= ((int64_t) (void*) wire_format_sats);
     dummy_var ^= ((int64_t) (void*) wire_list_fiat_currencies);
     dummy_var ^= ((int64_t) (void*) wire_send_onchain);
     dummy_var ^= ((int64_t) (void*) wire_receive_onchain);
@@ -418,10 +587,15 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_fetch_reverse_swap_fees);
     dummy_var ^= ((int64_t) (void*) wire_recommended_fees);
     dummy_var ^= ((int64_t) (void*) wire_execute_command);
+    dummy_var ^= ((int64_t) (void*) new_StringList_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_bool_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_buy_bitcoin_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_buy_inbound_liquidity_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_change_backup_passphrase_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_check_message_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_db_maintenance_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_export_payments_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_greenlight_credentials_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_greenlight_node_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_i64_0);
@@ -432,12 +606,17 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_node_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_open_channel_fee_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_opening_fee_params_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_payments_cursor_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_buy_inbound_liquidity_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_refund_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_sweep_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_process_notification_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_receive_fiat_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_receive_onchain_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_receive_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_refund_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_reverse_swap_fees_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_send_fiat_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_send_onchain_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_send_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_send_spontaneous_payment_request_0);
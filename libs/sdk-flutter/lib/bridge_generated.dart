@@ -49,6 +49,17 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kCheckMessageConstMeta;
 
+  /// See [BreezServices::process_notification]
+  Future<ProcessNotificationResult> processNotification(
+      {required ProcessNotificationRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kProcessNotificationConstMeta;
+
+  /// See [BreezServices::register_webhook]
+  Future<void> registerWebhook({required String webhookUrl, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRegisterWebhookConstMeta;
+
   /// See [breez_services::mnemonic_to_seed]
   Future<Uint8List> mnemonicToSeed({required String phrase, dynamic hint});
 
@@ -73,6 +84,24 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kBreezEventsStreamConstMeta;
 
+  /// Adds a stream of SDK events, next to the one set with [breez_events_stream]. Unlike it, this
+  /// must be called after `connect`.
+  ///
+  /// Returns the id to pass to [remove_event_stream].
+  Stream<PersistedEvent> addEventStream({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAddEventStreamConstMeta;
+
+  /// See [BreezServices::remove_event_listener]
+  Future<void> removeEventStream({required String id, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRemoveEventStreamConstMeta;
+
+  /// See [BreezServices::events_since]
+  Future<List<PersistedEvent>> eventsSince({required int seq, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kEventsSinceConstMeta;
+
   /// If used, this must be called before `connect`. It can only be called once.
   Stream<LogEntry> breezLogStream({dynamic hint});
 
@@ -88,6 +117,11 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kConnectLspConstMeta;
 
+  /// See [BreezServices::connect_lsp_by_uri]
+  Future<LspInformation> connectLspByUri({required String uri, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kConnectLspByUriConstMeta;
+
   /// See [BreezServices::lsp_id]
   Future<String?> lspId({dynamic hint});
 
@@ -108,6 +142,18 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kCloseLspChannelsConstMeta;
 
+  /// See [BreezServices::prepare_buy_inbound_liquidity]
+  Future<PrepareBuyInboundLiquidityResponse> prepareBuyInboundLiquidity(
+      {required PrepareBuyInboundLiquidityRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPrepareBuyInboundLiquidityConstMeta;
+
+  /// See [BreezServices::buy_inbound_liquidity]
+  Future<BuyInboundLiquidityResponse> buyInboundLiquidity(
+      {required BuyInboundLiquidityRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBuyInboundLiquidityConstMeta;
+
   /// See [BreezServices::backup]
   Future<void> backup({dynamic hint});
 
@@ -118,6 +164,41 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kBackupStatusConstMeta;
 
+  /// See [BreezServices::verify_backup]
+  Future<BackupVerificationReport> verifyBackup({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kVerifyBackupConstMeta;
+
+  /// See [BreezServices::list_backup_versions]
+  Future<List<BackupVersion>> listBackupVersions({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListBackupVersionsConstMeta;
+
+  /// See [BreezServices::restore_backup]
+  Future<void> restoreBackup({required int version, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRestoreBackupConstMeta;
+
+  /// See [BreezServices::change_backup_passphrase]
+  Future<void> changeBackupPassphrase({required ChangeBackupPassphraseRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kChangeBackupPassphraseConstMeta;
+
+  /// See [BreezServices::export_backup]
+  Future<void> exportBackup({required String path, required String passphrase, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportBackupConstMeta;
+
+  /// See [BreezServices::import_backup]
+  Future<void> importBackup({required String path, required String passphrase, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kImportBackupConstMeta;
+
+  /// See [BreezServices::db_maintenance]
+  Future<DbMaintenanceReport> dbMaintenance({required DbMaintenanceRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDbMaintenanceConstMeta;
+
   Future<LNInvoice> parseInvoice({required String invoice, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kParseInvoiceConstMeta;
@@ -136,6 +217,17 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kPaymentByHashConstMeta;
 
+  /// See [BreezServices::export_payments]
+  Future<String> exportPayments({required ExportPaymentsRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportPaymentsConstMeta;
+
+  /// See [BreezServices::set_payment_metadata]
+  Future<void> setPaymentMetadata(
+      {required String hash, String? note, required List<String> tags, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetPaymentMetadataConstMeta;
+
   /// See [BreezServices::send_payment]
   Future<SendPaymentResponse> sendPayment({required SendPaymentRequest req, dynamic hint});
 
@@ -152,6 +244,17 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kReceivePaymentConstMeta;
 
+  /// See [BreezServices::send_fiat_payment]
+  Future<SendFiatPaymentResponse> sendFiatPayment({required SendFiatPaymentRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSendFiatPaymentConstMeta;
+
+  /// See [BreezServices::receive_fiat_payment]
+  Future<ReceiveFiatPaymentResponse> receiveFiatPayment(
+      {required ReceiveFiatPaymentRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceiveFiatPaymentConstMeta;
+
   /// See [BreezServices::lnurl_pay]
   Future<LnUrlPayResult> lnurlPay({required LnUrlPayRequest req, dynamic hint});
 
@@ -172,6 +275,23 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kFetchFiatRatesConstMeta;
 
+  /// See [BreezServices::fiat_rates]
+  Future<FiatRates> fiatRates({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFiatRatesConstMeta;
+
+  /// See [crate::format_fiat]
+  Future<String> formatFiat(
+      {required double amount, required String currencyId, required String locale, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFormatFiatConstMeta;
+
+  /// See [crate::format_sats]
+  Future<String> formatSats(
+      {required int amountMsat, required BitcoinUnit unit, required String locale, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFormatSatsConstMeta;
+
   /// See [BreezServices::list_fiat_currencies]
   Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint});
 
@@ -280,6 +400,61 @@ class BackupStatus {
   });
 }
 
+/// Comparison of a synced table between the local state and the remote backup
+class BackupTableDiff {
+  final String table;
+  final int localRows;
+  final int remoteRows;
+
+  /// Whether the table content is the same, compared by hashing all the rows
+  final bool inSync;
+
+  const BackupTableDiff({
+    required this.table,
+    required this.localRows,
+    required this.remoteRows,
+    required this.inSync,
+  });
+}
+
+/// Result of [crate::BreezServices::verify_backup]
+class BackupVerificationReport {
+  /// The generation of the remote backup, `None` if there is no remote backup yet
+  final int? remoteGeneration;
+
+  /// The last backup version pushed from this instance
+  final int? localVersion;
+
+  /// Whether the remote backup could be opened and passed the SQLite integrity check
+  final bool integrityOk;
+
+  /// Whether all the synced tables have the same content locally and in the remote backup
+  final bool inSync;
+  final List<BackupTableDiff> diff;
+
+  const BackupVerificationReport({
+    this.remoteGeneration,
+    this.localVersion,
+    required this.integrityOk,
+    required this.inSync,
+    required this.diff,
+  });
+}
+
+/// A version of the backup that was pushed to the remote storage and can be restored
+/// with [crate::BreezServices::restore_backup]
+class BackupVersion {
+  final int version;
+
+  /// The time this version was pushed, formatted as `YYYY-MM-DD HH:MM:SS` (UTC)
+  final String createdAt;
+
+  const BackupVersion({
+    required this.version,
+    required this.createdAt,
+  });
+}
+
 /// Wrapped in a [BitcoinAddress], this is the result of [parse] when given a plain or BIP-21 BTC address.
 class BitcoinAddressData {
   final String address;
@@ -297,6 +472,13 @@ class BitcoinAddressData {
   });
 }
 
+/// The unit in which a bitcoin amount is formatted, see [format_sats]
+enum BitcoinUnit {
+  Btc,
+  Sat,
+  Msat,
+}
+
 @freezed
 class BreezEvent with _$BreezEvent {
   /// Indicates that a new block has just been found
@@ -332,6 +514,43 @@ class BreezEvent with _$BreezEvent {
   const factory BreezEvent.backupFailed({
     required BackupFailedData details,
   }) = BreezEvent_BackupFailed;
+
+  /// Indicates that changes pushed to the remote backup by another app were merged into the local state
+  const factory BreezEvent.remoteStateChanged() = BreezEvent_RemoteStateChanged;
+
+  /// Indicates that a channel has been opened and is ready to be used
+  const factory BreezEvent.channelOpened({
+    required String fundingTxid,
+  }) = BreezEvent_ChannelOpened;
+
+  /// Indicates that the closing of a channel has been initiated
+  const factory BreezEvent.channelPendingClose({
+    required String fundingTxid,
+  }) = BreezEvent_ChannelPendingClose;
+
+  /// Indicates that a channel has been closed. The closing txid may be unknown if it could not
+  /// be retrieved.
+  const factory BreezEvent.channelClosed({
+    required String fundingTxid,
+    String? closingTxid,
+  }) = BreezEvent_ChannelClosed;
+
+  /// Indicates that the balance of the node has changed
+  const factory BreezEvent.balanceChanged({
+    required NodeBalance previous,
+    required NodeBalance current,
+  }) = BreezEvent_BalanceChanged;
+
+  /// Indicates that an order bought with [BreezServices::buy_inbound_liquidity] has changed
+  const factory BreezEvent.liquidityOrderUpdated({
+    required LiquidityOrder details,
+  }) = BreezEvent_LiquidityOrderUpdated;
+
+  /// Indicates that the local sync state was restored from a backup version, see
+  /// [BreezServices::restore_backup]
+  const factory BreezEvent.backupRestored({
+    required int version,
+  }) = BreezEvent_BackupRestored;
 }
 
 /// Different providers will demand different behaviours when the user is trying to buy bitcoin.
@@ -359,6 +578,33 @@ class BuyBitcoinResponse {
   });
 }
 
+/// Request to pay an order prepared with [crate::BreezServices::prepare_buy_inbound_liquidity],
+/// see [crate::BreezServices::buy_inbound_liquidity]
+class BuyInboundLiquidityRequest {
+  final String orderId;
+
+  const BuyInboundLiquidityRequest({
+    required this.orderId,
+  });
+}
+
+class BuyInboundLiquidityResponse {
+  final LiquidityOrder order;
+
+  const BuyInboundLiquidityResponse({
+    required this.order,
+  });
+}
+
+/// Represents a request to set, change or remove (when `passphrase` is `None`) the backup passphrase
+class ChangeBackupPassphraseRequest {
+  final String? passphrase;
+
+  const ChangeBackupPassphraseRequest({
+    this.passphrase,
+  });
+}
+
 /// State of a Lightning channel
 enum ChannelState {
   PendingOpen,
@@ -424,6 +670,10 @@ class Config {
   /// Directory in which all SDK files (DB, log) are stored. Defaults to ".", otherwise if it's customized,
   /// the folder should exist before starting the SDK.
   final String workingDir;
+
+  /// The 32 bytes key used to encrypt the local databases. When not set, the key is derived
+  /// from the seed.
+  final Uint8List? storageKey;
   final Network network;
   final int paymentTimeoutSec;
   final String? defaultLspId;
@@ -436,10 +686,31 @@ class Config {
   final int exemptfeeMsat;
   final NodeConfig nodeConfig;
 
+  /// URLs of additional fiat rate feeds, whose rates are aggregated with the ones of the Breez
+  /// server. Each must respond to a GET request with a JSON list of [Rate].
+  final List<String> fiatRatesUrls;
+
+  /// The fiat currencies whose rates are recorded with each payment, see [Payment::fiat_rates]
+  final List<String> recordedFiatCurrencies;
+
+  /// Number of emitted events kept in the event log, see [crate::BreezServices::events_since].
+  /// Set to 0 to disable the event log.
+  final int eventLogRetention;
+
+  /// Whether the LSP is selected automatically: the best ranked one when none is selected, and
+  /// another one when the selected one is unreachable. LSPs are ranked by reachability, their
+  /// fees, the inbound liquidity of the channels with them and their channel capacity.
+  final bool autoSelectLsp;
+
+  /// Whether the LSP used when the selected one is unreachable replaces the selected one. By
+  /// default, it is only used for the failed over call. Requires [Config::auto_select_lsp].
+  final bool persistLspFailover;
+
   const Config({
     required this.breezserver,
     required this.mempoolspaceUrl,
     required this.workingDir,
+    this.storageKey,
     required this.network,
     required this.paymentTimeoutSec,
     this.defaultLspId,
@@ -447,6 +718,11 @@ class Config {
     required this.maxfeePercent,
     required this.exemptfeeMsat,
     required this.nodeConfig,
+    required this.fiatRatesUrls,
+    required this.recordedFiatCurrencies,
+    required this.eventLogRetention,
+    required this.autoSelectLsp,
+    required this.persistLspFailover,
   });
 }
 
@@ -471,12 +747,76 @@ class CurrencyInfo {
   });
 }
 
+/// Result of [crate::BreezServices::db_maintenance]
+class DbMaintenanceReport {
+  /// Whether the main DB passed the integrity check. If not, it was rebuilt from the node.
+  final bool mainDbOk;
+
+  /// Whether the sync DB passed the integrity check. If not, it was restored from the remote backup.
+  final bool syncDbOk;
+
+  /// Number of payments pulled from the node when rebuilding the main DB
+  final int recoveredPayments;
+
+  /// Number of swaps restored from the remote backup when restoring the sync DB
+  final int recoveredSwaps;
+
+  /// Number of reverse swaps restored from the remote backup when restoring the sync DB
+  final int recoveredReverseSwaps;
+  final int prunedFailedPayments;
+  final int prunedBackupVersions;
+  final int prunedCachedItems;
+
+  const DbMaintenanceReport({
+    required this.mainDbOk,
+    required this.syncDbOk,
+    required this.recoveredPayments,
+    required this.recoveredSwaps,
+    required this.recoveredReverseSwaps,
+    required this.prunedFailedPayments,
+    required this.prunedBackupVersions,
+    required this.prunedCachedItems,
+  });
+}
+
+/// Represents a database maintenance request, see [crate::BreezServices::db_maintenance]
+class DbMaintenanceRequest {
+  /// Failed payments older than this number of days are deleted. Nothing is deleted if not set.
+  final int? failedPaymentsRetentionDays;
+
+  /// Number of most recent backup versions to keep in the versions history. All the versions
+  /// are kept if not set.
+  final int? backupVersionsToKeep;
+
+  const DbMaintenanceRequest({
+    this.failedPaymentsRetentionDays,
+    this.backupVersionsToKeep,
+  });
+}
+
 /// Indicates the different kinds of supported environments for [crate::BreezServices].
 enum EnvironmentType {
   Production,
   Staging,
 }
 
+/// Represents a payment history export request, see [crate::BreezServices::export_payments]
+class ExportPaymentsRequest {
+  final PaymentExportFormat format;
+  final int? fromTimestamp;
+  final int? toTimestamp;
+
+  /// The id of the fiat currency, e.g. "USD", in which the payment amounts are also given
+  final String? fiatCurrency;
+
+  const ExportPaymentsRequest({
+    required this.format,
+    this.fromTimestamp,
+    this.toTimestamp,
+    this.fiatCurrency,
+  });
+}
+
 /// Wrapper around the [CurrencyInfo] of a fiat currency
 class FiatCurrency {
   final String id;
@@ -488,6 +828,45 @@ class FiatCurrency {
   });
 }
 
+/// The conversion of a fiat amount to millisatoshis, locked in for a payment
+class FiatQuote {
+  final String fiatCurrency;
+  final double fiatAmount;
+
+  /// The price of one bitcoin in the fiat currency
+  final double rate;
+  final int amountMsat;
+
+  /// The time until which the quote holds, which is the expiry of the invoice, in seconds
+  /// since epoch
+  final int expiresAt;
+
+  const FiatQuote({
+    required this.fiatCurrency,
+    required this.fiatAmount,
+    required this.rate,
+    required this.amountMsat,
+    required this.expiresAt,
+  });
+}
+
+/// Fiat rates along with the time they were fetched
+class FiatRates {
+  final List<Rate> rates;
+
+  /// The time the rates were fetched, in seconds since epoch
+  final int fetchedAt;
+
+  /// Whether the rates could not be refreshed, in which case the last known rates are returned
+  final bool isStale;
+
+  const FiatRates({
+    required this.rates,
+    required this.fetchedAt,
+    required this.isStale,
+  });
+}
+
 /// Client-specific credentials to connect to and manage a Greenlight node in the cloud
 class GreenlightCredentials {
   final Uint8List deviceKey;
@@ -579,6 +958,44 @@ class InvoicePaidDetails {
   });
 }
 
+/// An order for a channel with inbound liquidity, bought from the LSP with LSPS1
+class LiquidityOrder {
+  final String orderId;
+  final LiquidityOrderState state;
+  final int lspBalanceSat;
+  final int leaseBlocks;
+  final int feeSat;
+  final String bolt11;
+
+  /// The funding outpoint of the channel, once it's opened
+  final String? fundingOutpoint;
+
+  const LiquidityOrder({
+    required this.orderId,
+    required this.state,
+    required this.lspBalanceSat,
+    required this.leaseBlocks,
+    required this.feeSat,
+    required this.bolt11,
+    this.fundingOutpoint,
+  });
+}
+
+/// The state of a [LiquidityOrder]
+enum LiquidityOrderState {
+  /// The order is waiting for its invoice to be paid
+  Created,
+
+  /// The invoice is paid and the LSP is opening the channel
+  Paid,
+
+  /// The channel is open
+  Completed,
+
+  /// The channel could not be opened. A paid invoice is refunded by the LSP.
+  Failed,
+}
+
 /// Represents a list payments request.
 class ListPaymentsRequest {
   final List<PaymentTypeFilter>? filters;
@@ -588,6 +1005,17 @@ class ListPaymentsRequest {
   final int? offset;
   final int? limit;
 
+  /// Only list the payments that come after this one, see [PaymentsCursor]
+  final PaymentsCursor? cursor;
+
+  /// Text matched against the description, LNURL metadata, Lightning address and payment hash
+  final String? search;
+  final int? minAmountMsat;
+  final int? maxAmountMsat;
+
+  /// Only list the payments that have at least one of these tags
+  final List<String>? tags;
+
   const ListPaymentsRequest({
     this.filters,
     this.fromTimestamp,
@@ -595,6 +1023,11 @@ class ListPaymentsRequest {
     this.includeFailures,
     this.offset,
     this.limit,
+    this.cursor,
+    this.search,
+    this.minAmountMsat,
+    this.maxAmountMsat,
+    this.tags,
   });
 }
 
@@ -949,6 +1382,17 @@ enum Network {
   Regtest,
 }
 
+/// The balances of the node, included as payload in [BreezEvent::BalanceChanged]
+class NodeBalance {
+  final int channelsBalanceMsat;
+  final int onchainBalanceMsat;
+
+  const NodeBalance({
+    required this.channelsBalanceMsat,
+    required this.onchainBalanceMsat,
+  });
+}
+
 @freezed
 class NodeConfig with _$NodeConfig {
   const factory NodeConfig.greenlight({
@@ -1054,6 +1498,16 @@ class Payment {
   final String? description;
   final PaymentDetails details;
 
+  /// The note and tags set by the app, see [crate::BreezServices::set_payment_metadata]
+  final PaymentMetadata? metadata;
+
+  /// The rates at the time the payment was completed of the currencies in
+  /// [Config::recorded_fiat_currencies] and, for payments made with
+  /// [crate::BreezServices::send_fiat_payment] or [crate::BreezServices::receive_fiat_payment],
+  /// of the quoted currency. Empty for payments made before rates were recorded, or if they
+  /// couldn't be fetched at that time.
+  final List<PaymentFiatRate> fiatRates;
+
   const Payment({
     required this.id,
     required this.paymentType,
@@ -1063,6 +1517,8 @@ class Payment {
     required this.status,
     this.description,
     required this.details,
+    this.metadata,
+    required this.fiatRates,
   });
 }
 
@@ -1076,6 +1532,12 @@ class PaymentDetails with _$PaymentDetails {
   }) = PaymentDetails_ClosedChannel;
 }
 
+/// The file format of a payment history export
+enum PaymentExportFormat {
+  Csv,
+  Json,
+}
+
 class PaymentFailedData {
   final String error;
   final String nodeId;
@@ -1088,6 +1550,31 @@ class PaymentFailedData {
   });
 }
 
+/// The rate of a fiat currency at the time a [crate::Payment] was made
+class PaymentFiatRate {
+  final String coin;
+
+  /// The price of one bitcoin in the currency, in millionths of the currency unit. Unlike
+  /// [Rate::value], it keeps [crate::Payment] comparable with `Eq`.
+  final int valueMicros;
+
+  const PaymentFiatRate({
+    required this.coin,
+    required this.valueMicros,
+  });
+}
+
+/// App-level metadata attached to a [Payment], which is synced with the backup
+class PaymentMetadata {
+  final String? note;
+  final List<String> tags;
+
+  const PaymentMetadata({
+    this.note,
+    required this.tags,
+  });
+}
+
 /// The status of a payment
 enum PaymentStatus {
   Pending,
@@ -1109,6 +1596,62 @@ enum PaymentTypeFilter {
   ClosedChannels,
 }
 
+/// Position in the payments list, which is ordered by payment time and id, newest first.
+///
+/// To fetch the next page, use the `payment_time` and `id` of the last payment of the current
+/// one. Unlike an offset, the cursor stays valid when new payments are added.
+class PaymentsCursor {
+  final int paymentTime;
+  final String id;
+
+  const PaymentsCursor({
+    required this.paymentTime,
+    required this.id,
+  });
+}
+
+/// An emitted [BreezEvent], as kept in the event log
+class PersistedEvent {
+  /// Increasing sequence number of the event, to pass to [BreezServices::events_since] to get
+  /// the events emitted after it. It is 0 for the events that were not logged, because they
+  /// are not kept in the event log, it is disabled or it couldn't be written.
+  final int seq;
+
+  /// Epoch time, in seconds, at which the event was emitted
+  final int createdAt;
+  final BreezEvent event;
+
+  const PersistedEvent({
+    required this.seq,
+    required this.createdAt,
+    required this.event,
+  });
+}
+
+/// Request to order a channel with inbound liquidity from the LSP, see
+/// [crate::BreezServices::prepare_buy_inbound_liquidity]
+class PrepareBuyInboundLiquidityRequest {
+  /// The inbound liquidity, i.e. the balance on the LSP side of the channel
+  final int amountSat;
+
+  /// The number of blocks the LSP commits to keep the channel open
+  final int leaseBlocks;
+
+  const PrepareBuyInboundLiquidityRequest({
+    required this.amountSat,
+    required this.leaseBlocks,
+  });
+}
+
+class PrepareBuyInboundLiquidityResponse {
+  /// The created order, whose [LiquidityOrder::fee_sat] is paid when buying it
+  final LiquidityOrder order;
+
+  const PrepareBuyInboundLiquidityResponse({
+    required this.order,
+  });
+}
+
 class PrepareRefundRequest {
   final String swapAddress;
   final String toAddress;
@@ -1156,6 +1699,39 @@ class PrepareSweepResponse {
   });
 }
 
+/// Represents a request to process a notification received on the registered webhook.
+///
+/// See [crate::BreezServices::process_notification]
+class ProcessNotificationRequest {
+  /// The JSON payload posted to the webhook
+  final String payload;
+
+  /// The time by which the processing has to finish, in seconds since epoch. It should leave
+  /// some margin before the deadline of the OS to run in the background.
+  final int deadline;
+
+  const ProcessNotificationRequest({
+    required this.payload,
+    required this.deadline,
+  });
+}
+
+@freezed
+class ProcessNotificationResult with _$ProcessNotificationResult {
+  /// The payment of the notification was received
+  const factory ProcessNotificationResult.paymentReceived({
+    required Payment payment,
+  }) = ProcessNotificationResult_PaymentReceived;
+
+  /// The swap of the notified address was updated and, if possible, redeemed
+  const factory ProcessNotificationResult.swapUpdated({
+    required SwapInfo swapInfo,
+  }) = ProcessNotificationResult_SwapUpdated;
+
+  /// The processing didn't finish before the deadline
+  const factory ProcessNotificationResult.deadlineReached() = ProcessNotificationResult_DeadlineReached;
+}
+
 /// Denominator in an exchange rate
 class Rate {
   final String coin;
@@ -1167,6 +1743,58 @@ class Rate {
   });
 }
 
+/// Represents a receive payment request where the amount is given in a fiat currency.
+///
+/// See [crate::BreezServices::receive_fiat_payment]
+class ReceiveFiatPaymentRequest {
+  /// The id of the fiat currency, as in [crate::FiatCurrency::id]
+  final String fiatCurrency;
+
+  /// The amount in the fiat currency
+  final double fiatAmount;
+
+  /// The description for this payment request. The fiat amount and rate are appended to it.
+  final String description;
+
+  /// If set and valid, these fess options are used when a new channels is needed.
+  /// Otherwise the default fee options will be used.
+  final OpeningFeeParams? openingFeeParams;
+
+  /// If set to true, then the bolt11 invoice returned includes the description hash.
+  final bool? useDescriptionHash;
+
+  /// if specified, set the time the invoice is valid for, in seconds.
+  final int? expiry;
+
+  /// if specified, sets the min_final_cltv_expiry for the invoice
+  final int? cltv;
+
+  const ReceiveFiatPaymentRequest({
+    required this.fiatCurrency,
+    required this.fiatAmount,
+    required this.description,
+    this.openingFeeParams,
+    this.useDescriptionHash,
+    this.expiry,
+    this.cltv,
+  });
+}
+
+/// Represents a receive fiat payment response.
+class ReceiveFiatPaymentResponse {
+  final LNInvoice lnInvoice;
+  final OpeningFeeParams? openingFeeParams;
+  final int? openingFeeMsat;
+  final FiatQuote quote;
+
+  const ReceiveFiatPaymentResponse({
+    required this.lnInvoice,
+    this.openingFeeParams,
+    this.openingFeeMsat,
+    required this.quote,
+  });
+}
+
 class ReceiveOnchainRequest {
   final OpeningFeeParams? openingFeeParams;
 
@@ -1395,6 +2023,37 @@ class RouteHintHop {
   });
 }
 
+/// Represents a send payment request where the amount is given in a fiat currency.
+///
+/// See [crate::BreezServices::send_fiat_payment]
+class SendFiatPaymentRequest {
+  /// The bolt11 invoice, which must not have an amount
+  final String bolt11;
+
+  /// The id of the fiat currency, as in [crate::FiatCurrency::id]
+  final String fiatCurrency;
+
+  /// The amount to pay in the fiat currency
+  final double fiatAmount;
+
+  const SendFiatPaymentRequest({
+    required this.bolt11,
+    required this.fiatCurrency,
+    required this.fiatAmount,
+  });
+}
+
+/// Represents a send fiat payment response.
+class SendFiatPaymentResponse {
+  final Payment payment;
+  final FiatQuote quote;
+
+  const SendFiatPaymentResponse({
+    required this.payment,
+    required this.quote,
+  });
+}
+
 class SendOnchainRequest {
   final int amountSat;
   final String onchainRecipientAddress;
@@ -1478,8 +2137,18 @@ class SignMessageResponse {
 class StaticBackupRequest {
   final String workingDir;
 
+  /// The seed used to connect, needed to read the storage as it is encrypted with a key
+  /// derived from it
+  final Uint8List? seed;
+
+  /// The key the storage is encrypted with, when set with [Config::storage_key]. Takes
+  /// precedence over the seed.
+  final Uint8List? storageKey;
+
   const StaticBackupRequest({
     required this.workingDir,
+    this.seed,
+    this.storageKey,
   });
 }
 
@@ -1756,7 +2425,40 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["req"],
       );
 
-  Future<Uint8List> mnemonicToSeed({required String phrase, dynamic hint}) {
+  Future<ProcessNotificationResult> processNotification(
+      {required ProcessNotificationRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_process_notification_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_process_notification(port_, arg0),
+      parseSuccessData: _wire2api_process_notification_result,
+      constMeta: kProcessNotificationConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kProcessNotificationConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "process_notification",
+        argNames: ["req"],
+      );
+
+  Future<void> registerWebhook({required String webhookUrl, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(webhookUrl);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_register_webhook(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kRegisterWebhookConstMeta,
+      argValues: [webhookUrl],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRegisterWebhookConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "register_webhook",
+        argNames: ["webhookUrl"],
+      );
+
+  Future<Uint8List> mnemonicToSeed({required String phrase, dynamic hint}) {
     var arg0 = _platform.api2wire_String(phrase);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_mnemonic_to_seed(port_, arg0),
@@ -1825,6 +2527,53 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Stream<PersistedEvent> addEventStream({dynamic hint}) {
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_add_event_stream(port_),
+      parseSuccessData: _wire2api_persisted_event,
+      constMeta: kAddEventStreamConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAddEventStreamConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "add_event_stream",
+        argNames: [],
+      );
+
+  Future<void> removeEventStream({required String id, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(id);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_remove_event_stream(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kRemoveEventStreamConstMeta,
+      argValues: [id],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRemoveEventStreamConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "remove_event_stream",
+        argNames: ["id"],
+      );
+
+  Future<List<PersistedEvent>> eventsSince({required int seq, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(seq);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_events_since(port_, arg0),
+      parseSuccessData: _wire2api_list_persisted_event,
+      constMeta: kEventsSinceConstMeta,
+      argValues: [seq],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kEventsSinceConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "events_since",
+        argNames: ["seq"],
+      );
+
   Stream<LogEntry> breezLogStream({dynamic hint}) {
     return _platform.executeStream(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_breez_log_stream(port_),
@@ -1871,6 +2620,22 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["lspId"],
       );
 
+  Future<LspInformation> connectLspByUri({required String uri, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(uri);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_connect_lsp_by_uri(port_, arg0),
+      parseSuccessData: _wire2api_lsp_information,
+      constMeta: kConnectLspByUriConstMeta,
+      argValues: [uri],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kConnectLspByUriConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "connect_lsp_by_uri",
+        argNames: ["uri"],
+      );
+
   Future<String?> lspId({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_lsp_id(port_),
@@ -1932,6 +2697,40 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<PrepareBuyInboundLiquidityResponse> prepareBuyInboundLiquidity(
+      {required PrepareBuyInboundLiquidityRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_prepare_buy_inbound_liquidity_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prepare_buy_inbound_liquidity(port_, arg0),
+      parseSuccessData: _wire2api_prepare_buy_inbound_liquidity_response,
+      constMeta: kPrepareBuyInboundLiquidityConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPrepareBuyInboundLiquidityConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_buy_inbound_liquidity",
+        argNames: ["req"],
+      );
+
+  Future<BuyInboundLiquidityResponse> buyInboundLiquidity(
+      {required BuyInboundLiquidityRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_buy_inbound_liquidity_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_buy_inbound_liquidity(port_, arg0),
+      parseSuccessData: _wire2api_buy_inbound_liquidity_response,
+      constMeta: kBuyInboundLiquidityConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBuyInboundLiquidityConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "buy_inbound_liquidity",
+        argNames: ["req"],
+      );
+
   Future<void> backup({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_backup(port_),
@@ -1962,6 +2761,118 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<BackupVerificationReport> verifyBackup({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_verify_backup(port_),
+      parseSuccessData: _wire2api_backup_verification_report,
+      constMeta: kVerifyBackupConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kVerifyBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "verify_backup",
+        argNames: [],
+      );
+
+  Future<List<BackupVersion>> listBackupVersions({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_backup_versions(port_),
+      parseSuccessData: _wire2api_list_backup_version,
+      constMeta: kListBackupVersionsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListBackupVersionsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_backup_versions",
+        argNames: [],
+      );
+
+  Future<void> restoreBackup({required int version, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(version);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_restore_backup(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kRestoreBackupConstMeta,
+      argValues: [version],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRestoreBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "restore_backup",
+        argNames: ["version"],
+      );
+
+  Future<void> changeBackupPassphrase({required ChangeBackupPassphraseRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_change_backup_passphrase_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_change_backup_passphrase(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kChangeBackupPassphraseConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kChangeBackupPassphraseConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "change_backup_passphrase",
+        argNames: ["req"],
+      );
+
+  Future<void> exportBackup({required String path, required String passphrase, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(path);
+    var arg1 = _platform.api2wire_String(passphrase);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_export_backup(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kExportBackupConstMeta,
+      argValues: [path, passphrase],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_backup",
+        argNames: ["path", "passphrase"],
+      );
+
+  Future<void> importBackup({required String path, required String passphrase, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(path);
+    var arg1 = _platform.api2wire_String(passphrase);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_import_backup(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kImportBackupConstMeta,
+      argValues: [path, passphrase],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kImportBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "import_backup",
+        argNames: ["path", "passphrase"],
+      );
+
+  Future<DbMaintenanceReport> dbMaintenance({required DbMaintenanceRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_db_maintenance_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_db_maintenance(port_, arg0),
+      parseSuccessData: _wire2api_db_maintenance_report,
+      constMeta: kDbMaintenanceConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDbMaintenanceConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "db_maintenance",
+        argNames: ["req"],
+      );
+
   Future<LNInvoice> parseInvoice({required String invoice, dynamic hint}) {
     var arg0 = _platform.api2wire_String(invoice);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2026,6 +2937,41 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["hash"],
       );
 
+  Future<String> exportPayments({required ExportPaymentsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_export_payments_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_export_payments(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kExportPaymentsConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportPaymentsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_payments",
+        argNames: ["req"],
+      );
+
+  Future<void> setPaymentMetadata(
+      {required String hash, String? note, required List<String> tags, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(hash);
+    var arg1 = _platform.api2wire_opt_String(note);
+    var arg2 = _platform.api2wire_StringList(tags);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_payment_metadata(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetPaymentMetadataConstMeta,
+      argValues: [hash, note, tags],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetPaymentMetadataConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_payment_metadata",
+        argNames: ["hash", "note", "tags"],
+      );
+
   Future<SendPaymentResponse> sendPayment({required SendPaymentRequest req, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_send_payment_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2075,6 +3021,39 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["req"],
       );
 
+  Future<SendFiatPaymentResponse> sendFiatPayment({required SendFiatPaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_send_fiat_payment_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_send_fiat_payment(port_, arg0),
+      parseSuccessData: _wire2api_send_fiat_payment_response,
+      constMeta: kSendFiatPaymentConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSendFiatPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "send_fiat_payment",
+        argNames: ["req"],
+      );
+
+  Future<ReceiveFiatPaymentResponse> receiveFiatPayment(
+      {required ReceiveFiatPaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_receive_fiat_payment_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_fiat_payment(port_, arg0),
+      parseSuccessData: _wire2api_receive_fiat_payment_response,
+      constMeta: kReceiveFiatPaymentConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceiveFiatPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_fiat_payment",
+        argNames: ["req"],
+      );
+
   Future<LnUrlPayResult> lnurlPay({required LnUrlPayRequest req, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_ln_url_pay_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2138,6 +3117,59 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<FiatRates> fiatRates({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_fiat_rates(port_),
+      parseSuccessData: _wire2api_fiat_rates,
+      constMeta: kFiatRatesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFiatRatesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fiat_rates",
+        argNames: [],
+      );
+
+  Future<String> formatFiat(
+      {required double amount, required String currencyId, required String locale, dynamic hint}) {
+    var arg0 = api2wire_f64(amount);
+    var arg1 = _platform.api2wire_String(currencyId);
+    var arg2 = _platform.api2wire_String(locale);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_format_fiat(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kFormatFiatConstMeta,
+      argValues: [amount, currencyId, locale],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFormatFiatConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "format_fiat",
+        argNames: ["amount", "currencyId", "locale"],
+      );
+
+  Future<String> formatSats(
+      {required int amountMsat, required BitcoinUnit unit, required String locale, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(amountMsat);
+    var arg1 = api2wire_bitcoin_unit(unit);
+    var arg2 = _platform.api2wire_String(locale);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_format_sats(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kFormatSatsConstMeta,
+      argValues: [amountMsat, unit, locale],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFormatSatsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "format_sats",
+        argNames: ["amountMsat", "unit", "locale"],
+      );
+
   Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_list_fiat_currencies(port_),
@@ -2412,6 +3444,38 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  BackupTableDiff _wire2api_backup_table_diff(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BackupTableDiff(
+      table: _wire2api_String(arr[0]),
+      localRows: _wire2api_u64(arr[1]),
+      remoteRows: _wire2api_u64(arr[2]),
+      inSync: _wire2api_bool(arr[3]),
+    );
+  }
+
+  BackupVerificationReport _wire2api_backup_verification_report(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BackupVerificationReport(
+      remoteGeneration: _wire2api_opt_box_autoadd_u64(arr[0]),
+      localVersion: _wire2api_opt_box_autoadd_u64(arr[1]),
+      integrityOk: _wire2api_bool(arr[2]),
+      inSync: _wire2api_bool(arr[3]),
+      diff: _wire2api_list_backup_table_diff(arr[4]),
+    );
+  }
+
+  BackupVersion _wire2api_backup_version(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BackupVersion(
+      version: _wire2api_u64(arr[0]),
+      createdAt: _wire2api_String(arr[1]),
+    );
+  }
+
   BitcoinAddressData _wire2api_bitcoin_address_data(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
@@ -2460,6 +3524,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_invoice_paid_details(raw);
   }
 
+  LiquidityOrder _wire2api_box_autoadd_liquidity_order(dynamic raw) {
+    return _wire2api_liquidity_order(raw);
+  }
+
   LNInvoice _wire2api_box_autoadd_ln_invoice(dynamic raw) {
     return _wire2api_ln_invoice(raw);
   }
@@ -2496,6 +3564,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_message_success_action_data(raw);
   }
 
+  NodeBalance _wire2api_box_autoadd_node_balance(dynamic raw) {
+    return _wire2api_node_balance(raw);
+  }
+
   OpeningFeeParams _wire2api_box_autoadd_opening_fee_params(dynamic raw) {
     return _wire2api_opening_fee_params(raw);
   }
@@ -2508,6 +3580,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_payment_failed_data(raw);
   }
 
+  PaymentMetadata _wire2api_box_autoadd_payment_metadata(dynamic raw) {
+    return _wire2api_payment_metadata(raw);
+  }
+
   SuccessActionProcessed _wire2api_box_autoadd_success_action_processed(dynamic raw) {
     return _wire2api_success_action_processed(raw);
   }
@@ -2560,6 +3636,34 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         return BreezEvent_BackupFailed(
           details: _wire2api_box_autoadd_backup_failed_data(raw[1]),
         );
+      case 8:
+        return BreezEvent_RemoteStateChanged();
+      case 9:
+        return BreezEvent_ChannelOpened(
+          fundingTxid: _wire2api_String(raw[1]),
+        );
+      case 10:
+        return BreezEvent_ChannelPendingClose(
+          fundingTxid: _wire2api_String(raw[1]),
+        );
+      case 11:
+        return BreezEvent_ChannelClosed(
+          fundingTxid: _wire2api_String(raw[1]),
+          closingTxid: _wire2api_opt_String(raw[2]),
+        );
+      case 12:
+        return BreezEvent_BalanceChanged(
+          previous: _wire2api_box_autoadd_node_balance(raw[1]),
+          current: _wire2api_box_autoadd_node_balance(raw[2]),
+        );
+      case 13:
+        return BreezEvent_LiquidityOrderUpdated(
+          details: _wire2api_box_autoadd_liquidity_order(raw[1]),
+        );
+      case 14:
+        return BreezEvent_BackupRestored(
+          version: _wire2api_u64(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -2574,6 +3678,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  BuyInboundLiquidityResponse _wire2api_buy_inbound_liquidity_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return BuyInboundLiquidityResponse(
+      order: _wire2api_liquidity_order(arr[0]),
+    );
+  }
+
   ChannelState _wire2api_channel_state(dynamic raw) {
     return ChannelState.values[raw as int];
   }
@@ -2599,18 +3711,24 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   Config _wire2api_config(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 16) throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
     return Config(
       breezserver: _wire2api_String(arr[0]),
       mempoolspaceUrl: _wire2api_String(arr[1]),
       workingDir: _wire2api_String(arr[2]),
-      network: _wire2api_network(arr[3]),
-      paymentTimeoutSec: _wire2api_u32(arr[4]),
-      defaultLspId: _wire2api_opt_String(arr[5]),
-      apiKey: _wire2api_opt_String(arr[6]),
-      maxfeePercent: _wire2api_f64(arr[7]),
-      exemptfeeMsat: _wire2api_u64(arr[8]),
-      nodeConfig: _wire2api_node_config(arr[9]),
+      storageKey: _wire2api_opt_uint_8_list(arr[3]),
+      network: _wire2api_network(arr[4]),
+      paymentTimeoutSec: _wire2api_u32(arr[5]),
+      defaultLspId: _wire2api_opt_String(arr[6]),
+      apiKey: _wire2api_opt_String(arr[7]),
+      maxfeePercent: _wire2api_f64(arr[8]),
+      exemptfeeMsat: _wire2api_u64(arr[9]),
+      nodeConfig: _wire2api_node_config(arr[10]),
+      fiatRatesUrls: _wire2api_StringList(arr[11]),
+      recordedFiatCurrencies: _wire2api_StringList(arr[12]),
+      eventLogRetention: _wire2api_u32(arr[13]),
+      autoSelectLsp: _wire2api_bool(arr[14]),
+      persistLspFailover: _wire2api_bool(arr[15]),
     );
   }
 
@@ -2628,6 +3746,21 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  DbMaintenanceReport _wire2api_db_maintenance_report(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return DbMaintenanceReport(
+      mainDbOk: _wire2api_bool(arr[0]),
+      syncDbOk: _wire2api_bool(arr[1]),
+      recoveredPayments: _wire2api_u32(arr[2]),
+      recoveredSwaps: _wire2api_u32(arr[3]),
+      recoveredReverseSwaps: _wire2api_u32(arr[4]),
+      prunedFailedPayments: _wire2api_u32(arr[5]),
+      prunedBackupVersions: _wire2api_u32(arr[6]),
+      prunedCachedItems: _wire2api_u32(arr[7]),
+    );
+  }
+
   double _wire2api_f64(dynamic raw) {
     return raw as double;
   }
@@ -2641,6 +3774,28 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  FiatQuote _wire2api_fiat_quote(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FiatQuote(
+      fiatCurrency: _wire2api_String(arr[0]),
+      fiatAmount: _wire2api_f64(arr[1]),
+      rate: _wire2api_f64(arr[2]),
+      amountMsat: _wire2api_u64(arr[3]),
+      expiresAt: _wire2api_u64(arr[4]),
+    );
+  }
+
+  FiatRates _wire2api_fiat_rates(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FiatRates(
+      rates: _wire2api_list_rate(arr[0]),
+      fetchedAt: _wire2api_u64(arr[1]),
+      isStale: _wire2api_bool(arr[2]),
+    );
+  }
+
   GreenlightCredentials _wire2api_greenlight_credentials(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -2715,6 +3870,32 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  LiquidityOrder _wire2api_liquidity_order(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return LiquidityOrder(
+      orderId: _wire2api_String(arr[0]),
+      state: _wire2api_liquidity_order_state(arr[1]),
+      lspBalanceSat: _wire2api_u64(arr[2]),
+      leaseBlocks: _wire2api_u32(arr[3]),
+      feeSat: _wire2api_u64(arr[4]),
+      bolt11: _wire2api_String(arr[5]),
+      fundingOutpoint: _wire2api_opt_String(arr[6]),
+    );
+  }
+
+  LiquidityOrderState _wire2api_liquidity_order_state(dynamic raw) {
+    return LiquidityOrderState.values[raw as int];
+  }
+
+  List<BackupTableDiff> _wire2api_list_backup_table_diff(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_backup_table_diff).toList();
+  }
+
+  List<BackupVersion> _wire2api_list_backup_version(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_backup_version).toList();
+  }
+
   List<FiatCurrency> _wire2api_list_fiat_currency(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_fiat_currency).toList();
   }
@@ -2739,6 +3920,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return (raw as List<dynamic>).map(_wire2api_payment).toList();
   }
 
+  List<PaymentFiatRate> _wire2api_list_payment_fiat_rate(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_payment_fiat_rate).toList();
+  }
+
+  List<PersistedEvent> _wire2api_list_persisted_event(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_persisted_event).toList();
+  }
+
   List<Rate> _wire2api_list_rate(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_rate).toList();
   }
@@ -2953,6 +4142,15 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return Network.values[raw as int];
   }
 
+  NodeBalance _wire2api_node_balance(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NodeBalance(
+      channelsBalanceMsat: _wire2api_u64(arr[0]),
+      onchainBalanceMsat: _wire2api_u64(arr[1]),
+    );
+  }
+
   NodeConfig _wire2api_node_config(dynamic raw) {
     switch (raw[0]) {
       case 0:
@@ -3044,6 +4242,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_box_autoadd_payment(raw);
   }
 
+  PaymentMetadata? _wire2api_opt_box_autoadd_payment_metadata(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_payment_metadata(raw);
+  }
+
   SuccessActionProcessed? _wire2api_opt_box_autoadd_success_action_processed(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_success_action_processed(raw);
   }
@@ -3072,9 +4274,13 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_list_localized_name(raw);
   }
 
+  Uint8List? _wire2api_opt_uint_8_list(dynamic raw) {
+    return raw == null ? null : _wire2api_uint_8_list(raw);
+  }
+
   Payment _wire2api_payment(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
     return Payment(
       id: _wire2api_String(arr[0]),
       paymentType: _wire2api_payment_type(arr[1]),
@@ -3084,6 +4290,8 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       status: _wire2api_payment_status(arr[5]),
       description: _wire2api_opt_String(arr[6]),
       details: _wire2api_payment_details(arr[7]),
+      metadata: _wire2api_opt_box_autoadd_payment_metadata(arr[8]),
+      fiatRates: _wire2api_list_payment_fiat_rate(arr[9]),
     );
   }
 
@@ -3112,6 +4320,24 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  PaymentFiatRate _wire2api_payment_fiat_rate(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaymentFiatRate(
+      coin: _wire2api_String(arr[0]),
+      valueMicros: _wire2api_u64(arr[1]),
+    );
+  }
+
+  PaymentMetadata _wire2api_payment_metadata(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaymentMetadata(
+      note: _wire2api_opt_String(arr[0]),
+      tags: _wire2api_StringList(arr[1]),
+    );
+  }
+
   PaymentStatus _wire2api_payment_status(dynamic raw) {
     return PaymentStatus.values[raw as int];
   }
@@ -3120,6 +4346,24 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return PaymentType.values[raw as int];
   }
 
+  PersistedEvent _wire2api_persisted_event(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PersistedEvent(
+      seq: _wire2api_u64(arr[0]),
+      createdAt: _wire2api_u64(arr[1]),
+      event: _wire2api_breez_event(arr[2]),
+    );
+  }
+
+  PrepareBuyInboundLiquidityResponse _wire2api_prepare_buy_inbound_liquidity_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return PrepareBuyInboundLiquidityResponse(
+      order: _wire2api_liquidity_order(arr[0]),
+    );
+  }
+
   PrepareRefundResponse _wire2api_prepare_refund_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -3138,6 +4382,23 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  ProcessNotificationResult _wire2api_process_notification_result(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return ProcessNotificationResult_PaymentReceived(
+          payment: _wire2api_box_autoadd_payment(raw[1]),
+        );
+      case 1:
+        return ProcessNotificationResult_SwapUpdated(
+          swapInfo: _wire2api_box_autoadd_swap_info(raw[1]),
+        );
+      case 2:
+        return ProcessNotificationResult_DeadlineReached();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   Rate _wire2api_rate(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -3147,6 +4408,17 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  ReceiveFiatPaymentResponse _wire2api_receive_fiat_payment_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReceiveFiatPaymentResponse(
+      lnInvoice: _wire2api_ln_invoice(arr[0]),
+      openingFeeParams: _wire2api_opt_box_autoadd_opening_fee_params(arr[1]),
+      openingFeeMsat: _wire2api_opt_box_autoadd_u64(arr[2]),
+      quote: _wire2api_fiat_quote(arr[3]),
+    );
+  }
+
   ReceivePaymentResponse _wire2api_receive_payment_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
@@ -3230,6 +4502,15 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  SendFiatPaymentResponse _wire2api_send_fiat_payment_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SendFiatPaymentResponse(
+      payment: _wire2api_payment(arr[0]),
+      quote: _wire2api_fiat_quote(arr[1]),
+    );
+  }
+
   SendOnchainResponse _wire2api_send_onchain_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3380,6 +4661,11 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
 // Section: api2wire
 
+@protected
+int api2wire_bitcoin_unit(BitcoinUnit raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 bool api2wire_bool(bool raw) {
   return raw;
@@ -3410,6 +4696,11 @@ int api2wire_network(Network raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_payment_export_format(PaymentExportFormat raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_payment_type_filter(PaymentTypeFilter raw) {
   return api2wire_i32(raw.index);
@@ -3442,6 +4733,15 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return api2wire_uint_8_list(utf8.encoder.convert(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_StringList(List<String> raw) {
+    final ans = inner.new_StringList_0(raw.length);
+    for (var i = 0; i < raw.length; i++) {
+      ans.ref.ptr[i] = api2wire_String(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> api2wire_box_autoadd_bool(bool raw) {
     return inner.new_box_autoadd_bool_0(api2wire_bool(raw));
@@ -3454,6 +4754,22 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_BuyInboundLiquidityRequest> api2wire_box_autoadd_buy_inbound_liquidity_request(
+      BuyInboundLiquidityRequest raw) {
+    final ptr = inner.new_box_autoadd_buy_inbound_liquidity_request_0();
+    _api_fill_to_wire_buy_inbound_liquidity_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ChangeBackupPassphraseRequest> api2wire_box_autoadd_change_backup_passphrase_request(
+      ChangeBackupPassphraseRequest raw) {
+    final ptr = inner.new_box_autoadd_change_backup_passphrase_request_0();
+    _api_fill_to_wire_change_backup_passphrase_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_CheckMessageRequest> api2wire_box_autoadd_check_message_request(CheckMessageRequest raw) {
     final ptr = inner.new_box_autoadd_check_message_request_0();
@@ -3468,6 +4784,22 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_DbMaintenanceRequest> api2wire_box_autoadd_db_maintenance_request(
+      DbMaintenanceRequest raw) {
+    final ptr = inner.new_box_autoadd_db_maintenance_request_0();
+    _api_fill_to_wire_db_maintenance_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ExportPaymentsRequest> api2wire_box_autoadd_export_payments_request(
+      ExportPaymentsRequest raw) {
+    final ptr = inner.new_box_autoadd_export_payments_request_0();
+    _api_fill_to_wire_export_payments_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_GreenlightCredentials> api2wire_box_autoadd_greenlight_credentials(
       GreenlightCredentials raw) {
@@ -3541,6 +4873,22 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_PaymentsCursor> api2wire_box_autoadd_payments_cursor(PaymentsCursor raw) {
+    final ptr = inner.new_box_autoadd_payments_cursor_0();
+    _api_fill_to_wire_payments_cursor(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<
+      wire_PrepareBuyInboundLiquidityRequest> api2wire_box_autoadd_prepare_buy_inbound_liquidity_request(
+      PrepareBuyInboundLiquidityRequest raw) {
+    final ptr = inner.new_box_autoadd_prepare_buy_inbound_liquidity_request_0();
+    _api_fill_to_wire_prepare_buy_inbound_liquidity_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_PrepareRefundRequest> api2wire_box_autoadd_prepare_refund_request(
       PrepareRefundRequest raw) {
@@ -3556,6 +4904,22 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_ProcessNotificationRequest> api2wire_box_autoadd_process_notification_request(
+      ProcessNotificationRequest raw) {
+    final ptr = inner.new_box_autoadd_process_notification_request_0();
+    _api_fill_to_wire_process_notification_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ReceiveFiatPaymentRequest> api2wire_box_autoadd_receive_fiat_payment_request(
+      ReceiveFiatPaymentRequest raw) {
+    final ptr = inner.new_box_autoadd_receive_fiat_payment_request_0();
+    _api_fill_to_wire_receive_fiat_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ReceiveOnchainRequest> api2wire_box_autoadd_receive_onchain_request(
       ReceiveOnchainRequest raw) {
@@ -3587,6 +4951,14 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_SendFiatPaymentRequest> api2wire_box_autoadd_send_fiat_payment_request(
+      SendFiatPaymentRequest raw) {
+    final ptr = inner.new_box_autoadd_send_fiat_payment_request_0();
+    _api_fill_to_wire_send_fiat_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_SendOnchainRequest> api2wire_box_autoadd_send_onchain_request(SendOnchainRequest raw) {
     final ptr = inner.new_box_autoadd_send_onchain_request_0();
@@ -3659,6 +5031,11 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return raw == null ? ffi.nullptr : api2wire_String(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_opt_StringList(List<String>? raw) {
+    return raw == null ? ffi.nullptr : api2wire_StringList(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> api2wire_opt_box_autoadd_bool(bool? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_bool(raw);
@@ -3680,6 +5057,11 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_opening_fee_params(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_PaymentsCursor> api2wire_opt_box_autoadd_payments_cursor(PaymentsCursor? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_payments_cursor(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
@@ -3721,6 +5103,16 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_buy_bitcoin_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_buy_inbound_liquidity_request(
+      BuyInboundLiquidityRequest apiObj, ffi.Pointer<wire_BuyInboundLiquidityRequest> wireObj) {
+    _api_fill_to_wire_buy_inbound_liquidity_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_change_backup_passphrase_request(
+      ChangeBackupPassphraseRequest apiObj, ffi.Pointer<wire_ChangeBackupPassphraseRequest> wireObj) {
+    _api_fill_to_wire_change_backup_passphrase_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_check_message_request(
       CheckMessageRequest apiObj, ffi.Pointer<wire_CheckMessageRequest> wireObj) {
     _api_fill_to_wire_check_message_request(apiObj, wireObj.ref);
@@ -3730,6 +5122,16 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_config(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_db_maintenance_request(
+      DbMaintenanceRequest apiObj, ffi.Pointer<wire_DbMaintenanceRequest> wireObj) {
+    _api_fill_to_wire_db_maintenance_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_export_payments_request(
+      ExportPaymentsRequest apiObj, ffi.Pointer<wire_ExportPaymentsRequest> wireObj) {
+    _api_fill_to_wire_export_payments_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_greenlight_credentials(
       GreenlightCredentials apiObj, ffi.Pointer<wire_GreenlightCredentials> wireObj) {
     _api_fill_to_wire_greenlight_credentials(apiObj, wireObj.ref);
@@ -3774,6 +5176,16 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_opening_fee_params(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_payments_cursor(
+      PaymentsCursor apiObj, ffi.Pointer<wire_PaymentsCursor> wireObj) {
+    _api_fill_to_wire_payments_cursor(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_prepare_buy_inbound_liquidity_request(
+      PrepareBuyInboundLiquidityRequest apiObj, ffi.Pointer<wire_PrepareBuyInboundLiquidityRequest> wireObj) {
+    _api_fill_to_wire_prepare_buy_inbound_liquidity_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_prepare_refund_request(
       PrepareRefundRequest apiObj, ffi.Pointer<wire_PrepareRefundRequest> wireObj) {
     _api_fill_to_wire_prepare_refund_request(apiObj, wireObj.ref);
@@ -3784,6 +5196,16 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_prepare_sweep_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_process_notification_request(
+      ProcessNotificationRequest apiObj, ffi.Pointer<wire_ProcessNotificationRequest> wireObj) {
+    _api_fill_to_wire_process_notification_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_receive_fiat_payment_request(
+      ReceiveFiatPaymentRequest apiObj, ffi.Pointer<wire_ReceiveFiatPaymentRequest> wireObj) {
+    _api_fill_to_wire_receive_fiat_payment_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_receive_onchain_request(
       ReceiveOnchainRequest apiObj, ffi.Pointer<wire_ReceiveOnchainRequest> wireObj) {
     _api_fill_to_wire_receive_onchain_request(apiObj, wireObj.ref);
@@ -3804,6 +5226,11 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_reverse_swap_fees_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_send_fiat_payment_request(
+      SendFiatPaymentRequest apiObj, ffi.Pointer<wire_SendFiatPaymentRequest> wireObj) {
+    _api_fill_to_wire_send_fiat_payment_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_send_onchain_request(
       SendOnchainRequest apiObj, ffi.Pointer<wire_SendOnchainRequest> wireObj) {
     _api_fill_to_wire_send_onchain_request(apiObj, wireObj.ref);
@@ -3839,6 +5266,16 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.opening_fee_params = api2wire_opt_box_autoadd_opening_fee_params(apiObj.openingFeeParams);
   }
 
+  void _api_fill_to_wire_buy_inbound_liquidity_request(
+      BuyInboundLiquidityRequest apiObj, wire_BuyInboundLiquidityRequest wireObj) {
+    wireObj.order_id = api2wire_String(apiObj.orderId);
+  }
+
+  void _api_fill_to_wire_change_backup_passphrase_request(
+      ChangeBackupPassphraseRequest apiObj, wire_ChangeBackupPassphraseRequest wireObj) {
+    wireObj.passphrase = api2wire_opt_String(apiObj.passphrase);
+  }
+
   void _api_fill_to_wire_check_message_request(CheckMessageRequest apiObj, wire_CheckMessageRequest wireObj) {
     wireObj.message = api2wire_String(apiObj.message);
     wireObj.pubkey = api2wire_String(apiObj.pubkey);
@@ -3849,6 +5286,7 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.breezserver = api2wire_String(apiObj.breezserver);
     wireObj.mempoolspace_url = api2wire_String(apiObj.mempoolspaceUrl);
     wireObj.working_dir = api2wire_String(apiObj.workingDir);
+    wireObj.storage_key = api2wire_opt_uint_8_list(apiObj.storageKey);
     wireObj.network = api2wire_network(apiObj.network);
     wireObj.payment_timeout_sec = api2wire_u32(apiObj.paymentTimeoutSec);
     wireObj.default_lsp_id = api2wire_opt_String(apiObj.defaultLspId);
@@ -3856,6 +5294,25 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.maxfee_percent = api2wire_f64(apiObj.maxfeePercent);
     wireObj.exemptfee_msat = api2wire_u64(apiObj.exemptfeeMsat);
     _api_fill_to_wire_node_config(apiObj.nodeConfig, wireObj.node_config);
+    wireObj.fiat_rates_urls = api2wire_StringList(apiObj.fiatRatesUrls);
+    wireObj.recorded_fiat_currencies = api2wire_StringList(apiObj.recordedFiatCurrencies);
+    wireObj.event_log_retention = api2wire_u32(apiObj.eventLogRetention);
+    wireObj.auto_select_lsp = api2wire_bool(apiObj.autoSelectLsp);
+    wireObj.persist_lsp_failover = api2wire_bool(apiObj.persistLspFailover);
+  }
+
+  void _api_fill_to_wire_db_maintenance_request(
+      DbMaintenanceRequest apiObj, wire_DbMaintenanceRequest wireObj) {
+    wireObj.failed_payments_retention_days = api2wire_opt_box_autoadd_u32(apiObj.failedPaymentsRetentionDays);
+    wireObj.backup_versions_to_keep = api2wire_opt_box_autoadd_u32(apiObj.backupVersionsToKeep);
+  }
+
+  void _api_fill_to_wire_export_payments_request(
+      ExportPaymentsRequest apiObj, wire_ExportPaymentsRequest wireObj) {
+    wireObj.format = api2wire_payment_export_format(apiObj.format);
+    wireObj.from_timestamp = api2wire_opt_box_autoadd_i64(apiObj.fromTimestamp);
+    wireObj.to_timestamp = api2wire_opt_box_autoadd_i64(apiObj.toTimestamp);
+    wireObj.fiat_currency = api2wire_opt_String(apiObj.fiatCurrency);
   }
 
   void _api_fill_to_wire_greenlight_credentials(
@@ -3877,6 +5334,11 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.include_failures = api2wire_opt_box_autoadd_bool(apiObj.includeFailures);
     wireObj.offset = api2wire_opt_box_autoadd_u32(apiObj.offset);
     wireObj.limit = api2wire_opt_box_autoadd_u32(apiObj.limit);
+    wireObj.cursor = api2wire_opt_box_autoadd_payments_cursor(apiObj.cursor);
+    wireObj.search = api2wire_opt_String(apiObj.search);
+    wireObj.min_amount_msat = api2wire_opt_box_autoadd_u64(apiObj.minAmountMsat);
+    wireObj.max_amount_msat = api2wire_opt_box_autoadd_u64(apiObj.maxAmountMsat);
+    wireObj.tags = api2wire_opt_StringList(apiObj.tags);
   }
 
   void _api_fill_to_wire_ln_url_auth_request_data(
@@ -3955,6 +5417,22 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     if (apiObj != null) _api_fill_to_wire_box_autoadd_opening_fee_params(apiObj, wireObj);
   }
 
+  void _api_fill_to_wire_opt_box_autoadd_payments_cursor(
+      PaymentsCursor? apiObj, ffi.Pointer<wire_PaymentsCursor> wireObj) {
+    if (apiObj != null) _api_fill_to_wire_box_autoadd_payments_cursor(apiObj, wireObj);
+  }
+
+  void _api_fill_to_wire_payments_cursor(PaymentsCursor apiObj, wire_PaymentsCursor wireObj) {
+    wireObj.payment_time = api2wire_i64(apiObj.paymentTime);
+    wireObj.id = api2wire_String(apiObj.id);
+  }
+
+  void _api_fill_to_wire_prepare_buy_inbound_liquidity_request(
+      PrepareBuyInboundLiquidityRequest apiObj, wire_PrepareBuyInboundLiquidityRequest wireObj) {
+    wireObj.amount_sat = api2wire_u64(apiObj.amountSat);
+    wireObj.lease_blocks = api2wire_u32(apiObj.leaseBlocks);
+  }
+
   void _api_fill_to_wire_prepare_refund_request(
       PrepareRefundRequest apiObj, wire_PrepareRefundRequest wireObj) {
     wireObj.swap_address = api2wire_String(apiObj.swapAddress);
@@ -3967,6 +5445,23 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.sats_per_vbyte = api2wire_u64(apiObj.satsPerVbyte);
   }
 
+  void _api_fill_to_wire_process_notification_request(
+      ProcessNotificationRequest apiObj, wire_ProcessNotificationRequest wireObj) {
+    wireObj.payload = api2wire_String(apiObj.payload);
+    wireObj.deadline = api2wire_u64(apiObj.deadline);
+  }
+
+  void _api_fill_to_wire_receive_fiat_payment_request(
+      ReceiveFiatPaymentRequest apiObj, wire_ReceiveFiatPaymentRequest wireObj) {
+    wireObj.fiat_currency = api2wire_String(apiObj.fiatCurrency);
+    wireObj.fiat_amount = api2wire_f64(apiObj.fiatAmount);
+    wireObj.description = api2wire_String(apiObj.description);
+    wireObj.opening_fee_params = api2wire_opt_box_autoadd_opening_fee_params(apiObj.openingFeeParams);
+    wireObj.use_description_hash = api2wire_opt_box_autoadd_bool(apiObj.useDescriptionHash);
+    wireObj.expiry = api2wire_opt_box_autoadd_u32(apiObj.expiry);
+    wireObj.cltv = api2wire_opt_box_autoadd_u32(apiObj.cltv);
+  }
+
   void _api_fill_to_wire_receive_onchain_request(
       ReceiveOnchainRequest apiObj, wire_ReceiveOnchainRequest wireObj) {
     wireObj.opening_fee_params = api2wire_opt_box_autoadd_opening_fee_params(apiObj.openingFeeParams);
@@ -3994,6 +5489,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.send_amount_sat = api2wire_opt_box_autoadd_u64(apiObj.sendAmountSat);
   }
 
+  void _api_fill_to_wire_send_fiat_payment_request(
+      SendFiatPaymentRequest apiObj, wire_SendFiatPaymentRequest wireObj) {
+    wireObj.bolt11 = api2wire_String(apiObj.bolt11);
+    wireObj.fiat_currency = api2wire_String(apiObj.fiatCurrency);
+    wireObj.fiat_amount = api2wire_f64(apiObj.fiatAmount);
+  }
+
   void _api_fill_to_wire_send_onchain_request(SendOnchainRequest apiObj, wire_SendOnchainRequest wireObj) {
     wireObj.amount_sat = api2wire_u64(apiObj.amountSat);
     wireObj.onchain_recipient_address = api2wire_String(apiObj.onchainRecipientAddress);
@@ -4018,6 +5520,8 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
 
   void _api_fill_to_wire_static_backup_request(StaticBackupRequest apiObj, wire_StaticBackupRequest wireObj) {
     wireObj.working_dir = api2wire_String(apiObj.workingDir);
+    wireObj.seed = api2wire_opt_uint_8_list(apiObj.seed);
+    wireObj.storage_key = api2wire_opt_uint_8_list(apiObj.storageKey);
   }
 
   void _api_fill_to_wire_sweep_request(SweepRequest apiObj, wire_SweepRequest wireObj) {
@@ -4207,6 +5711,38 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_check_message =
       _wire_check_messagePtr.asFunction<void Function(int, ffi.Pointer<wire_CheckMessageRequest>)>();
 
+  void wire_process_notification(
+    int port_,
+    ffi.Pointer<wire_ProcessNotificationRequest> req,
+  ) {
+    return _wire_process_notification(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_process_notificationPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ProcessNotificationRequest>)>>(
+          'wire_process_notification');
+  late final _wire_process_notification = _wire_process_notificationPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ProcessNotificationRequest>)>();
+
+  void wire_register_webhook(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> webhook_url,
+  ) {
+    return _wire_register_webhook(
+      port_,
+      webhook_url,
+    );
+  }
+
+  late final _wire_register_webhookPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_register_webhook');
+  late final _wire_register_webhook =
+      _wire_register_webhookPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_mnemonic_to_seed(
     int port_,
     ffi.Pointer<wire_uint_8_list> phrase,
@@ -4244,33 +5780,75 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_default_config = _wire_default_configPtr
       .asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_NodeConfig>)>();
 
-  void wire_static_backup(
+  void wire_static_backup(
+    int port_,
+    ffi.Pointer<wire_StaticBackupRequest> req,
+  ) {
+    return _wire_static_backup(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_static_backupPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StaticBackupRequest>)>>(
+          'wire_static_backup');
+  late final _wire_static_backup =
+      _wire_static_backupPtr.asFunction<void Function(int, ffi.Pointer<wire_StaticBackupRequest>)>();
+
+  void wire_breez_events_stream(
+    int port_,
+  ) {
+    return _wire_breez_events_stream(
+      port_,
+    );
+  }
+
+  late final _wire_breez_events_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_breez_events_stream');
+  late final _wire_breez_events_stream = _wire_breez_events_streamPtr.asFunction<void Function(int)>();
+
+  void wire_add_event_stream(
+    int port_,
+  ) {
+    return _wire_add_event_stream(
+      port_,
+    );
+  }
+
+  late final _wire_add_event_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_add_event_stream');
+  late final _wire_add_event_stream = _wire_add_event_streamPtr.asFunction<void Function(int)>();
+
+  void wire_remove_event_stream(
     int port_,
-    ffi.Pointer<wire_StaticBackupRequest> req,
+    ffi.Pointer<wire_uint_8_list> id,
   ) {
-    return _wire_static_backup(
+    return _wire_remove_event_stream(
       port_,
-      req,
+      id,
     );
   }
 
-  late final _wire_static_backupPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StaticBackupRequest>)>>(
-          'wire_static_backup');
-  late final _wire_static_backup =
-      _wire_static_backupPtr.asFunction<void Function(int, ffi.Pointer<wire_StaticBackupRequest>)>();
+  late final _wire_remove_event_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_remove_event_stream');
+  late final _wire_remove_event_stream =
+      _wire_remove_event_streamPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_breez_events_stream(
+  void wire_events_since(
     int port_,
+    int seq,
   ) {
-    return _wire_breez_events_stream(
+    return _wire_events_since(
       port_,
+      seq,
     );
   }
 
-  late final _wire_breez_events_streamPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_breez_events_stream');
-  late final _wire_breez_events_stream = _wire_breez_events_streamPtr.asFunction<void Function(int)>();
+  late final _wire_events_sincePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_events_since');
+  late final _wire_events_since = _wire_events_sincePtr.asFunction<void Function(int, int)>();
 
   void wire_breez_log_stream(
     int port_,
@@ -4311,6 +5889,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_connect_lsp =
       _wire_connect_lspPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_connect_lsp_by_uri(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> uri,
+  ) {
+    return _wire_connect_lsp_by_uri(
+      port_,
+      uri,
+    );
+  }
+
+  late final _wire_connect_lsp_by_uriPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_connect_lsp_by_uri');
+  late final _wire_connect_lsp_by_uri =
+      _wire_connect_lsp_by_uriPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_lsp_id(
     int port_,
   ) {
@@ -4361,6 +5955,39 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_close_lsp_channels');
   late final _wire_close_lsp_channels = _wire_close_lsp_channelsPtr.asFunction<void Function(int)>();
 
+  void wire_prepare_buy_inbound_liquidity(
+    int port_,
+    ffi.Pointer<wire_PrepareBuyInboundLiquidityRequest> req,
+  ) {
+    return _wire_prepare_buy_inbound_liquidity(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_prepare_buy_inbound_liquidityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_PrepareBuyInboundLiquidityRequest>)>>('wire_prepare_buy_inbound_liquidity');
+  late final _wire_prepare_buy_inbound_liquidity = _wire_prepare_buy_inbound_liquidityPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_PrepareBuyInboundLiquidityRequest>)>();
+
+  void wire_buy_inbound_liquidity(
+    int port_,
+    ffi.Pointer<wire_BuyInboundLiquidityRequest> req,
+  ) {
+    return _wire_buy_inbound_liquidity(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_buy_inbound_liquidityPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_BuyInboundLiquidityRequest>)>>(
+          'wire_buy_inbound_liquidity');
+  late final _wire_buy_inbound_liquidity = _wire_buy_inbound_liquidityPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_BuyInboundLiquidityRequest>)>();
+
   void wire_backup(
     int port_,
   ) {
@@ -4384,6 +6011,114 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_backup_status');
   late final _wire_backup_status = _wire_backup_statusPtr.asFunction<void Function(int)>();
 
+  void wire_verify_backup(
+    int port_,
+  ) {
+    return _wire_verify_backup(
+      port_,
+    );
+  }
+
+  late final _wire_verify_backupPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_verify_backup');
+  late final _wire_verify_backup = _wire_verify_backupPtr.asFunction<void Function(int)>();
+
+  void wire_list_backup_versions(
+    int port_,
+  ) {
+    return _wire_list_backup_versions(
+      port_,
+    );
+  }
+
+  late final _wire_list_backup_versionsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_backup_versions');
+  late final _wire_list_backup_versions = _wire_list_backup_versionsPtr.asFunction<void Function(int)>();
+
+  void wire_restore_backup(
+    int port_,
+    int version,
+  ) {
+    return _wire_restore_backup(
+      port_,
+      version,
+    );
+  }
+
+  late final _wire_restore_backupPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_restore_backup');
+  late final _wire_restore_backup = _wire_restore_backupPtr.asFunction<void Function(int, int)>();
+
+  void wire_change_backup_passphrase(
+    int port_,
+    ffi.Pointer<wire_ChangeBackupPassphraseRequest> req,
+  ) {
+    return _wire_change_backup_passphrase(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_change_backup_passphrasePtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ChangeBackupPassphraseRequest>)>>(
+      'wire_change_backup_passphrase');
+  late final _wire_change_backup_passphrase = _wire_change_backup_passphrasePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ChangeBackupPassphraseRequest>)>();
+
+  void wire_export_backup(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> path,
+    ffi.Pointer<wire_uint_8_list> passphrase,
+  ) {
+    return _wire_export_backup(
+      port_,
+      path,
+      passphrase,
+    );
+  }
+
+  late final _wire_export_backupPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_export_backup');
+  late final _wire_export_backup = _wire_export_backupPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_import_backup(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> path,
+    ffi.Pointer<wire_uint_8_list> passphrase,
+  ) {
+    return _wire_import_backup(
+      port_,
+      path,
+      passphrase,
+    );
+  }
+
+  late final _wire_import_backupPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_import_backup');
+  late final _wire_import_backup = _wire_import_backupPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_db_maintenance(
+    int port_,
+    ffi.Pointer<wire_DbMaintenanceRequest> req,
+  ) {
+    return _wire_db_maintenance(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_db_maintenancePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DbMaintenanceRequest>)>>(
+          'wire_db_maintenance');
+  late final _wire_db_maintenance =
+      _wire_db_maintenancePtr.asFunction<void Function(int, ffi.Pointer<wire_DbMaintenanceRequest>)>();
+
   void wire_parse_invoice(
     int port_,
     ffi.Pointer<wire_uint_8_list> invoice,
@@ -4448,6 +6183,44 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_payment_by_hash =
       _wire_payment_by_hashPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_export_payments(
+    int port_,
+    ffi.Pointer<wire_ExportPaymentsRequest> req,
+  ) {
+    return _wire_export_payments(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_export_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExportPaymentsRequest>)>>(
+          'wire_export_payments');
+  late final _wire_export_payments =
+      _wire_export_paymentsPtr.asFunction<void Function(int, ffi.Pointer<wire_ExportPaymentsRequest>)>();
+
+  void wire_set_payment_metadata(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> hash,
+    ffi.Pointer<wire_uint_8_list> note,
+    ffi.Pointer<wire_StringList> tags,
+  ) {
+    return _wire_set_payment_metadata(
+      port_,
+      hash,
+      note,
+      tags,
+    );
+  }
+
+  late final _wire_set_payment_metadataPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_StringList>)>>('wire_set_payment_metadata');
+  late final _wire_set_payment_metadata = _wire_set_payment_metadataPtr.asFunction<
+      void Function(
+          int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_StringList>)>();
+
   void wire_send_payment(
     int port_,
     ffi.Pointer<wire_SendPaymentRequest> req,
@@ -4496,6 +6269,38 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_receive_payment =
       _wire_receive_paymentPtr.asFunction<void Function(int, ffi.Pointer<wire_ReceivePaymentRequest>)>();
 
+  void wire_send_fiat_payment(
+    int port_,
+    ffi.Pointer<wire_SendFiatPaymentRequest> req,
+  ) {
+    return _wire_send_fiat_payment(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_send_fiat_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_SendFiatPaymentRequest>)>>(
+          'wire_send_fiat_payment');
+  late final _wire_send_fiat_payment =
+      _wire_send_fiat_paymentPtr.asFunction<void Function(int, ffi.Pointer<wire_SendFiatPaymentRequest>)>();
+
+  void wire_receive_fiat_payment(
+    int port_,
+    ffi.Pointer<wire_ReceiveFiatPaymentRequest> req,
+  ) {
+    return _wire_receive_fiat_payment(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_receive_fiat_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ReceiveFiatPaymentRequest>)>>(
+          'wire_receive_fiat_payment');
+  late final _wire_receive_fiat_payment = _wire_receive_fiat_paymentPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ReceiveFiatPaymentRequest>)>();
+
   void wire_lnurl_pay(
     int port_,
     ffi.Pointer<wire_LnUrlPayRequest> req,
@@ -4556,6 +6361,60 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_fetch_fiat_rates');
   late final _wire_fetch_fiat_rates = _wire_fetch_fiat_ratesPtr.asFunction<void Function(int)>();
 
+  void wire_fiat_rates(
+    int port_,
+  ) {
+    return _wire_fiat_rates(
+      port_,
+    );
+  }
+
+  late final _wire_fiat_ratesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_fiat_rates');
+  late final _wire_fiat_rates = _wire_fiat_ratesPtr.asFunction<void Function(int)>();
+
+  void wire_format_fiat(
+    int port_,
+    double amount,
+    ffi.Pointer<wire_uint_8_list> currency_id,
+    ffi.Pointer<wire_uint_8_list> locale,
+  ) {
+    return _wire_format_fiat(
+      port_,
+      amount,
+      currency_id,
+      locale,
+    );
+  }
+
+  late final _wire_format_fiatPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Double, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_format_fiat');
+  late final _wire_format_fiat = _wire_format_fiatPtr
+      .asFunction<void Function(int, double, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_format_sats(
+    int port_,
+    int amount_msat,
+    int unit,
+    ffi.Pointer<wire_uint_8_list> locale,
+  ) {
+    return _wire_format_sats(
+      port_,
+      amount_msat,
+      unit,
+      locale,
+    );
+  }
+
+  late final _wire_format_satsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Uint64, ffi.Int32, ffi.Pointer<wire_uint_8_list>)>>('wire_format_sats');
+  late final _wire_format_sats =
+      _wire_format_satsPtr.asFunction<void Function(int, int, int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_list_fiat_currencies(
     int port_,
   ) {
@@ -4774,6 +6633,19 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_execute_command =
       _wire_execute_commandPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  ffi.Pointer<wire_StringList> new_StringList_0(
+    int len,
+  ) {
+    return _new_StringList_0(
+      len,
+    );
+  }
+
+  late final _new_StringList_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_StringList> Function(ffi.Int32)>>('new_StringList_0');
+  late final _new_StringList_0 =
+      _new_StringList_0Ptr.asFunction<ffi.Pointer<wire_StringList> Function(int)>();
+
   ffi.Pointer<ffi.Bool> new_box_autoadd_bool_0(
     bool value,
   ) {
@@ -4797,6 +6669,28 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_buy_bitcoin_request_0 =
       _new_box_autoadd_buy_bitcoin_request_0Ptr.asFunction<ffi.Pointer<wire_BuyBitcoinRequest> Function()>();
 
+  ffi.Pointer<wire_BuyInboundLiquidityRequest> new_box_autoadd_buy_inbound_liquidity_request_0() {
+    return _new_box_autoadd_buy_inbound_liquidity_request_0();
+  }
+
+  late final _new_box_autoadd_buy_inbound_liquidity_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_BuyInboundLiquidityRequest> Function()>>(
+          'new_box_autoadd_buy_inbound_liquidity_request_0');
+  late final _new_box_autoadd_buy_inbound_liquidity_request_0 =
+      _new_box_autoadd_buy_inbound_liquidity_request_0Ptr
+          .asFunction<ffi.Pointer<wire_BuyInboundLiquidityRequest> Function()>();
+
+  ffi.Pointer<wire_ChangeBackupPassphraseRequest> new_box_autoadd_change_backup_passphrase_request_0() {
+    return _new_box_autoadd_change_backup_passphrase_request_0();
+  }
+
+  late final _new_box_autoadd_change_backup_passphrase_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ChangeBackupPassphraseRequest> Function()>>(
+          'new_box_autoadd_change_backup_passphrase_request_0');
+  late final _new_box_autoadd_change_backup_passphrase_request_0 =
+      _new_box_autoadd_change_backup_passphrase_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ChangeBackupPassphraseRequest> Function()>();
+
   ffi.Pointer<wire_CheckMessageRequest> new_box_autoadd_check_message_request_0() {
     return _new_box_autoadd_check_message_request_0();
   }
@@ -4816,6 +6710,26 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_config_0 =
       _new_box_autoadd_config_0Ptr.asFunction<ffi.Pointer<wire_Config> Function()>();
 
+  ffi.Pointer<wire_DbMaintenanceRequest> new_box_autoadd_db_maintenance_request_0() {
+    return _new_box_autoadd_db_maintenance_request_0();
+  }
+
+  late final _new_box_autoadd_db_maintenance_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_DbMaintenanceRequest> Function()>>(
+          'new_box_autoadd_db_maintenance_request_0');
+  late final _new_box_autoadd_db_maintenance_request_0 = _new_box_autoadd_db_maintenance_request_0Ptr
+      .asFunction<ffi.Pointer<wire_DbMaintenanceRequest> Function()>();
+
+  ffi.Pointer<wire_ExportPaymentsRequest> new_box_autoadd_export_payments_request_0() {
+    return _new_box_autoadd_export_payments_request_0();
+  }
+
+  late final _new_box_autoadd_export_payments_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ExportPaymentsRequest> Function()>>(
+          'new_box_autoadd_export_payments_request_0');
+  late final _new_box_autoadd_export_payments_request_0 = _new_box_autoadd_export_payments_request_0Ptr
+      .asFunction<ffi.Pointer<wire_ExportPaymentsRequest> Function()>();
+
   ffi.Pointer<wire_GreenlightCredentials> new_box_autoadd_greenlight_credentials_0() {
     return _new_box_autoadd_greenlight_credentials_0();
   }
@@ -4918,6 +6832,28 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_opening_fee_params_0 =
       _new_box_autoadd_opening_fee_params_0Ptr.asFunction<ffi.Pointer<wire_OpeningFeeParams> Function()>();
 
+  ffi.Pointer<wire_PaymentsCursor> new_box_autoadd_payments_cursor_0() {
+    return _new_box_autoadd_payments_cursor_0();
+  }
+
+  late final _new_box_autoadd_payments_cursor_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PaymentsCursor> Function()>>(
+          'new_box_autoadd_payments_cursor_0');
+  late final _new_box_autoadd_payments_cursor_0 =
+      _new_box_autoadd_payments_cursor_0Ptr.asFunction<ffi.Pointer<wire_PaymentsCursor> Function()>();
+
+  ffi.Pointer<
+      wire_PrepareBuyInboundLiquidityRequest> new_box_autoadd_prepare_buy_inbound_liquidity_request_0() {
+    return _new_box_autoadd_prepare_buy_inbound_liquidity_request_0();
+  }
+
+  late final _new_box_autoadd_prepare_buy_inbound_liquidity_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PrepareBuyInboundLiquidityRequest> Function()>>(
+          'new_box_autoadd_prepare_buy_inbound_liquidity_request_0');
+  late final _new_box_autoadd_prepare_buy_inbound_liquidity_request_0 =
+      _new_box_autoadd_prepare_buy_inbound_liquidity_request_0Ptr
+          .asFunction<ffi.Pointer<wire_PrepareBuyInboundLiquidityRequest> Function()>();
+
   ffi.Pointer<wire_PrepareRefundRequest> new_box_autoadd_prepare_refund_request_0() {
     return _new_box_autoadd_prepare_refund_request_0();
   }
@@ -4938,6 +6874,28 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_prepare_sweep_request_0 = _new_box_autoadd_prepare_sweep_request_0Ptr
       .asFunction<ffi.Pointer<wire_PrepareSweepRequest> Function()>();
 
+  ffi.Pointer<wire_ProcessNotificationRequest> new_box_autoadd_process_notification_request_0() {
+    return _new_box_autoadd_process_notification_request_0();
+  }
+
+  late final _new_box_autoadd_process_notification_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ProcessNotificationRequest> Function()>>(
+          'new_box_autoadd_process_notification_request_0');
+  late final _new_box_autoadd_process_notification_request_0 =
+      _new_box_autoadd_process_notification_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ProcessNotificationRequest> Function()>();
+
+  ffi.Pointer<wire_ReceiveFiatPaymentRequest> new_box_autoadd_receive_fiat_payment_request_0() {
+    return _new_box_autoadd_receive_fiat_payment_request_0();
+  }
+
+  late final _new_box_autoadd_receive_fiat_payment_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ReceiveFiatPaymentRequest> Function()>>(
+          'new_box_autoadd_receive_fiat_payment_request_0');
+  late final _new_box_autoadd_receive_fiat_payment_request_0 =
+      _new_box_autoadd_receive_fiat_payment_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ReceiveFiatPaymentRequest> Function()>();
+
   ffi.Pointer<wire_ReceiveOnchainRequest> new_box_autoadd_receive_onchain_request_0() {
     return _new_box_autoadd_receive_onchain_request_0();
   }
@@ -4978,6 +6936,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_reverse_swap_fees_request_0 = _new_box_autoadd_reverse_swap_fees_request_0Ptr
       .asFunction<ffi.Pointer<wire_ReverseSwapFeesRequest> Function()>();
 
+  ffi.Pointer<wire_SendFiatPaymentRequest> new_box_autoadd_send_fiat_payment_request_0() {
+    return _new_box_autoadd_send_fiat_payment_request_0();
+  }
+
+  late final _new_box_autoadd_send_fiat_payment_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_SendFiatPaymentRequest> Function()>>(
+          'new_box_autoadd_send_fiat_payment_request_0');
+  late final _new_box_autoadd_send_fiat_payment_request_0 = _new_box_autoadd_send_fiat_payment_request_0Ptr
+      .asFunction<ffi.Pointer<wire_SendFiatPaymentRequest> Function()>();
+
   ffi.Pointer<wire_SendOnchainRequest> new_box_autoadd_send_onchain_request_0() {
     return _new_box_autoadd_send_onchain_request_0();
   }
@@ -5150,6 +7118,13 @@ class wire_NodeConfig extends ffi.Struct {
   external ffi.Pointer<NodeConfigKind> kind;
 }
 
+class wire_StringList extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 class wire_Config extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> breezserver;
 
@@ -5157,6 +7132,8 @@ class wire_Config extends ffi.Struct {
 
   external ffi.Pointer<wire_uint_8_list> working_dir;
 
+  external ffi.Pointer<wire_uint_8_list> storage_key;
+
   @ffi.Int32()
   external int network;
 
@@ -5174,6 +7151,19 @@ class wire_Config extends ffi.Struct {
   external int exemptfee_msat;
 
   external wire_NodeConfig node_config;
+
+  external ffi.Pointer<wire_StringList> fiat_rates_urls;
+
+  external ffi.Pointer<wire_StringList> recorded_fiat_currencies;
+
+  @ffi.Uint32()
+  external int event_log_retention;
+
+  @ffi.Bool()
+  external bool auto_select_lsp;
+
+  @ffi.Bool()
+  external bool persist_lsp_failover;
 }
 
 class wire_SignMessageRequest extends ffi.Struct {
@@ -5188,8 +7178,41 @@ class wire_CheckMessageRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> signature;
 }
 
+class wire_ProcessNotificationRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> payload;
+
+  @ffi.Uint64()
+  external int deadline;
+}
+
 class wire_StaticBackupRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> working_dir;
+
+  external ffi.Pointer<wire_uint_8_list> seed;
+
+  external ffi.Pointer<wire_uint_8_list> storage_key;
+}
+
+class wire_PrepareBuyInboundLiquidityRequest extends ffi.Struct {
+  @ffi.Uint64()
+  external int amount_sat;
+
+  @ffi.Uint32()
+  external int lease_blocks;
+}
+
+class wire_BuyInboundLiquidityRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> order_id;
+}
+
+class wire_ChangeBackupPassphraseRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> passphrase;
+}
+
+class wire_DbMaintenanceRequest extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> failed_payments_retention_days;
+
+  external ffi.Pointer<ffi.Uint32> backup_versions_to_keep;
 }
 
 class wire_list_payment_type_filter extends ffi.Struct {
@@ -5199,6 +7222,13 @@ class wire_list_payment_type_filter extends ffi.Struct {
   external int len;
 }
 
+class wire_PaymentsCursor extends ffi.Struct {
+  @ffi.Int64()
+  external int payment_time;
+
+  external ffi.Pointer<wire_uint_8_list> id;
+}
+
 class wire_ListPaymentsRequest extends ffi.Struct {
   external ffi.Pointer<wire_list_payment_type_filter> filters;
 
@@ -5211,6 +7241,27 @@ class wire_ListPaymentsRequest extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> offset;
 
   external ffi.Pointer<ffi.Uint32> limit;
+
+  external ffi.Pointer<wire_PaymentsCursor> cursor;
+
+  external ffi.Pointer<wire_uint_8_list> search;
+
+  external ffi.Pointer<ffi.Uint64> min_amount_msat;
+
+  external ffi.Pointer<ffi.Uint64> max_amount_msat;
+
+  external ffi.Pointer<wire_StringList> tags;
+}
+
+class wire_ExportPaymentsRequest extends ffi.Struct {
+  @ffi.Int32()
+  external int format;
+
+  external ffi.Pointer<ffi.Int64> from_timestamp;
+
+  external ffi.Pointer<ffi.Int64> to_timestamp;
+
+  external ffi.Pointer<wire_uint_8_list> fiat_currency;
 }
 
 class wire_SendPaymentRequest extends ffi.Struct {
@@ -5261,6 +7312,32 @@ class wire_ReceivePaymentRequest extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> cltv;
 }
 
+class wire_SendFiatPaymentRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> bolt11;
+
+  external ffi.Pointer<wire_uint_8_list> fiat_currency;
+
+  @ffi.Double()
+  external double fiat_amount;
+}
+
+class wire_ReceiveFiatPaymentRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> fiat_currency;
+
+  @ffi.Double()
+  external double fiat_amount;
+
+  external ffi.Pointer<wire_uint_8_list> description;
+
+  external ffi.Pointer<wire_OpeningFeeParams> opening_fee_params;
+
+  external ffi.Pointer<ffi.Bool> use_description_hash;
+
+  external ffi.Pointer<ffi.Uint32> expiry;
+
+  external ffi.Pointer<ffi.Uint32> cltv;
+}
+
 class wire_LnUrlPayRequestData extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> callback;
 
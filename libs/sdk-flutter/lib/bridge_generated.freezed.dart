@@ -26,6 +26,13 @@ mixin _$BreezEvent {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -38,6 +45,13 @@ mixin _$BreezEvent {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -50,6 +64,13 @@ mixin _$BreezEvent {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -63,6 +84,13 @@ mixin _$BreezEvent {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -75,6 +103,13 @@ mixin _$BreezEvent {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -87,6 +122,13 @@ mixin _$BreezEvent {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -179,6 +221,13 @@ class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return newBlock(block);
   }
@@ -194,6 +243,13 @@ class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return newBlock?.call(block);
   }
@@ -209,6 +265,13 @@ class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (newBlock != null) {
@@ -228,6 +291,13 @@ class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return newBlock(this);
   }
@@ -243,6 +313,13 @@ class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return newBlock?.call(this);
   }
@@ -258,6 +335,13 @@ class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (newBlock != null) {
@@ -347,6 +431,13 @@ class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return invoicePaid(details);
   }
@@ -362,6 +453,13 @@ class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return invoicePaid?.call(details);
   }
@@ -377,6 +475,13 @@ class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (invoicePaid != null) {
@@ -396,6 +501,13 @@ class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return invoicePaid(this);
   }
@@ -411,6 +523,13 @@ class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return invoicePaid?.call(this);
   }
@@ -426,6 +545,13 @@ class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (invoicePaid != null) {
@@ -487,6 +613,13 @@ class _$BreezEvent_Synced implements BreezEvent_Synced {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return synced();
   }
@@ -502,6 +635,13 @@ class _$BreezEvent_Synced implements BreezEvent_Synced {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return synced?.call();
   }
@@ -517,6 +657,13 @@ class _$BreezEvent_Synced implements BreezEvent_Synced {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (synced != null) {
@@ -536,6 +683,13 @@ class _$BreezEvent_Synced implements BreezEvent_Synced {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return synced(this);
   }
@@ -551,6 +705,13 @@ class _$BreezEvent_Synced implements BreezEvent_Synced {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return synced?.call(this);
   }
@@ -566,6 +727,13 @@ class _$BreezEvent_Synced implements BreezEvent_Synced {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (synced != null) {
@@ -651,6 +819,13 @@ class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return paymentSucceed(details);
   }
@@ -666,6 +841,13 @@ class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return paymentSucceed?.call(details);
   }
@@ -681,6 +863,13 @@ class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (paymentSucceed != null) {
@@ -700,6 +889,13 @@ class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return paymentSucceed(this);
   }
@@ -715,6 +911,13 @@ class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return paymentSucceed?.call(this);
   }
@@ -730,6 +933,13 @@ class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (paymentSucceed != null) {
@@ -820,6 +1030,13 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return paymentFailed(details);
   }
@@ -835,6 +1052,13 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return paymentFailed?.call(details);
   }
@@ -850,6 +1074,13 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (paymentFailed != null) {
@@ -869,6 +1100,13 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return paymentFailed(this);
   }
@@ -884,6 +1122,13 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return paymentFailed?.call(this);
   }
@@ -899,6 +1144,13 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (paymentFailed != null) {
@@ -964,6 +1216,13 @@ class _$BreezEvent_BackupStarted implements BreezEvent_BackupStarted {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return backupStarted();
   }
@@ -979,6 +1238,13 @@ class _$BreezEvent_BackupStarted implements BreezEvent_BackupStarted {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return backupStarted?.call();
   }
@@ -994,6 +1260,13 @@ class _$BreezEvent_BackupStarted implements BreezEvent_BackupStarted {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (backupStarted != null) {
@@ -1013,6 +1286,13 @@ class _$BreezEvent_BackupStarted implements BreezEvent_BackupStarted {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return backupStarted(this);
   }
@@ -1028,6 +1308,13 @@ class _$BreezEvent_BackupStarted implements BreezEvent_BackupStarted {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return backupStarted?.call(this);
   }
@@ -1043,6 +1330,13 @@ class _$BreezEvent_BackupStarted implements BreezEvent_BackupStarted {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (backupStarted != null) {
@@ -1102,6 +1396,13 @@ class _$BreezEvent_BackupSucceeded implements BreezEvent_BackupSucceeded {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return backupSucceeded();
   }
@@ -1117,6 +1418,13 @@ class _$BreezEvent_BackupSucceeded implements BreezEvent_BackupSucceeded {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return backupSucceeded?.call();
   }
@@ -1132,6 +1440,13 @@ class _$BreezEvent_BackupSucceeded implements BreezEvent_BackupSucceeded {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (backupSucceeded != null) {
@@ -1151,6 +1466,13 @@ class _$BreezEvent_BackupSucceeded implements BreezEvent_BackupSucceeded {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return backupSucceeded(this);
   }
@@ -1166,6 +1488,13 @@ class _$BreezEvent_BackupSucceeded implements BreezEvent_BackupSucceeded {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return backupSucceeded?.call(this);
   }
@@ -1181,6 +1510,13 @@ class _$BreezEvent_BackupSucceeded implements BreezEvent_BackupSucceeded {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (backupSucceeded != null) {
@@ -1266,6 +1602,13 @@ class _$BreezEvent_BackupFailed implements BreezEvent_BackupFailed {
     required TResult Function() backupStarted,
     required TResult Function() backupSucceeded,
     required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
   }) {
     return backupFailed(details);
   }
@@ -1281,6 +1624,13 @@ class _$BreezEvent_BackupFailed implements BreezEvent_BackupFailed {
     TResult? Function()? backupStarted,
     TResult? Function()? backupSucceeded,
     TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
   }) {
     return backupFailed?.call(details);
   }
@@ -1296,6 +1646,13 @@ class _$BreezEvent_BackupFailed implements BreezEvent_BackupFailed {
     TResult Function()? backupStarted,
     TResult Function()? backupSucceeded,
     TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
     required TResult orElse(),
   }) {
     if (backupFailed != null) {
@@ -1315,6 +1672,13 @@ class _$BreezEvent_BackupFailed implements BreezEvent_BackupFailed {
     required TResult Function(BreezEvent_BackupStarted value) backupStarted,
     required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
   }) {
     return backupFailed(this);
   }
@@ -1330,6 +1694,13 @@ class _$BreezEvent_BackupFailed implements BreezEvent_BackupFailed {
     TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
   }) {
     return backupFailed?.call(this);
   }
@@ -1345,6 +1716,13 @@ class _$BreezEvent_BackupFailed implements BreezEvent_BackupFailed {
     TResult Function(BreezEvent_BackupStarted value)? backupStarted,
     TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
     required TResult orElse(),
   }) {
     if (backupFailed != null) {
@@ -1364,6 +1742,1475 @@ abstract class BreezEvent_BackupFailed implements BreezEvent {
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$BreezEvent_RemoteStateChangedCopyWith<$Res> {
+  factory _$$BreezEvent_RemoteStateChangedCopyWith(
+          _$BreezEvent_RemoteStateChanged value, $Res Function(_$BreezEvent_RemoteStateChanged) then) =
+      __$$BreezEvent_RemoteStateChangedCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BreezEvent_RemoteStateChangedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_RemoteStateChanged>
+    implements _$$BreezEvent_RemoteStateChangedCopyWith<$Res> {
+  __$$BreezEvent_RemoteStateChangedCopyWithImpl(
+      _$BreezEvent_RemoteStateChanged _value, $Res Function(_$BreezEvent_RemoteStateChanged) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$BreezEvent_RemoteStateChanged implements BreezEvent_RemoteStateChanged {
+  const _$BreezEvent_RemoteStateChanged();
+
+  @override
+  String toString() {
+    return 'BreezEvent.remoteStateChanged()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$BreezEvent_RemoteStateChanged);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
+  }) {
+    return remoteStateChanged();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
+  }) {
+    return remoteStateChanged?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (remoteStateChanged != null) {
+      return remoteStateChanged();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
+  }) {
+    return remoteStateChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
+  }) {
+    return remoteStateChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (remoteStateChanged != null) {
+      return remoteStateChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_RemoteStateChanged implements BreezEvent {
+  const factory BreezEvent_RemoteStateChanged() = _$BreezEvent_RemoteStateChanged;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ChannelOpenedCopyWith<$Res> {
+  factory _$$BreezEvent_ChannelOpenedCopyWith(
+          _$BreezEvent_ChannelOpened value, $Res Function(_$BreezEvent_ChannelOpened) then) =
+      __$$BreezEvent_ChannelOpenedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String fundingTxid});
+}
+
+/// @nodoc
+class __$$BreezEvent_ChannelOpenedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ChannelOpened>
+    implements _$$BreezEvent_ChannelOpenedCopyWith<$Res> {
+  __$$BreezEvent_ChannelOpenedCopyWithImpl(
+      _$BreezEvent_ChannelOpened _value, $Res Function(_$BreezEvent_ChannelOpened) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? fundingTxid = null,
+  }) {
+    return _then(_$BreezEvent_ChannelOpened(
+      fundingTxid: null == fundingTxid
+          ? _value.fundingTxid
+          : fundingTxid // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ChannelOpened implements BreezEvent_ChannelOpened {
+  const _$BreezEvent_ChannelOpened({required this.fundingTxid});
+
+  @override
+  final String fundingTxid;
+
+  @override
+  String toString() {
+    return 'BreezEvent.channelOpened(fundingTxid: $fundingTxid)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ChannelOpened &&
+            (identical(other.fundingTxid, fundingTxid) || other.fundingTxid == fundingTxid));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, fundingTxid);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ChannelOpenedCopyWith<_$BreezEvent_ChannelOpened> get copyWith =>
+      __$$BreezEvent_ChannelOpenedCopyWithImpl<_$BreezEvent_ChannelOpened>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
+  }) {
+    return channelOpened(fundingTxid);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
+  }) {
+    return channelOpened?.call(fundingTxid);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (channelOpened != null) {
+      return channelOpened(fundingTxid);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
+  }) {
+    return channelOpened(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
+  }) {
+    return channelOpened?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (channelOpened != null) {
+      return channelOpened(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ChannelOpened implements BreezEvent {
+  const factory BreezEvent_ChannelOpened({required final String fundingTxid}) = _$BreezEvent_ChannelOpened;
+
+  String get fundingTxid;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ChannelOpenedCopyWith<_$BreezEvent_ChannelOpened> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ChannelPendingCloseCopyWith<$Res> {
+  factory _$$BreezEvent_ChannelPendingCloseCopyWith(
+          _$BreezEvent_ChannelPendingClose value, $Res Function(_$BreezEvent_ChannelPendingClose) then) =
+      __$$BreezEvent_ChannelPendingCloseCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String fundingTxid});
+}
+
+/// @nodoc
+class __$$BreezEvent_ChannelPendingCloseCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ChannelPendingClose>
+    implements _$$BreezEvent_ChannelPendingCloseCopyWith<$Res> {
+  __$$BreezEvent_ChannelPendingCloseCopyWithImpl(
+      _$BreezEvent_ChannelPendingClose _value, $Res Function(_$BreezEvent_ChannelPendingClose) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? fundingTxid = null,
+  }) {
+    return _then(_$BreezEvent_ChannelPendingClose(
+      fundingTxid: null == fundingTxid
+          ? _value.fundingTxid
+          : fundingTxid // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ChannelPendingClose implements BreezEvent_ChannelPendingClose {
+  const _$BreezEvent_ChannelPendingClose({required this.fundingTxid});
+
+  @override
+  final String fundingTxid;
+
+  @override
+  String toString() {
+    return 'BreezEvent.channelPendingClose(fundingTxid: $fundingTxid)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ChannelPendingClose &&
+            (identical(other.fundingTxid, fundingTxid) || other.fundingTxid == fundingTxid));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, fundingTxid);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ChannelPendingCloseCopyWith<_$BreezEvent_ChannelPendingClose> get copyWith =>
+      __$$BreezEvent_ChannelPendingCloseCopyWithImpl<_$BreezEvent_ChannelPendingClose>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
+  }) {
+    return channelPendingClose(fundingTxid);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
+  }) {
+    return channelPendingClose?.call(fundingTxid);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (channelPendingClose != null) {
+      return channelPendingClose(fundingTxid);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
+  }) {
+    return channelPendingClose(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
+  }) {
+    return channelPendingClose?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (channelPendingClose != null) {
+      return channelPendingClose(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ChannelPendingClose implements BreezEvent {
+  const factory BreezEvent_ChannelPendingClose({required final String fundingTxid}) =
+      _$BreezEvent_ChannelPendingClose;
+
+  String get fundingTxid;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ChannelPendingCloseCopyWith<_$BreezEvent_ChannelPendingClose> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ChannelClosedCopyWith<$Res> {
+  factory _$$BreezEvent_ChannelClosedCopyWith(
+          _$BreezEvent_ChannelClosed value, $Res Function(_$BreezEvent_ChannelClosed) then) =
+      __$$BreezEvent_ChannelClosedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String fundingTxid, String? closingTxid});
+}
+
+/// @nodoc
+class __$$BreezEvent_ChannelClosedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ChannelClosed>
+    implements _$$BreezEvent_ChannelClosedCopyWith<$Res> {
+  __$$BreezEvent_ChannelClosedCopyWithImpl(
+      _$BreezEvent_ChannelClosed _value, $Res Function(_$BreezEvent_ChannelClosed) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? fundingTxid = null,
+    Object? closingTxid = freezed,
+  }) {
+    return _then(_$BreezEvent_ChannelClosed(
+      fundingTxid: null == fundingTxid
+          ? _value.fundingTxid
+          : fundingTxid // ignore: cast_nullable_to_non_nullable
+              as String,
+      closingTxid: freezed == closingTxid
+          ? _value.closingTxid
+          : closingTxid // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ChannelClosed implements BreezEvent_ChannelClosed {
+  const _$BreezEvent_ChannelClosed({required this.fundingTxid, this.closingTxid});
+
+  @override
+  final String fundingTxid;
+  @override
+  final String? closingTxid;
+
+  @override
+  String toString() {
+    return 'BreezEvent.channelClosed(fundingTxid: $fundingTxid, closingTxid: $closingTxid)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ChannelClosed &&
+            (identical(other.fundingTxid, fundingTxid) || other.fundingTxid == fundingTxid) &&
+            (identical(other.closingTxid, closingTxid) || other.closingTxid == closingTxid));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, fundingTxid, closingTxid);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ChannelClosedCopyWith<_$BreezEvent_ChannelClosed> get copyWith =>
+      __$$BreezEvent_ChannelClosedCopyWithImpl<_$BreezEvent_ChannelClosed>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
+  }) {
+    return channelClosed(fundingTxid, closingTxid);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
+  }) {
+    return channelClosed?.call(fundingTxid, closingTxid);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (channelClosed != null) {
+      return channelClosed(fundingTxid, closingTxid);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
+  }) {
+    return channelClosed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
+  }) {
+    return channelClosed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (channelClosed != null) {
+      return channelClosed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ChannelClosed implements BreezEvent {
+  const factory BreezEvent_ChannelClosed({required final String fundingTxid, final String? closingTxid}) =
+      _$BreezEvent_ChannelClosed;
+
+  String get fundingTxid;
+  String? get closingTxid;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ChannelClosedCopyWith<_$BreezEvent_ChannelClosed> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_BalanceChangedCopyWith<$Res> {
+  factory _$$BreezEvent_BalanceChangedCopyWith(
+          _$BreezEvent_BalanceChanged value, $Res Function(_$BreezEvent_BalanceChanged) then) =
+      __$$BreezEvent_BalanceChangedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({NodeBalance previous, NodeBalance current});
+}
+
+/// @nodoc
+class __$$BreezEvent_BalanceChangedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BalanceChanged>
+    implements _$$BreezEvent_BalanceChangedCopyWith<$Res> {
+  __$$BreezEvent_BalanceChangedCopyWithImpl(
+      _$BreezEvent_BalanceChanged _value, $Res Function(_$BreezEvent_BalanceChanged) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? previous = null,
+    Object? current = null,
+  }) {
+    return _then(_$BreezEvent_BalanceChanged(
+      previous: null == previous
+          ? _value.previous
+          : previous // ignore: cast_nullable_to_non_nullable
+              as NodeBalance,
+      current: null == current
+          ? _value.current
+          : current // ignore: cast_nullable_to_non_nullable
+              as NodeBalance,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_BalanceChanged implements BreezEvent_BalanceChanged {
+  const _$BreezEvent_BalanceChanged({required this.previous, required this.current});
+
+  @override
+  final NodeBalance previous;
+  @override
+  final NodeBalance current;
+
+  @override
+  String toString() {
+    return 'BreezEvent.balanceChanged(previous: $previous, current: $current)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_BalanceChanged &&
+            (identical(other.previous, previous) || other.previous == previous) &&
+            (identical(other.current, current) || other.current == current));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, previous, current);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_BalanceChangedCopyWith<_$BreezEvent_BalanceChanged> get copyWith =>
+      __$$BreezEvent_BalanceChangedCopyWithImpl<_$BreezEvent_BalanceChanged>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
+  }) {
+    return balanceChanged(previous, current);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
+  }) {
+    return balanceChanged?.call(previous, current);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (balanceChanged != null) {
+      return balanceChanged(previous, current);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
+  }) {
+    return balanceChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
+  }) {
+    return balanceChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (balanceChanged != null) {
+      return balanceChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_BalanceChanged implements BreezEvent {
+  const factory BreezEvent_BalanceChanged(
+      {required final NodeBalance previous,
+      required final NodeBalance current}) = _$BreezEvent_BalanceChanged;
+
+  NodeBalance get previous;
+  NodeBalance get current;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_BalanceChangedCopyWith<_$BreezEvent_BalanceChanged> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LiquidityOrderUpdatedCopyWith<$Res> {
+  factory _$$BreezEvent_LiquidityOrderUpdatedCopyWith(
+          _$BreezEvent_LiquidityOrderUpdated value, $Res Function(_$BreezEvent_LiquidityOrderUpdated) then) =
+      __$$BreezEvent_LiquidityOrderUpdatedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LiquidityOrder details});
+}
+
+/// @nodoc
+class __$$BreezEvent_LiquidityOrderUpdatedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LiquidityOrderUpdated>
+    implements _$$BreezEvent_LiquidityOrderUpdatedCopyWith<$Res> {
+  __$$BreezEvent_LiquidityOrderUpdatedCopyWithImpl(
+      _$BreezEvent_LiquidityOrderUpdated _value, $Res Function(_$BreezEvent_LiquidityOrderUpdated) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_LiquidityOrderUpdated(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as LiquidityOrder,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LiquidityOrderUpdated implements BreezEvent_LiquidityOrderUpdated {
+  const _$BreezEvent_LiquidityOrderUpdated({required this.details});
+
+  @override
+  final LiquidityOrder details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.liquidityOrderUpdated(details: $details)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LiquidityOrderUpdated &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LiquidityOrderUpdatedCopyWith<_$BreezEvent_LiquidityOrderUpdated> get copyWith =>
+      __$$BreezEvent_LiquidityOrderUpdatedCopyWithImpl<_$BreezEvent_LiquidityOrderUpdated>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
+  }) {
+    return liquidityOrderUpdated(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
+  }) {
+    return liquidityOrderUpdated?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (liquidityOrderUpdated != null) {
+      return liquidityOrderUpdated(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
+  }) {
+    return liquidityOrderUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
+  }) {
+    return liquidityOrderUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (liquidityOrderUpdated != null) {
+      return liquidityOrderUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LiquidityOrderUpdated implements BreezEvent {
+  const factory BreezEvent_LiquidityOrderUpdated({required final LiquidityOrder details}) =
+      _$BreezEvent_LiquidityOrderUpdated;
+
+  LiquidityOrder get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LiquidityOrderUpdatedCopyWith<_$BreezEvent_LiquidityOrderUpdated> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_BackupRestoredCopyWith<$Res> {
+  factory _$$BreezEvent_BackupRestoredCopyWith(
+          _$BreezEvent_BackupRestored value, $Res Function(_$BreezEvent_BackupRestored) then) =
+      __$$BreezEvent_BackupRestoredCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int version});
+}
+
+/// @nodoc
+class __$$BreezEvent_BackupRestoredCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BackupRestored>
+    implements _$$BreezEvent_BackupRestoredCopyWith<$Res> {
+  __$$BreezEvent_BackupRestoredCopyWithImpl(
+      _$BreezEvent_BackupRestored _value, $Res Function(_$BreezEvent_BackupRestored) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? version = null,
+  }) {
+    return _then(_$BreezEvent_BackupRestored(
+      version: null == version
+          ? _value.version
+          : version // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_BackupRestored implements BreezEvent_BackupRestored {
+  const _$BreezEvent_BackupRestored({required this.version});
+
+  @override
+  final int version;
+
+  @override
+  String toString() {
+    return 'BreezEvent.backupRestored(version: $version)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_BackupRestored &&
+            (identical(other.version, version) || other.version == version));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, version);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_BackupRestoredCopyWith<_$BreezEvent_BackupRestored> get copyWith =>
+      __$$BreezEvent_BackupRestoredCopyWithImpl<_$BreezEvent_BackupRestored>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function() remoteStateChanged,
+    required TResult Function(String fundingTxid) channelOpened,
+    required TResult Function(String fundingTxid) channelPendingClose,
+    required TResult Function(String fundingTxid, String? closingTxid) channelClosed,
+    required TResult Function(NodeBalance previous, NodeBalance current) balanceChanged,
+    required TResult Function(LiquidityOrder details) liquidityOrderUpdated,
+    required TResult Function(int version) backupRestored,
+  }) {
+    return backupRestored(version);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function()? remoteStateChanged,
+    TResult? Function(String fundingTxid)? channelOpened,
+    TResult? Function(String fundingTxid)? channelPendingClose,
+    TResult? Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult? Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult? Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult? Function(int version)? backupRestored,
+  }) {
+    return backupRestored?.call(version);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function()? remoteStateChanged,
+    TResult Function(String fundingTxid)? channelOpened,
+    TResult Function(String fundingTxid)? channelPendingClose,
+    TResult Function(String fundingTxid, String? closingTxid)? channelClosed,
+    TResult Function(NodeBalance previous, NodeBalance current)? balanceChanged,
+    TResult Function(LiquidityOrder details)? liquidityOrderUpdated,
+    TResult Function(int version)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (backupRestored != null) {
+      return backupRestored(version);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_RemoteStateChanged value) remoteStateChanged,
+    required TResult Function(BreezEvent_ChannelOpened value) channelOpened,
+    required TResult Function(BreezEvent_ChannelPendingClose value) channelPendingClose,
+    required TResult Function(BreezEvent_ChannelClosed value) channelClosed,
+    required TResult Function(BreezEvent_BalanceChanged value) balanceChanged,
+    required TResult Function(BreezEvent_LiquidityOrderUpdated value) liquidityOrderUpdated,
+    required TResult Function(BreezEvent_BackupRestored value) backupRestored,
+  }) {
+    return backupRestored(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult? Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult? Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult? Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult? Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult? Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult? Function(BreezEvent_BackupRestored value)? backupRestored,
+  }) {
+    return backupRestored?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_RemoteStateChanged value)? remoteStateChanged,
+    TResult Function(BreezEvent_ChannelOpened value)? channelOpened,
+    TResult Function(BreezEvent_ChannelPendingClose value)? channelPendingClose,
+    TResult Function(BreezEvent_ChannelClosed value)? channelClosed,
+    TResult Function(BreezEvent_BalanceChanged value)? balanceChanged,
+    TResult Function(BreezEvent_LiquidityOrderUpdated value)? liquidityOrderUpdated,
+    TResult Function(BreezEvent_BackupRestored value)? backupRestored,
+    required TResult orElse(),
+  }) {
+    if (backupRestored != null) {
+      return backupRestored(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_BackupRestored implements BreezEvent {
+  const factory BreezEvent_BackupRestored({required final int version}) = _$BreezEvent_BackupRestored;
+
+  int get version;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_BackupRestoredCopyWith<_$BreezEvent_BackupRestored> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 mixin _$InputType {
   @optionalTypeArgs
@@ -3663,57 +5510,261 @@ class _$LnUrlWithdrawResult_ErrorStatus implements LnUrlWithdrawResult_ErrorStat
   const _$LnUrlWithdrawResult_ErrorStatus({required this.data});
 
   @override
-  final LnUrlErrorData data;
+  final LnUrlErrorData data;
+
+  @override
+  String toString() {
+    return 'LnUrlWithdrawResult.errorStatus(data: $data)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LnUrlWithdrawResult_ErrorStatus &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LnUrlWithdrawResult_ErrorStatusCopyWith<_$LnUrlWithdrawResult_ErrorStatus> get copyWith =>
+      __$$LnUrlWithdrawResult_ErrorStatusCopyWithImpl<_$LnUrlWithdrawResult_ErrorStatus>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LnUrlWithdrawSuccessData data) ok,
+    required TResult Function(LnUrlErrorData data) errorStatus,
+  }) {
+    return errorStatus(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
+  }) {
+    return errorStatus?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult Function(LnUrlErrorData data)? errorStatus,
+    required TResult orElse(),
+  }) {
+    if (errorStatus != null) {
+      return errorStatus(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
+    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
+  }) {
+    return errorStatus(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+  }) {
+    return errorStatus?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    required TResult orElse(),
+  }) {
+    if (errorStatus != null) {
+      return errorStatus(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LnUrlWithdrawResult_ErrorStatus implements LnUrlWithdrawResult {
+  const factory LnUrlWithdrawResult_ErrorStatus({required final LnUrlErrorData data}) =
+      _$LnUrlWithdrawResult_ErrorStatus;
+
+  @override
+  LnUrlErrorData get data;
+  @JsonKey(ignore: true)
+  _$$LnUrlWithdrawResult_ErrorStatusCopyWith<_$LnUrlWithdrawResult_ErrorStatus> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$NodeConfig {
+  GreenlightNodeConfig get config => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(GreenlightNodeConfig config) greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(GreenlightNodeConfig config)? greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(GreenlightNodeConfig config)? greenlight,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NodeConfig_Greenlight value) greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @JsonKey(ignore: true)
+  $NodeConfigCopyWith<NodeConfig> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $NodeConfigCopyWith<$Res> {
+  factory $NodeConfigCopyWith(NodeConfig value, $Res Function(NodeConfig) then) =
+      _$NodeConfigCopyWithImpl<$Res, NodeConfig>;
+  @useResult
+  $Res call({GreenlightNodeConfig config});
+}
+
+/// @nodoc
+class _$NodeConfigCopyWithImpl<$Res, $Val extends NodeConfig> implements $NodeConfigCopyWith<$Res> {
+  _$NodeConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? config = null,
+  }) {
+    return _then(_value.copyWith(
+      config: null == config
+          ? _value.config
+          : config // ignore: cast_nullable_to_non_nullable
+              as GreenlightNodeConfig,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$NodeConfig_GreenlightCopyWith<$Res> implements $NodeConfigCopyWith<$Res> {
+  factory _$$NodeConfig_GreenlightCopyWith(
+          _$NodeConfig_Greenlight value, $Res Function(_$NodeConfig_Greenlight) then) =
+      __$$NodeConfig_GreenlightCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({GreenlightNodeConfig config});
+}
+
+/// @nodoc
+class __$$NodeConfig_GreenlightCopyWithImpl<$Res>
+    extends _$NodeConfigCopyWithImpl<$Res, _$NodeConfig_Greenlight>
+    implements _$$NodeConfig_GreenlightCopyWith<$Res> {
+  __$$NodeConfig_GreenlightCopyWithImpl(
+      _$NodeConfig_Greenlight _value, $Res Function(_$NodeConfig_Greenlight) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? config = null,
+  }) {
+    return _then(_$NodeConfig_Greenlight(
+      config: null == config
+          ? _value.config
+          : config // ignore: cast_nullable_to_non_nullable
+              as GreenlightNodeConfig,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$NodeConfig_Greenlight implements NodeConfig_Greenlight {
+  const _$NodeConfig_Greenlight({required this.config});
+
+  @override
+  final GreenlightNodeConfig config;
 
   @override
   String toString() {
-    return 'LnUrlWithdrawResult.errorStatus(data: $data)';
+    return 'NodeConfig.greenlight(config: $config)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LnUrlWithdrawResult_ErrorStatus &&
-            (identical(other.data, data) || other.data == data));
+            other is _$NodeConfig_Greenlight &&
+            (identical(other.config, config) || other.config == config));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, config);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LnUrlWithdrawResult_ErrorStatusCopyWith<_$LnUrlWithdrawResult_ErrorStatus> get copyWith =>
-      __$$LnUrlWithdrawResult_ErrorStatusCopyWithImpl<_$LnUrlWithdrawResult_ErrorStatus>(this, _$identity);
+  _$$NodeConfig_GreenlightCopyWith<_$NodeConfig_Greenlight> get copyWith =>
+      __$$NodeConfig_GreenlightCopyWithImpl<_$NodeConfig_Greenlight>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawSuccessData data) ok,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function(GreenlightNodeConfig config) greenlight,
   }) {
-    return errorStatus(data);
+    return greenlight(config);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function(GreenlightNodeConfig config)? greenlight,
   }) {
-    return errorStatus?.call(data);
+    return greenlight?.call(config);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+    TResult Function(GreenlightNodeConfig config)? greenlight,
     required TResult orElse(),
   }) {
-    if (errorStatus != null) {
-      return errorStatus(data);
+    if (greenlight != null) {
+      return greenlight(config);
     }
     return orElse();
   }
@@ -3721,203 +5772,321 @@ class _$LnUrlWithdrawResult_ErrorStatus implements LnUrlWithdrawResult_ErrorStat
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
-    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
+    required TResult Function(NodeConfig_Greenlight value) greenlight,
   }) {
-    return errorStatus(this);
+    return greenlight(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult? Function(NodeConfig_Greenlight value)? greenlight,
   }) {
-    return errorStatus?.call(this);
+    return greenlight?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult Function(NodeConfig_Greenlight value)? greenlight,
     required TResult orElse(),
   }) {
-    if (errorStatus != null) {
-      return errorStatus(this);
+    if (greenlight != null) {
+      return greenlight(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlWithdrawResult_ErrorStatus implements LnUrlWithdrawResult {
-  const factory LnUrlWithdrawResult_ErrorStatus({required final LnUrlErrorData data}) =
-      _$LnUrlWithdrawResult_ErrorStatus;
+abstract class NodeConfig_Greenlight implements NodeConfig {
+  const factory NodeConfig_Greenlight({required final GreenlightNodeConfig config}) = _$NodeConfig_Greenlight;
 
   @override
-  LnUrlErrorData get data;
+  GreenlightNodeConfig get config;
+  @override
   @JsonKey(ignore: true)
-  _$$LnUrlWithdrawResult_ErrorStatusCopyWith<_$LnUrlWithdrawResult_ErrorStatus> get copyWith =>
+  _$$NodeConfig_GreenlightCopyWith<_$NodeConfig_Greenlight> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$NodeConfig {
-  GreenlightNodeConfig get config => throw _privateConstructorUsedError;
+mixin _$PaymentDetails {
+  Object get data => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(GreenlightNodeConfig config) greenlight,
+    required TResult Function(LnPaymentDetails data) ln,
+    required TResult Function(ClosedChannelPaymentDetails data) closedChannel,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(GreenlightNodeConfig config)? greenlight,
+    TResult? Function(LnPaymentDetails data)? ln,
+    TResult? Function(ClosedChannelPaymentDetails data)? closedChannel,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(GreenlightNodeConfig config)? greenlight,
+    TResult Function(LnPaymentDetails data)? ln,
+    TResult Function(ClosedChannelPaymentDetails data)? closedChannel,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NodeConfig_Greenlight value) greenlight,
+    required TResult Function(PaymentDetails_Ln value) ln,
+    required TResult Function(PaymentDetails_ClosedChannel value) closedChannel,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+    TResult? Function(PaymentDetails_Ln value)? ln,
+    TResult? Function(PaymentDetails_ClosedChannel value)? closedChannel,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    TResult Function(PaymentDetails_Ln value)? ln,
+    TResult Function(PaymentDetails_ClosedChannel value)? closedChannel,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
-
-  @JsonKey(ignore: true)
-  $NodeConfigCopyWith<NodeConfig> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $NodeConfigCopyWith<$Res> {
-  factory $NodeConfigCopyWith(NodeConfig value, $Res Function(NodeConfig) then) =
-      _$NodeConfigCopyWithImpl<$Res, NodeConfig>;
-  @useResult
-  $Res call({GreenlightNodeConfig config});
+abstract class $PaymentDetailsCopyWith<$Res> {
+  factory $PaymentDetailsCopyWith(PaymentDetails value, $Res Function(PaymentDetails) then) =
+      _$PaymentDetailsCopyWithImpl<$Res, PaymentDetails>;
 }
 
 /// @nodoc
-class _$NodeConfigCopyWithImpl<$Res, $Val extends NodeConfig> implements $NodeConfigCopyWith<$Res> {
-  _$NodeConfigCopyWithImpl(this._value, this._then);
+class _$PaymentDetailsCopyWithImpl<$Res, $Val extends PaymentDetails>
+    implements $PaymentDetailsCopyWith<$Res> {
+  _$PaymentDetailsCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$PaymentDetails_LnCopyWith<$Res> {
+  factory _$$PaymentDetails_LnCopyWith(_$PaymentDetails_Ln value, $Res Function(_$PaymentDetails_Ln) then) =
+      __$$PaymentDetails_LnCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnPaymentDetails data});
+}
+
+/// @nodoc
+class __$$PaymentDetails_LnCopyWithImpl<$Res> extends _$PaymentDetailsCopyWithImpl<$Res, _$PaymentDetails_Ln>
+    implements _$$PaymentDetails_LnCopyWith<$Res> {
+  __$$PaymentDetails_LnCopyWithImpl(_$PaymentDetails_Ln _value, $Res Function(_$PaymentDetails_Ln) _then)
+      : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? config = null,
+    Object? data = null,
   }) {
-    return _then(_value.copyWith(
-      config: null == config
-          ? _value.config
-          : config // ignore: cast_nullable_to_non_nullable
-              as GreenlightNodeConfig,
-    ) as $Val);
+    return _then(_$PaymentDetails_Ln(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnPaymentDetails,
+    ));
   }
 }
 
 /// @nodoc
-abstract class _$$NodeConfig_GreenlightCopyWith<$Res> implements $NodeConfigCopyWith<$Res> {
-  factory _$$NodeConfig_GreenlightCopyWith(
-          _$NodeConfig_Greenlight value, $Res Function(_$NodeConfig_Greenlight) then) =
-      __$$NodeConfig_GreenlightCopyWithImpl<$Res>;
+
+class _$PaymentDetails_Ln implements PaymentDetails_Ln {
+  const _$PaymentDetails_Ln({required this.data});
+
+  @override
+  final LnPaymentDetails data;
+
+  @override
+  String toString() {
+    return 'PaymentDetails.ln(data: $data)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentDetails_Ln &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentDetails_LnCopyWith<_$PaymentDetails_Ln> get copyWith =>
+      __$$PaymentDetails_LnCopyWithImpl<_$PaymentDetails_Ln>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LnPaymentDetails data) ln,
+    required TResult Function(ClosedChannelPaymentDetails data) closedChannel,
+  }) {
+    return ln(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LnPaymentDetails data)? ln,
+    TResult? Function(ClosedChannelPaymentDetails data)? closedChannel,
+  }) {
+    return ln?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LnPaymentDetails data)? ln,
+    TResult Function(ClosedChannelPaymentDetails data)? closedChannel,
+    required TResult orElse(),
+  }) {
+    if (ln != null) {
+      return ln(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PaymentDetails_Ln value) ln,
+    required TResult Function(PaymentDetails_ClosedChannel value) closedChannel,
+  }) {
+    return ln(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PaymentDetails_Ln value)? ln,
+    TResult? Function(PaymentDetails_ClosedChannel value)? closedChannel,
+  }) {
+    return ln?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PaymentDetails_Ln value)? ln,
+    TResult Function(PaymentDetails_ClosedChannel value)? closedChannel,
+    required TResult orElse(),
+  }) {
+    if (ln != null) {
+      return ln(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PaymentDetails_Ln implements PaymentDetails {
+  const factory PaymentDetails_Ln({required final LnPaymentDetails data}) = _$PaymentDetails_Ln;
+
   @override
+  LnPaymentDetails get data;
+  @JsonKey(ignore: true)
+  _$$PaymentDetails_LnCopyWith<_$PaymentDetails_Ln> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PaymentDetails_ClosedChannelCopyWith<$Res> {
+  factory _$$PaymentDetails_ClosedChannelCopyWith(
+          _$PaymentDetails_ClosedChannel value, $Res Function(_$PaymentDetails_ClosedChannel) then) =
+      __$$PaymentDetails_ClosedChannelCopyWithImpl<$Res>;
   @useResult
-  $Res call({GreenlightNodeConfig config});
+  $Res call({ClosedChannelPaymentDetails data});
 }
 
 /// @nodoc
-class __$$NodeConfig_GreenlightCopyWithImpl<$Res>
-    extends _$NodeConfigCopyWithImpl<$Res, _$NodeConfig_Greenlight>
-    implements _$$NodeConfig_GreenlightCopyWith<$Res> {
-  __$$NodeConfig_GreenlightCopyWithImpl(
-      _$NodeConfig_Greenlight _value, $Res Function(_$NodeConfig_Greenlight) _then)
+class __$$PaymentDetails_ClosedChannelCopyWithImpl<$Res>
+    extends _$PaymentDetailsCopyWithImpl<$Res, _$PaymentDetails_ClosedChannel>
+    implements _$$PaymentDetails_ClosedChannelCopyWith<$Res> {
+  __$$PaymentDetails_ClosedChannelCopyWithImpl(
+      _$PaymentDetails_ClosedChannel _value, $Res Function(_$PaymentDetails_ClosedChannel) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
-  $Res call({
-    Object? config = null,
-  }) {
-    return _then(_$NodeConfig_Greenlight(
-      config: null == config
-          ? _value.config
-          : config // ignore: cast_nullable_to_non_nullable
-              as GreenlightNodeConfig,
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$PaymentDetails_ClosedChannel(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as ClosedChannelPaymentDetails,
     ));
   }
 }
 
 /// @nodoc
 
-class _$NodeConfig_Greenlight implements NodeConfig_Greenlight {
-  const _$NodeConfig_Greenlight({required this.config});
+class _$PaymentDetails_ClosedChannel implements PaymentDetails_ClosedChannel {
+  const _$PaymentDetails_ClosedChannel({required this.data});
 
   @override
-  final GreenlightNodeConfig config;
+  final ClosedChannelPaymentDetails data;
 
   @override
   String toString() {
-    return 'NodeConfig.greenlight(config: $config)';
+    return 'PaymentDetails.closedChannel(data: $data)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$NodeConfig_Greenlight &&
-            (identical(other.config, config) || other.config == config));
+            other is _$PaymentDetails_ClosedChannel &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, config);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$NodeConfig_GreenlightCopyWith<_$NodeConfig_Greenlight> get copyWith =>
-      __$$NodeConfig_GreenlightCopyWithImpl<_$NodeConfig_Greenlight>(this, _$identity);
+  _$$PaymentDetails_ClosedChannelCopyWith<_$PaymentDetails_ClosedChannel> get copyWith =>
+      __$$PaymentDetails_ClosedChannelCopyWithImpl<_$PaymentDetails_ClosedChannel>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(GreenlightNodeConfig config) greenlight,
+    required TResult Function(LnPaymentDetails data) ln,
+    required TResult Function(ClosedChannelPaymentDetails data) closedChannel,
   }) {
-    return greenlight(config);
+    return closedChannel(data);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(GreenlightNodeConfig config)? greenlight,
+    TResult? Function(LnPaymentDetails data)? ln,
+    TResult? Function(ClosedChannelPaymentDetails data)? closedChannel,
   }) {
-    return greenlight?.call(config);
+    return closedChannel?.call(data);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(GreenlightNodeConfig config)? greenlight,
+    TResult Function(LnPaymentDetails data)? ln,
+    TResult Function(ClosedChannelPaymentDetails data)? closedChannel,
     required TResult orElse(),
   }) {
-    if (greenlight != null) {
-      return greenlight(config);
+    if (closedChannel != null) {
+      return closedChannel(data);
     }
     return orElse();
   }
@@ -3925,96 +6094,105 @@ class _$NodeConfig_Greenlight implements NodeConfig_Greenlight {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NodeConfig_Greenlight value) greenlight,
+    required TResult Function(PaymentDetails_Ln value) ln,
+    required TResult Function(PaymentDetails_ClosedChannel value) closedChannel,
   }) {
-    return greenlight(this);
+    return closedChannel(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+    TResult? Function(PaymentDetails_Ln value)? ln,
+    TResult? Function(PaymentDetails_ClosedChannel value)? closedChannel,
   }) {
-    return greenlight?.call(this);
+    return closedChannel?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    TResult Function(PaymentDetails_Ln value)? ln,
+    TResult Function(PaymentDetails_ClosedChannel value)? closedChannel,
     required TResult orElse(),
   }) {
-    if (greenlight != null) {
-      return greenlight(this);
+    if (closedChannel != null) {
+      return closedChannel(this);
     }
     return orElse();
   }
 }
 
-abstract class NodeConfig_Greenlight implements NodeConfig {
-  const factory NodeConfig_Greenlight({required final GreenlightNodeConfig config}) = _$NodeConfig_Greenlight;
+abstract class PaymentDetails_ClosedChannel implements PaymentDetails {
+  const factory PaymentDetails_ClosedChannel({required final ClosedChannelPaymentDetails data}) =
+      _$PaymentDetails_ClosedChannel;
 
   @override
-  GreenlightNodeConfig get config;
-  @override
+  ClosedChannelPaymentDetails get data;
   @JsonKey(ignore: true)
-  _$$NodeConfig_GreenlightCopyWith<_$NodeConfig_Greenlight> get copyWith =>
+  _$$PaymentDetails_ClosedChannelCopyWith<_$PaymentDetails_ClosedChannel> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$PaymentDetails {
-  Object get data => throw _privateConstructorUsedError;
+mixin _$ProcessNotificationResult {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnPaymentDetails data) ln,
-    required TResult Function(ClosedChannelPaymentDetails data) closedChannel,
+    required TResult Function(Payment payment) paymentReceived,
+    required TResult Function(SwapInfo swapInfo) swapUpdated,
+    required TResult Function() deadlineReached,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnPaymentDetails data)? ln,
-    TResult? Function(ClosedChannelPaymentDetails data)? closedChannel,
+    TResult? Function(Payment payment)? paymentReceived,
+    TResult? Function(SwapInfo swapInfo)? swapUpdated,
+    TResult? Function()? deadlineReached,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnPaymentDetails data)? ln,
-    TResult Function(ClosedChannelPaymentDetails data)? closedChannel,
+    TResult Function(Payment payment)? paymentReceived,
+    TResult Function(SwapInfo swapInfo)? swapUpdated,
+    TResult Function()? deadlineReached,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(PaymentDetails_Ln value) ln,
-    required TResult Function(PaymentDetails_ClosedChannel value) closedChannel,
+    required TResult Function(ProcessNotificationResult_PaymentReceived value) paymentReceived,
+    required TResult Function(ProcessNotificationResult_SwapUpdated value) swapUpdated,
+    required TResult Function(ProcessNotificationResult_DeadlineReached value) deadlineReached,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(PaymentDetails_Ln value)? ln,
-    TResult? Function(PaymentDetails_ClosedChannel value)? closedChannel,
+    TResult? Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult? Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult? Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(PaymentDetails_Ln value)? ln,
-    TResult Function(PaymentDetails_ClosedChannel value)? closedChannel,
+    TResult Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $PaymentDetailsCopyWith<$Res> {
-  factory $PaymentDetailsCopyWith(PaymentDetails value, $Res Function(PaymentDetails) then) =
-      _$PaymentDetailsCopyWithImpl<$Res, PaymentDetails>;
+abstract class $ProcessNotificationResultCopyWith<$Res> {
+  factory $ProcessNotificationResultCopyWith(
+          ProcessNotificationResult value, $Res Function(ProcessNotificationResult) then) =
+      _$ProcessNotificationResultCopyWithImpl<$Res, ProcessNotificationResult>;
 }
 
 /// @nodoc
-class _$PaymentDetailsCopyWithImpl<$Res, $Val extends PaymentDetails>
-    implements $PaymentDetailsCopyWith<$Res> {
-  _$PaymentDetailsCopyWithImpl(this._value, this._then);
+class _$ProcessNotificationResultCopyWithImpl<$Res, $Val extends ProcessNotificationResult>
+    implements $ProcessNotificationResultCopyWith<$Res> {
+  _$ProcessNotificationResultCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -4023,90 +6201,99 @@ class _$PaymentDetailsCopyWithImpl<$Res, $Val extends PaymentDetails>
 }
 
 /// @nodoc
-abstract class _$$PaymentDetails_LnCopyWith<$Res> {
-  factory _$$PaymentDetails_LnCopyWith(_$PaymentDetails_Ln value, $Res Function(_$PaymentDetails_Ln) then) =
-      __$$PaymentDetails_LnCopyWithImpl<$Res>;
+abstract class _$$ProcessNotificationResult_PaymentReceivedCopyWith<$Res> {
+  factory _$$ProcessNotificationResult_PaymentReceivedCopyWith(
+          _$ProcessNotificationResult_PaymentReceived value,
+          $Res Function(_$ProcessNotificationResult_PaymentReceived) then) =
+      __$$ProcessNotificationResult_PaymentReceivedCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnPaymentDetails data});
+  $Res call({Payment payment});
 }
 
 /// @nodoc
-class __$$PaymentDetails_LnCopyWithImpl<$Res> extends _$PaymentDetailsCopyWithImpl<$Res, _$PaymentDetails_Ln>
-    implements _$$PaymentDetails_LnCopyWith<$Res> {
-  __$$PaymentDetails_LnCopyWithImpl(_$PaymentDetails_Ln _value, $Res Function(_$PaymentDetails_Ln) _then)
+class __$$ProcessNotificationResult_PaymentReceivedCopyWithImpl<$Res>
+    extends _$ProcessNotificationResultCopyWithImpl<$Res, _$ProcessNotificationResult_PaymentReceived>
+    implements _$$ProcessNotificationResult_PaymentReceivedCopyWith<$Res> {
+  __$$ProcessNotificationResult_PaymentReceivedCopyWithImpl(
+      _$ProcessNotificationResult_PaymentReceived _value,
+      $Res Function(_$ProcessNotificationResult_PaymentReceived) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? payment = null,
   }) {
-    return _then(_$PaymentDetails_Ln(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnPaymentDetails,
+    return _then(_$ProcessNotificationResult_PaymentReceived(
+      payment: null == payment
+          ? _value.payment
+          : payment // ignore: cast_nullable_to_non_nullable
+              as Payment,
     ));
   }
 }
 
 /// @nodoc
 
-class _$PaymentDetails_Ln implements PaymentDetails_Ln {
-  const _$PaymentDetails_Ln({required this.data});
+class _$ProcessNotificationResult_PaymentReceived implements ProcessNotificationResult_PaymentReceived {
+  const _$ProcessNotificationResult_PaymentReceived({required this.payment});
 
   @override
-  final LnPaymentDetails data;
+  final Payment payment;
 
   @override
   String toString() {
-    return 'PaymentDetails.ln(data: $data)';
+    return 'ProcessNotificationResult.paymentReceived(payment: $payment)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$PaymentDetails_Ln &&
-            (identical(other.data, data) || other.data == data));
+            other is _$ProcessNotificationResult_PaymentReceived &&
+            (identical(other.payment, payment) || other.payment == payment));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, payment);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$PaymentDetails_LnCopyWith<_$PaymentDetails_Ln> get copyWith =>
-      __$$PaymentDetails_LnCopyWithImpl<_$PaymentDetails_Ln>(this, _$identity);
+  _$$ProcessNotificationResult_PaymentReceivedCopyWith<_$ProcessNotificationResult_PaymentReceived>
+      get copyWith => __$$ProcessNotificationResult_PaymentReceivedCopyWithImpl<
+          _$ProcessNotificationResult_PaymentReceived>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnPaymentDetails data) ln,
-    required TResult Function(ClosedChannelPaymentDetails data) closedChannel,
+    required TResult Function(Payment payment) paymentReceived,
+    required TResult Function(SwapInfo swapInfo) swapUpdated,
+    required TResult Function() deadlineReached,
   }) {
-    return ln(data);
+    return paymentReceived(payment);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnPaymentDetails data)? ln,
-    TResult? Function(ClosedChannelPaymentDetails data)? closedChannel,
+    TResult? Function(Payment payment)? paymentReceived,
+    TResult? Function(SwapInfo swapInfo)? swapUpdated,
+    TResult? Function()? deadlineReached,
   }) {
-    return ln?.call(data);
+    return paymentReceived?.call(payment);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnPaymentDetails data)? ln,
-    TResult Function(ClosedChannelPaymentDetails data)? closedChannel,
+    TResult Function(Payment payment)? paymentReceived,
+    TResult Function(SwapInfo swapInfo)? swapUpdated,
+    TResult Function()? deadlineReached,
     required TResult orElse(),
   }) {
-    if (ln != null) {
-      return ln(data);
+    if (paymentReceived != null) {
+      return paymentReceived(payment);
     }
     return orElse();
   }
@@ -4114,132 +6301,142 @@ class _$PaymentDetails_Ln implements PaymentDetails_Ln {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(PaymentDetails_Ln value) ln,
-    required TResult Function(PaymentDetails_ClosedChannel value) closedChannel,
+    required TResult Function(ProcessNotificationResult_PaymentReceived value) paymentReceived,
+    required TResult Function(ProcessNotificationResult_SwapUpdated value) swapUpdated,
+    required TResult Function(ProcessNotificationResult_DeadlineReached value) deadlineReached,
   }) {
-    return ln(this);
+    return paymentReceived(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(PaymentDetails_Ln value)? ln,
-    TResult? Function(PaymentDetails_ClosedChannel value)? closedChannel,
+    TResult? Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult? Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult? Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
   }) {
-    return ln?.call(this);
+    return paymentReceived?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(PaymentDetails_Ln value)? ln,
-    TResult Function(PaymentDetails_ClosedChannel value)? closedChannel,
+    TResult Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
     required TResult orElse(),
   }) {
-    if (ln != null) {
-      return ln(this);
+    if (paymentReceived != null) {
+      return paymentReceived(this);
     }
     return orElse();
   }
 }
 
-abstract class PaymentDetails_Ln implements PaymentDetails {
-  const factory PaymentDetails_Ln({required final LnPaymentDetails data}) = _$PaymentDetails_Ln;
+abstract class ProcessNotificationResult_PaymentReceived implements ProcessNotificationResult {
+  const factory ProcessNotificationResult_PaymentReceived({required final Payment payment}) =
+      _$ProcessNotificationResult_PaymentReceived;
 
-  @override
-  LnPaymentDetails get data;
+  Payment get payment;
   @JsonKey(ignore: true)
-  _$$PaymentDetails_LnCopyWith<_$PaymentDetails_Ln> get copyWith => throw _privateConstructorUsedError;
+  _$$ProcessNotificationResult_PaymentReceivedCopyWith<_$ProcessNotificationResult_PaymentReceived>
+      get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$PaymentDetails_ClosedChannelCopyWith<$Res> {
-  factory _$$PaymentDetails_ClosedChannelCopyWith(
-          _$PaymentDetails_ClosedChannel value, $Res Function(_$PaymentDetails_ClosedChannel) then) =
-      __$$PaymentDetails_ClosedChannelCopyWithImpl<$Res>;
+abstract class _$$ProcessNotificationResult_SwapUpdatedCopyWith<$Res> {
+  factory _$$ProcessNotificationResult_SwapUpdatedCopyWith(
+          _$ProcessNotificationResult_SwapUpdated value,
+          $Res Function(_$ProcessNotificationResult_SwapUpdated) then) =
+      __$$ProcessNotificationResult_SwapUpdatedCopyWithImpl<$Res>;
   @useResult
-  $Res call({ClosedChannelPaymentDetails data});
+  $Res call({SwapInfo swapInfo});
 }
 
 /// @nodoc
-class __$$PaymentDetails_ClosedChannelCopyWithImpl<$Res>
-    extends _$PaymentDetailsCopyWithImpl<$Res, _$PaymentDetails_ClosedChannel>
-    implements _$$PaymentDetails_ClosedChannelCopyWith<$Res> {
-  __$$PaymentDetails_ClosedChannelCopyWithImpl(
-      _$PaymentDetails_ClosedChannel _value, $Res Function(_$PaymentDetails_ClosedChannel) _then)
+class __$$ProcessNotificationResult_SwapUpdatedCopyWithImpl<$Res>
+    extends _$ProcessNotificationResultCopyWithImpl<$Res, _$ProcessNotificationResult_SwapUpdated>
+    implements _$$ProcessNotificationResult_SwapUpdatedCopyWith<$Res> {
+  __$$ProcessNotificationResult_SwapUpdatedCopyWithImpl(
+      _$ProcessNotificationResult_SwapUpdated _value,
+      $Res Function(_$ProcessNotificationResult_SwapUpdated) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? swapInfo = null,
   }) {
-    return _then(_$PaymentDetails_ClosedChannel(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as ClosedChannelPaymentDetails,
+    return _then(_$ProcessNotificationResult_SwapUpdated(
+      swapInfo: null == swapInfo
+          ? _value.swapInfo
+          : swapInfo // ignore: cast_nullable_to_non_nullable
+              as SwapInfo,
     ));
   }
 }
 
 /// @nodoc
 
-class _$PaymentDetails_ClosedChannel implements PaymentDetails_ClosedChannel {
-  const _$PaymentDetails_ClosedChannel({required this.data});
+class _$ProcessNotificationResult_SwapUpdated implements ProcessNotificationResult_SwapUpdated {
+  const _$ProcessNotificationResult_SwapUpdated({required this.swapInfo});
 
   @override
-  final ClosedChannelPaymentDetails data;
+  final SwapInfo swapInfo;
 
   @override
   String toString() {
-    return 'PaymentDetails.closedChannel(data: $data)';
+    return 'ProcessNotificationResult.swapUpdated(swapInfo: $swapInfo)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$PaymentDetails_ClosedChannel &&
-            (identical(other.data, data) || other.data == data));
+            other is _$ProcessNotificationResult_SwapUpdated &&
+            (identical(other.swapInfo, swapInfo) || other.swapInfo == swapInfo));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, swapInfo);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$PaymentDetails_ClosedChannelCopyWith<_$PaymentDetails_ClosedChannel> get copyWith =>
-      __$$PaymentDetails_ClosedChannelCopyWithImpl<_$PaymentDetails_ClosedChannel>(this, _$identity);
+  _$$ProcessNotificationResult_SwapUpdatedCopyWith<_$ProcessNotificationResult_SwapUpdated> get copyWith =>
+      __$$ProcessNotificationResult_SwapUpdatedCopyWithImpl<_$ProcessNotificationResult_SwapUpdated>(
+          this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnPaymentDetails data) ln,
-    required TResult Function(ClosedChannelPaymentDetails data) closedChannel,
+    required TResult Function(Payment payment) paymentReceived,
+    required TResult Function(SwapInfo swapInfo) swapUpdated,
+    required TResult Function() deadlineReached,
   }) {
-    return closedChannel(data);
+    return swapUpdated(swapInfo);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnPaymentDetails data)? ln,
-    TResult? Function(ClosedChannelPaymentDetails data)? closedChannel,
+    TResult? Function(Payment payment)? paymentReceived,
+    TResult? Function(SwapInfo swapInfo)? swapUpdated,
+    TResult? Function()? deadlineReached,
   }) {
-    return closedChannel?.call(data);
+    return swapUpdated?.call(swapInfo);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnPaymentDetails data)? ln,
-    TResult Function(ClosedChannelPaymentDetails data)? closedChannel,
+    TResult Function(Payment payment)? paymentReceived,
+    TResult Function(SwapInfo swapInfo)? swapUpdated,
+    TResult Function()? deadlineReached,
     required TResult orElse(),
   }) {
-    if (closedChannel != null) {
-      return closedChannel(data);
+    if (swapUpdated != null) {
+      return swapUpdated(swapInfo);
     }
     return orElse();
   }
@@ -4247,46 +6444,158 @@ class _$PaymentDetails_ClosedChannel implements PaymentDetails_ClosedChannel {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(PaymentDetails_Ln value) ln,
-    required TResult Function(PaymentDetails_ClosedChannel value) closedChannel,
+    required TResult Function(ProcessNotificationResult_PaymentReceived value) paymentReceived,
+    required TResult Function(ProcessNotificationResult_SwapUpdated value) swapUpdated,
+    required TResult Function(ProcessNotificationResult_DeadlineReached value) deadlineReached,
   }) {
-    return closedChannel(this);
+    return swapUpdated(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(PaymentDetails_Ln value)? ln,
-    TResult? Function(PaymentDetails_ClosedChannel value)? closedChannel,
+    TResult? Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult? Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult? Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
   }) {
-    return closedChannel?.call(this);
+    return swapUpdated?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(PaymentDetails_Ln value)? ln,
-    TResult Function(PaymentDetails_ClosedChannel value)? closedChannel,
+    TResult Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
     required TResult orElse(),
   }) {
-    if (closedChannel != null) {
-      return closedChannel(this);
+    if (swapUpdated != null) {
+      return swapUpdated(this);
     }
     return orElse();
   }
 }
 
-abstract class PaymentDetails_ClosedChannel implements PaymentDetails {
-  const factory PaymentDetails_ClosedChannel({required final ClosedChannelPaymentDetails data}) =
-      _$PaymentDetails_ClosedChannel;
+abstract class ProcessNotificationResult_SwapUpdated implements ProcessNotificationResult {
+  const factory ProcessNotificationResult_SwapUpdated({required final SwapInfo swapInfo}) =
+      _$ProcessNotificationResult_SwapUpdated;
 
-  @override
-  ClosedChannelPaymentDetails get data;
+  SwapInfo get swapInfo;
   @JsonKey(ignore: true)
-  _$$PaymentDetails_ClosedChannelCopyWith<_$PaymentDetails_ClosedChannel> get copyWith =>
+  _$$ProcessNotificationResult_SwapUpdatedCopyWith<_$ProcessNotificationResult_SwapUpdated> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$ProcessNotificationResult_DeadlineReachedCopyWith<$Res> {
+  factory _$$ProcessNotificationResult_DeadlineReachedCopyWith(
+          _$ProcessNotificationResult_DeadlineReached value,
+          $Res Function(_$ProcessNotificationResult_DeadlineReached) then) =
+      __$$ProcessNotificationResult_DeadlineReachedCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ProcessNotificationResult_DeadlineReachedCopyWithImpl<$Res>
+    extends _$ProcessNotificationResultCopyWithImpl<$Res, _$ProcessNotificationResult_DeadlineReached>
+    implements _$$ProcessNotificationResult_DeadlineReachedCopyWith<$Res> {
+  __$$ProcessNotificationResult_DeadlineReachedCopyWithImpl(
+      _$ProcessNotificationResult_DeadlineReached _value,
+      $Res Function(_$ProcessNotificationResult_DeadlineReached) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$ProcessNotificationResult_DeadlineReached implements ProcessNotificationResult_DeadlineReached {
+  const _$ProcessNotificationResult_DeadlineReached();
+
+  @override
+  String toString() {
+    return 'ProcessNotificationResult.deadlineReached()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$ProcessNotificationResult_DeadlineReached);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Payment payment) paymentReceived,
+    required TResult Function(SwapInfo swapInfo) swapUpdated,
+    required TResult Function() deadlineReached,
+  }) {
+    return deadlineReached();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Payment payment)? paymentReceived,
+    TResult? Function(SwapInfo swapInfo)? swapUpdated,
+    TResult? Function()? deadlineReached,
+  }) {
+    return deadlineReached?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Payment payment)? paymentReceived,
+    TResult Function(SwapInfo swapInfo)? swapUpdated,
+    TResult Function()? deadlineReached,
+    required TResult orElse(),
+  }) {
+    if (deadlineReached != null) {
+      return deadlineReached();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ProcessNotificationResult_PaymentReceived value) paymentReceived,
+    required TResult Function(ProcessNotificationResult_SwapUpdated value) swapUpdated,
+    required TResult Function(ProcessNotificationResult_DeadlineReached value) deadlineReached,
+  }) {
+    return deadlineReached(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult? Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult? Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
+  }) {
+    return deadlineReached?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ProcessNotificationResult_PaymentReceived value)? paymentReceived,
+    TResult Function(ProcessNotificationResult_SwapUpdated value)? swapUpdated,
+    TResult Function(ProcessNotificationResult_DeadlineReached value)? deadlineReached,
+    required TResult orElse(),
+  }) {
+    if (deadlineReached != null) {
+      return deadlineReached(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ProcessNotificationResult_DeadlineReached implements ProcessNotificationResult {
+  const factory ProcessNotificationResult_DeadlineReached() = _$ProcessNotificationResult_DeadlineReached;
+}
+
 /// @nodoc
 mixin _$SuccessActionProcessed {
   Object get data => throw _privateConstructorUsedError;
@@ -4,11 +4,13 @@ use std::sync::Arc;
 use anyhow::{anyhow, Error, Result};
 use breez_sdk_core::InputType::{LnUrlAuth, LnUrlPay, LnUrlWithdraw};
 use breez_sdk_core::{
-    parse, BreezEvent, BreezServices, BuyBitcoinRequest, CheckMessageRequest, EventListener,
-    GreenlightCredentials, ListPaymentsRequest, LnUrlPayRequest, LnUrlWithdrawRequest,
-    PrepareRefundRequest, ReceiveOnchainRequest, ReceivePaymentRequest, RefundRequest,
-    ReverseSwapFeesRequest, SendOnchainRequest, SendPaymentRequest, SendSpontaneousPaymentRequest,
-    SignMessageRequest, StaticBackupRequest, SweepRequest,
+    parse, BreezEvent, BreezServices, BuyBitcoinRequest, BuyInboundLiquidityRequest,
+    CheckMessageRequest, EventListener, ExportPaymentsRequest, GreenlightCredentials,
+    ListPaymentsRequest, LnUrlPayRequest, LnUrlWithdrawRequest, PaymentExportFormat,
+    PrepareBuyInboundLiquidityRequest, PrepareRefundRequest, ReceiveOnchainRequest,
+    ReceivePaymentRequest, RefundRequest, ReverseSwapFeesRequest, SendOnchainRequest,
+    SendPaymentRequest, SendSpontaneousPaymentRequest, SignMessageRequest, StaticBackupRequest,
+    SweepRequest,
 };
 use breez_sdk_core::{Config, GreenlightNodeConfig, NodeConfig};
 use once_cell::sync::OnceCell;
@@ -213,6 +215,10 @@ pub(crate) async fn handle_command(
             include_failures,
             limit,
             offset,
+            search,
+            min_amount_msat,
+            max_amount_msat,
+            tag,
         } => {
             let payments = sdk()?
                 .list_payments(ListPaymentsRequest {
@@ -222,6 +228,11 @@ pub(crate) async fn handle_command(
                     include_failures: Some(include_failures),
                     limit,
                     offset,
+                    cursor: None,
+                    search,
+                    min_amount_msat,
+                    max_amount_msat,
+                    tags: tag.map(|t| vec![t]),
                 })
                 .await?;
             serde_json::to_string_pretty(&payments).map_err(|e| e.into())
@@ -230,6 +241,30 @@ pub(crate) async fn handle_command(
             let payment = sdk()?.payment_by_hash(hash).await?;
             serde_json::to_string_pretty(&payment).map_err(|e| e.into())
         }
+        Commands::ExportPayments {
+            from_timestamp,
+            to_timestamp,
+            fiat_currency,
+            json,
+        } => {
+            let format = match json {
+                true => PaymentExportFormat::Json,
+                false => PaymentExportFormat::Csv,
+            };
+            sdk()?
+                .export_payments(ExportPaymentsRequest {
+                    format,
+                    from_timestamp,
+                    to_timestamp,
+                    fiat_currency,
+                })
+                .await
+                .map_err(|e| e.into())
+        }
+        Commands::SetPaymentMetadata { hash, note, tags } => {
+            sdk()?.set_payment_metadata(hash, note, tags).await?;
+            Ok("Payment metadata was set successfully".to_string())
+        }
         Commands::Sweep {
             to_address,
             fee_rate_sats_per_vbyte,
@@ -262,6 +297,10 @@ pub(crate) async fn handle_command(
             sdk()?.connect_lsp(lsp_id).await?;
             Ok("LSP connected successfully".to_string())
         }
+        Commands::ConnectLspByUri { uri } => {
+            let lsp = sdk()?.connect_lsp_by_uri(uri).await?;
+            serde_json::to_string_pretty(&lsp).map_err(|e| e.into())
+        }
         Commands::OpenChannelFee {
             amount_msat,
             expiry,
@@ -288,6 +327,24 @@ pub(crate) async fn handle_command(
             let tx_ids = sdk()?.close_lsp_channels().await?;
             Ok(format!("Closing transaction ids:\n{:?}", tx_ids))
         }
+        Commands::PrepareBuyInboundLiquidity {
+            amount_sat,
+            lease_blocks,
+        } => {
+            let res = sdk()?
+                .prepare_buy_inbound_liquidity(PrepareBuyInboundLiquidityRequest {
+                    amount_sat,
+                    lease_blocks,
+                })
+                .await?;
+            serde_json::to_string_pretty(&res.order).map_err(|e| e.into())
+        }
+        Commands::BuyInboundLiquidity { order_id } => {
+            let res = sdk()?
+                .buy_inbound_liquidity(BuyInboundLiquidityRequest { order_id })
+                .await?;
+            serde_json::to_string_pretty(&res.order).map_err(|e| e.into())
+        }
         Commands::Disconnect {} => {
             sdk()?.disconnect().await?;
             Ok("Node was stopped successfully".to_string())
@@ -358,6 +415,14 @@ pub(crate) async fn handle_command(
             let res = sdk()?.check_message(req).await?;
             Ok(format!("Message was signed by node: {}", res.is_valid))
         }
+        Commands::RegisterWebhook { url } => {
+            sdk()?.register_webhook(url).await?;
+            Ok("Webhook registered successfully".to_string())
+        }
+        Commands::EventsSince { seq } => {
+            let events = sdk()?.events_since(seq)?;
+            serde_json::to_string_pretty(&events).map_err(|e| e.into())
+        }
         Commands::LnurlPay { lnurl } => match parse(&lnurl).await? {
             LnUrlPay { data: pd } => {
                 let prompt = format!(
@@ -454,6 +519,8 @@ pub(crate) async fn handle_command(
                 .to_sdk_config(&persistence.data_dir);
             let backup_data = BreezServices::static_backup(StaticBackupRequest {
                 working_dir: config.working_dir,
+                seed: Some(persistence.get_or_create_seed()),
+                storage_key: config.storage_key,
             })?;
             match backup_data.backup {
                 Some(backup) => {
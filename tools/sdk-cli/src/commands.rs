@@ -110,6 +110,15 @@ pub(crate) enum Commands {
         signature: String,
     },
 
+    /// Register a webhook to be notified of incoming payments and swap transactions while offline
+    RegisterWebhook { url: String },
+
+    /// List the logged events emitted after the given sequence number
+    EventsSince {
+        #[clap(default_value = "0")]
+        seq: u64,
+    },
+
     /// List all payments
     ListPayments {
         /// The optional from unix timestamp
@@ -131,11 +140,60 @@ pub(crate) enum Commands {
         /// Optional offset in payments
         #[clap(short = 'o', long = "offset")]
         offset: Option<u32>,
+
+        /// Optional text to search in the description, LNURL metadata, Lightning address and hash
+        #[clap(short = 's', long = "search")]
+        search: Option<String>,
+
+        /// The optional minimum amount, in millisatoshis
+        #[clap(long = "min_amount")]
+        min_amount_msat: Option<u64>,
+
+        /// The optional maximum amount, in millisatoshis
+        #[clap(long = "max_amount")]
+        max_amount_msat: Option<u64>,
+
+        /// Only list the payments with this tag
+        #[clap(long = "tag")]
+        tag: Option<String>,
     },
 
     /// Retrieve a payment by its hash
     PaymentByHash { hash: String },
 
+    /// Export the payment history as CSV
+    ExportPayments {
+        /// The optional from unix timestamp
+        #[clap(name = "from_timestamp", short = 'f', long = "from")]
+        from_timestamp: Option<i64>,
+
+        /// The optional to unix timestamp
+        #[clap(name = "to_timestamp", short = 't', long = "to")]
+        to_timestamp: Option<i64>,
+
+        /// Optional fiat currency in which the amounts are also given
+        #[clap(short = 'c', long = "currency")]
+        fiat_currency: Option<String>,
+
+        /// Export as JSON instead of CSV
+        #[clap(short = 'j', long = "json")]
+        json: bool,
+    },
+
+    /// Set the note and tags of a payment
+    SetPaymentMetadata {
+        /// The hash of the payment
+        hash: String,
+
+        /// The optional note
+        #[clap(short = 'n', long = "note")]
+        note: Option<String>,
+
+        /// The tags, can be repeated
+        #[clap(short = 't', long = "tag")]
+        tags: Vec<String>,
+    },
+
     /// Send on-chain funds to an external address
     Sweep {
         /// The sweep destination address
@@ -163,6 +221,12 @@ pub(crate) enum Commands {
         lsp_id: String,
     },
 
+    /// Connect to a standards-compliant LSP by its node URI
+    ConnectLspByUri {
+        /// The node URI of the LSP, as pubkey@host:port
+        uri: String,
+    },
+
     OpenChannelFee {
         /// The received amount
         amount_msat: u64,
@@ -183,6 +247,22 @@ pub(crate) enum Commands {
     /// Close all LSP channels
     CloseLSPChannels {},
 
+    /// Order a channel with inbound liquidity from the LSP, showing its fee
+    PrepareBuyInboundLiquidity {
+        /// The inbound liquidity in sats
+        amount_sat: u64,
+
+        /// The number of blocks the LSP keeps the channel open
+        #[clap(default_value = "13140")]
+        lease_blocks: u32,
+    },
+
+    /// Buy a channel with inbound liquidity, paying a prepared order
+    BuyInboundLiquidity {
+        /// The id of the prepared order
+        order_id: String,
+    },
+
     /// Stop the node and disconnect from the sdk services
     Disconnect {},
 